@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+use super::{geometry, Entity};
+
+// 다이어그램(주제 영역) 모델
+// 프로젝트의 엔티티 중 일부를 골라 다이어그램마다 따로 위치/크기/색을 둡니다.
+// 기본 다이어그램은 프로젝트의 모든 엔티티를 보여주며, 배치를 따로 저장하지 않고 항상 엔티티의 x/y/크기/색으로 만듭니다.
+// (엔티티를 옮기면 기본 다이어그램도 그대로 따라가고, 기본 다이어그램의 배치를 바꾸면 엔티티에 저장합니다)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagram {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub is_default: bool,
+    pub items: Vec<DiagramItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagramItem {
+    pub entity_id: String,
+    #[serde(deserialize_with = "geometry::deserialize_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_coordinate")]
+    pub y: f64,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    // #rgb, #rrggbb 형태
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl DiagramItem {
+    pub fn from_entity(entity: &Entity) -> Self {
+        Self {
+            entity_id: entity.id.clone(),
+            x: entity.x,
+            y: entity.y,
+            width: entity.width,
+            height: entity.height,
+            color: entity.color.clone(),
+        }
+    }
+}
+
+impl Diagram {
+    pub const NAME: &'static str = "modeler_diagram";
+
+    pub const DEFAULT_NAME: &'static str = "기본 다이어그램";
+
+    // 동시에 여러 번 옮겨도 하나만 생기도록 기본 다이어그램 id는 프로젝트 id로 정합니다.
+    pub fn default_id(project_id: &str) -> String {
+        format!("{project_id}-default")
+    }
+
+    // 기존 엔티티의 x/y를 옮긴 기본 다이어그램
+    pub fn from_entities(project_id: &str, entities: &[Entity]) -> Self {
+        Self {
+            id: Self::default_id(project_id),
+            project_id: project_id.to_owned(),
+            name: Self::DEFAULT_NAME.to_owned(),
+            is_default: true,
+            items: entities.iter().map(DiagramItem::from_entity).collect(),
+        }
+    }
+
+    // 삭제된 엔티티는 빼고, 기본 다이어그램은 현재 엔티티로 다시 채웁니다.
+    pub fn sync_entities(&mut self, entities: &[Entity]) {
+        if self.is_default {
+            self.items = entities.iter().map(DiagramItem::from_entity).collect();
+        } else {
+            self.items
+                .retain(|item| entities.iter().any(|e| e.id == item.entity_id));
+        }
+    }
+
+    // 기본 다이어그램의 배치를 엔티티에 옮깁니다. 바뀐 엔티티만 (변경 전, 변경 후)로 돌려줍니다.
+    // 크기/색이 없는 항목은 엔티티의 값을 그대로 둡니다.
+    pub fn apply_to_entities(&self, entities: &[Entity]) -> Vec<(Entity, Entity)> {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                let before = entities.iter().find(|e| e.id == item.entity_id)?;
+
+                let mut after = before.clone();
+                after.x = item.x;
+                after.y = item.y;
+                after.width = item.width.or(before.width);
+                after.height = item.height.or(before.height);
+                after.color = item.color.clone().or(before.color.clone());
+
+                let is_changed = (
+                    before.x,
+                    before.y,
+                    before.width,
+                    before.height,
+                    &before.color,
+                ) != (after.x, after.y, after.width, after.height, &after.color);
+
+                is_changed.then(|| (before.clone(), after))
+            })
+            .collect()
+    }
+
+    pub fn validate_items(&self, entities: &[Entity]) -> Result<(), String> {
+        for (i, item) in self.items.iter().enumerate() {
+            if !entities.iter().any(|e| e.id == item.entity_id) {
+                return Err(format!("프로젝트에 없는 엔티티입니다: {}", item.entity_id));
+            }
+
+            if self.items[..i]
+                .iter()
+                .any(|e| e.entity_id == item.entity_id)
+            {
+                return Err(format!(
+                    "다이어그램에 같은 엔티티가 중복됩니다: {}",
+                    item.entity_id
+                ));
+            }
+
+            geometry::validate(
+                item.x,
+                item.y,
+                item.width,
+                item.height,
+                item.color.as_deref(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // 다이어그램에 놓인 엔티티만 다이어그램 좌표로 돌려줍니다. (SVG 등 좌표를 쓰는 출력용)
+    pub fn place_entities(&self, entities: &[Entity]) -> Vec<Entity> {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                let mut entity = entities.iter().find(|e| e.id == item.entity_id)?.clone();
+                entity.x = item.x;
+                entity.y = item.y;
+                entity.width = item.width.or(entity.width);
+                entity.height = item.height.or(entity.height);
+                entity.color = item.color.clone().or(entity.color);
+                Some(entity)
+            })
+            .collect()
+    }
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "project_id".to_string(),
+            AttributeValue::S(self.project_id.to_owned()),
+        );
+        map.insert("name".to_string(), AttributeValue::S(self.name.to_owned()));
+        map.insert(
+            "is_default".to_string(),
+            AttributeValue::Bool(self.is_default),
+        );
+        // 기본 다이어그램의 배치는 엔티티에 있으므로 저장하지 않습니다.
+        let items = if self.is_default {
+            &[][..]
+        } else {
+            &self.items[..]
+        };
+        map.insert(
+            "items".to_string(),
+            AttributeValue::S(serde_json::to_string(items).ok()?),
+        );
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let project_id = hashmap.get("project_id")?.as_s().ok()?.to_owned();
+        let name = hashmap.get("name")?.as_s().ok()?.to_owned();
+        let is_default = hashmap
+            .get("is_default")
+            .and_then(|e| e.as_bool().ok().copied())
+            .unwrap_or_default();
+        let items = hashmap
+            .get("items")
+            .and_then(|e| e.as_s().ok())
+            .and_then(|e| serde_json::from_str(e).ok())
+            .unwrap_or_default();
+
+        Some(Self {
+            id,
+            project_id,
+            name,
+            is_default,
+            items,
+        })
+    }
+}
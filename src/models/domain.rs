@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+use super::Column;
+
+// 컬럼 도메인 모델 (project_id가 없으면 팀 전체에서 사용하는 도메인)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Domain {
+    pub id: String,
+    pub team_id: String,
+    pub project_id: Option<String>,
+    pub name: String,
+    pub data_type: String,
+    pub length: Option<u32>,
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+    pub default_value: Option<String>,
+    pub nullable: bool,
+    pub comment: String,
+}
+
+impl Domain {
+    pub const NAME: &'static str = "modeler_domain";
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "team_id".to_string(),
+            AttributeValue::S(self.team_id.to_owned()),
+        );
+        map.insert("name".to_string(), AttributeValue::S(self.name.to_owned()));
+        map.insert(
+            "data_type".to_string(),
+            AttributeValue::S(self.data_type.to_owned()),
+        );
+        map.insert("nullable".to_string(), AttributeValue::Bool(self.nullable));
+        map.insert(
+            "comment".to_string(),
+            AttributeValue::S(self.comment.to_owned()),
+        );
+
+        if let Some(project_id) = self.project_id.clone() {
+            map.insert("project_id".to_string(), AttributeValue::S(project_id));
+        }
+
+        if let Some(length) = self.length {
+            map.insert("length".to_string(), AttributeValue::N(length.to_string()));
+        }
+
+        if let Some(precision) = self.precision {
+            map.insert(
+                "precision".to_string(),
+                AttributeValue::N(precision.to_string()),
+            );
+        }
+
+        if let Some(scale) = self.scale {
+            map.insert("scale".to_string(), AttributeValue::N(scale.to_string()));
+        }
+
+        if let Some(default_value) = self.default_value.clone() {
+            map.insert(
+                "default_value".to_string(),
+                AttributeValue::S(default_value),
+            );
+        }
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let team_id = hashmap.get("team_id")?.as_s().ok()?.to_owned();
+        let name = hashmap.get("name")?.as_s().ok()?.to_owned();
+        let data_type = hashmap.get("data_type")?.as_s().ok()?.to_owned();
+        let nullable = hashmap
+            .get("nullable")
+            .and_then(|e| e.as_bool().ok().copied())
+            .unwrap_or(true);
+        let comment = hashmap
+            .get("comment")
+            .and_then(|e| e.as_s().ok().map(|e| e.to_owned()))
+            .unwrap_or_default();
+        let project_id = hashmap
+            .get("project_id")
+            .and_then(|e| e.as_s().ok().map(|e| e.to_owned()));
+        let default_value = hashmap
+            .get("default_value")
+            .and_then(|e| e.as_s().ok().map(|e| e.to_owned()));
+
+        let number = |key: &str| {
+            hashmap
+                .get(key)
+                .and_then(|e| e.as_n().ok())
+                .and_then(|e| e.parse::<u32>().ok())
+        };
+
+        Some(Self {
+            id,
+            team_id,
+            project_id,
+            name,
+            data_type,
+            length: number("length"),
+            precision: number("precision"),
+            scale: number("scale"),
+            default_value,
+            nullable,
+            comment,
+        })
+    }
+
+    // 컬럼에 도메인 속성을 반영합니다. 바뀐 것이 있으면 true
+    // inherited_comment는 수정 전 도메인의 설명이며, 컬럼 설명이 이와 같으면 물려받은 것으로 봅니다.
+    pub fn apply_to(&self, column: &mut Column, inherited_comment: Option<&str>) -> bool {
+        let before = (
+            column.data_type.clone(),
+            column.length,
+            column.precision,
+            column.scale,
+            column.default_value.clone(),
+            column.nullable,
+            column.comment.clone(),
+        );
+
+        column.domain_id = Some(self.id.clone());
+        column.data_type = self.data_type.clone();
+        column.length = self.length;
+        column.precision = self.precision;
+        column.scale = self.scale;
+        column.default_value = self.default_value.clone();
+        // 기본 키는 도메인과 관계없이 NOT NULL을 유지합니다.
+        column.nullable = self.nullable && !column.is_primary_key;
+        // 컬럼에 직접 적은 설명이 있으면 그대로 두고, 물려받은 설명은 새 설명으로 바꿉니다.
+        let is_inherited = column.comment.trim().is_empty()
+            || inherited_comment.is_some_and(|e| e.trim() == column.comment.trim());
+
+        if is_inherited {
+            column.comment = self.comment.clone();
+        }
+
+        before
+            != (
+                column.data_type.clone(),
+                column.length,
+                column.precision,
+                column.scale,
+                column.default_value.clone(),
+                column.nullable,
+                column.comment.clone(),
+            )
+    }
+}
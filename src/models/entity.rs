@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use aws_sdk_dynamodb::model::AttributeValue;
 use serde::{Deserialize, Serialize};
 
+use super::{geometry, Domain};
+
 // 엔티티 모델
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,8 +15,26 @@ pub struct Entity {
     pub physical_name: String,
     pub comment: String,
     pub columns: Vec<Column>,
-    pub x: String,
-    pub y: String,
+    pub indexes: Vec<Index>,
+    #[serde(deserialize_with = "geometry::deserialize_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_coordinate")]
+    pub y: f64,
+    // 이하 항목은 기존에 저장된 엔티티에는 없으므로 기본값으로 읽습니다.
+    // 크기가 없으면 컬럼 수에 맞춘 기본 크기로 그립니다.
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    // #rgb, #rrggbb 형태
+    #[serde(default)]
+    pub color: Option<String>,
+    // 클수록 위에 그립니다.
+    #[serde(default)]
+    pub z_index: i32,
+    // 접으면 이름(헤더)만 보입니다.
+    #[serde(default)]
+    pub collapsed: bool,
 }
 
 impl Entity {
@@ -39,13 +59,37 @@ impl Entity {
             "comment".to_string(),
             AttributeValue::S(self.comment.to_owned()),
         );
-        map.insert("x".to_string(), AttributeValue::S(self.x.to_owned()));
-        map.insert("y".to_string(), AttributeValue::S(self.y.to_owned()));
+        map.insert("x".to_string(), AttributeValue::N(self.x.to_string()));
+        map.insert("y".to_string(), AttributeValue::N(self.y.to_string()));
+        map.insert(
+            "z_index".to_string(),
+            AttributeValue::N(self.z_index.to_string()),
+        );
+        map.insert(
+            "collapsed".to_string(),
+            AttributeValue::Bool(self.collapsed),
+        );
+
+        if let Some(width) = self.width {
+            map.insert("width".to_string(), AttributeValue::N(width.to_string()));
+        }
+
+        if let Some(height) = self.height {
+            map.insert("height".to_string(), AttributeValue::N(height.to_string()));
+        }
+
+        if let Some(color) = self.color.as_ref() {
+            map.insert("color".to_string(), AttributeValue::S(color.to_owned()));
+        }
 
         if let Ok(colmns) = serde_json::to_string(&self.columns) {
             map.insert("columns".to_string(), AttributeValue::S(colmns));
         }
 
+        if let Ok(indexes) = serde_json::to_string(&self.indexes) {
+            map.insert("indexes".to_string(), AttributeValue::S(indexes));
+        }
+
         Some(map)
     }
 
@@ -62,8 +106,26 @@ impl Entity {
             .map(|e| e.to_owned())
             .unwrap_or("".to_string());
         let columns = serde_json::from_str(columns.as_str()).unwrap_or(vec![]);
-        let x = hashmap.get("x")?.as_s().ok()?.to_owned();
-        let y = hashmap.get("y")?.as_s().ok()?.to_owned();
+        let indexes = hashmap
+            .get("indexes")
+            .and_then(|e| e.as_s().ok())
+            .and_then(|e| serde_json::from_str(e).ok())
+            .unwrap_or(vec![]);
+        let x = geometry::number_from_attribute(hashmap.get("x")).unwrap_or_default();
+        let y = geometry::number_from_attribute(hashmap.get("y")).unwrap_or_default();
+        let width = geometry::number_from_attribute(hashmap.get("width")).filter(|e| *e > 0.0);
+        let height = geometry::number_from_attribute(hashmap.get("height")).filter(|e| *e > 0.0);
+        let color = hashmap
+            .get("color")
+            .and_then(|e| e.as_s().ok())
+            .map(|e| e.to_owned());
+        let z_index = geometry::number_from_attribute(hashmap.get("z_index"))
+            .map(|e| e as i32)
+            .unwrap_or_default();
+        let collapsed = hashmap
+            .get("collapsed")
+            .and_then(|e| e.as_bool().ok().copied())
+            .unwrap_or_default();
 
         Some(Self {
             id,
@@ -72,10 +134,100 @@ impl Entity {
             physical_name,
             comment,
             columns,
+            indexes,
             x,
             y,
+            width,
+            height,
+            color,
+            z_index,
+            collapsed,
         })
     }
+
+    pub fn validate_geometry(&self) -> Result<(), String> {
+        geometry::validate(
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            self.color.as_deref(),
+        )
+    }
+
+    // 인덱스가 참조하는 컬럼이 모두 이 엔티티에 있는지 확인합니다.
+    pub fn validate_indexes(&self) -> Result<(), String> {
+        for (i, index) in self.indexes.iter().enumerate() {
+            if index.name.trim().is_empty() {
+                return Err("인덱스 이름이 비어 있습니다.".into());
+            }
+
+            if self.indexes[..i].iter().any(|e| e.name == index.name) {
+                return Err(format!("인덱스 이름이 중복됩니다: {}", index.name));
+            }
+
+            if index.columns.is_empty() {
+                return Err(format!("인덱스에 컬럼이 없습니다: {}", index.name));
+            }
+
+            for (j, index_column) in index.columns.iter().enumerate() {
+                if !self.columns.iter().any(|e| e.id == index_column.column_id) {
+                    return Err(format!(
+                        "인덱스 {}가 존재하지 않는 컬럼을 참조합니다: {}",
+                        index.name, index_column.column_id
+                    ));
+                }
+
+                if index.columns[..j]
+                    .iter()
+                    .any(|e| e.column_id == index_column.column_id)
+                {
+                    return Err(format!(
+                        "인덱스 {}에 같은 컬럼이 중복됩니다: {}",
+                        index.name, index_column.column_id
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // 삭제된 컬럼을 인덱스에서 빼고, 컬럼이 모두 빠진 인덱스는 지웁니다.
+    // 인덱스를 보내지 않고 컬럼만 수정할 때 씁니다.
+    pub fn prune_indexes(&mut self) {
+        let columns = &self.columns;
+
+        for index in self.indexes.iter_mut() {
+            index
+                .columns
+                .retain(|e| columns.iter().any(|column| column.id == e.column_id));
+        }
+
+        self.indexes.retain(|e| !e.columns.is_empty());
+    }
+
+    // 도메인을 참조하는 컬럼에 도메인 속성을 반영합니다.
+    pub fn apply_domains(&mut self, domains: &[Domain]) -> Result<(), String> {
+        for column in self.columns.iter_mut() {
+            let domain_id = match column.domain_id.as_ref() {
+                Some(domain_id) => domain_id,
+                None => continue,
+            };
+
+            let domain = domains
+                .iter()
+                .filter(|e| {
+                    e.project_id.is_none() || e.project_id.as_ref() == Some(&self.project_id)
+                })
+                .find(|e| &e.id == domain_id)
+                .ok_or(format!("존재하지 않는 도메인입니다: {domain_id}"))?;
+
+            domain.apply_to(column, None);
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,4 +239,88 @@ pub struct Column {
     pub data_type: String,
     pub nullable: bool,
     pub comment: String,
+    #[serde(default)]
+    pub foreign_key: Option<ForeignKey>,
+    // 이하 항목은 기존에 저장된 컬럼에는 없으므로 기본값으로 읽습니다.
+    #[serde(default)]
+    pub length: Option<u32>,
+    #[serde(default)]
+    pub precision: Option<u32>,
+    #[serde(default)]
+    pub scale: Option<u32>,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub is_unique: bool,
+    #[serde(default)]
+    pub is_auto_increment: bool,
+    #[serde(default)]
+    pub is_unsigned: bool,
+    #[serde(default)]
+    pub check: Option<String>,
+    // 도메인을 참조하는 컬럼은 타입/길이/기본값 등을 도메인에서 받아옵니다.
+    #[serde(default)]
+    pub domain_id: Option<String>,
+}
+
+impl Column {
+    // 길이/정밀도를 포함한 타입 표기 (예: VARCHAR(255), DECIMAL(10,2))
+    // data_type에 이미 괄호가 들어있는 예전 데이터는 그대로 사용합니다.
+    pub fn full_type(&self) -> String {
+        if self.data_type.contains('(') {
+            return self.data_type.to_owned();
+        }
+
+        match (self.length, self.precision, self.scale) {
+            (Some(length), _, _) => format!("{}({length})", self.data_type),
+            (None, Some(precision), Some(scale)) => {
+                format!("{}({precision},{scale})", self.data_type)
+            }
+            (None, Some(precision), None) => format!("{}({precision})", self.data_type),
+            _ => self.data_type.to_owned(),
+        }
+    }
+}
+
+// 컬럼 간 관계 (FK -> 참조 대상 엔티티의 컬럼)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForeignKey {
+    pub entity_id: String,
+    pub column_id: String,
+}
+
+// 인덱스 모델 (엔티티 안에 JSON으로 저장)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Index {
+    pub id: String,
+    pub name: String,
+    pub columns: Vec<IndexColumn>,
+    pub is_unique: bool,
+    #[serde(default)]
+    pub index_type: IndexType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexColumn {
+    pub column_id: String,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IndexType {
+    #[default]
+    Btree,
+    Hash,
+    Fulltext,
+    Spatial,
 }
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+use super::Entity;
+
+// 엔티티 변경 이력 모델
+// 변경 전/후 엔티티 상태를 그대로 저장하고, 차이는 조회할 때 계산합니다.
+// 테이블 키는 entity_id(파티션 키) + revision(정렬 키)입니다. 같은 리비전은 한 번만 기록됩니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HistoryAction {
+    Create,
+    Update,
+    Delete,
+    Revert,
+}
+
+impl From<HistoryAction> for String {
+    fn from(value: HistoryAction) -> Self {
+        match value {
+            HistoryAction::Create => "CREATE".to_string(),
+            HistoryAction::Update => "UPDATE".to_string(),
+            HistoryAction::Delete => "DELETE".to_string(),
+            HistoryAction::Revert => "REVERT".to_string(),
+        }
+    }
+}
+
+impl TryFrom<String> for HistoryAction {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "CREATE" => Ok(HistoryAction::Create),
+            "UPDATE" => Ok(HistoryAction::Update),
+            "DELETE" => Ok(HistoryAction::Delete),
+            "REVERT" => Ok(HistoryAction::Revert),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityHistory {
+    pub id: String,
+    pub entity_id: String,
+    pub project_id: String,
+    pub revision: u64,
+    pub action: HistoryAction,
+    pub actor_id: String,
+    pub created_at: u64,
+    pub before: Option<Entity>,
+    pub after: Option<Entity>,
+}
+
+impl EntityHistory {
+    pub const NAME: &'static str = "modeler_entity_history";
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "entity_id".to_string(),
+            AttributeValue::S(self.entity_id.to_owned()),
+        );
+        map.insert(
+            "project_id".to_string(),
+            AttributeValue::S(self.project_id.to_owned()),
+        );
+        map.insert(
+            "revision".to_string(),
+            AttributeValue::N(self.revision.to_string()),
+        );
+        map.insert("action".to_string(), AttributeValue::S(self.action.into()));
+        map.insert(
+            "actor_id".to_string(),
+            AttributeValue::S(self.actor_id.to_owned()),
+        );
+        map.insert(
+            "created_at".to_string(),
+            AttributeValue::N(self.created_at.to_string()),
+        );
+
+        if let Some(before) = self.before.as_ref() {
+            map.insert(
+                "before".to_string(),
+                AttributeValue::S(serde_json::to_string(before).ok()?),
+            );
+        }
+
+        if let Some(after) = self.after.as_ref() {
+            map.insert(
+                "after".to_string(),
+                AttributeValue::S(serde_json::to_string(after).ok()?),
+            );
+        }
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let entity_id = hashmap.get("entity_id")?.as_s().ok()?.to_owned();
+        let project_id = hashmap.get("project_id")?.as_s().ok()?.to_owned();
+        let revision = hashmap.get("revision")?.as_n().ok()?.parse::<u64>().ok()?;
+        let action = hashmap
+            .get("action")?
+            .as_s()
+            .ok()?
+            .to_owned()
+            .try_into()
+            .ok()?;
+        let actor_id = hashmap.get("actor_id")?.as_s().ok()?.to_owned();
+        let created_at = hashmap
+            .get("created_at")
+            .and_then(|e| e.as_n().ok())
+            .and_then(|e| e.parse::<u64>().ok())
+            .unwrap_or_default();
+        let before = hashmap
+            .get("before")
+            .and_then(|e| e.as_s().ok())
+            .and_then(|e| serde_json::from_str(e).ok());
+        let after = hashmap
+            .get("after")
+            .and_then(|e| e.as_s().ok())
+            .and_then(|e| serde_json::from_str(e).ok());
+
+        Some(Self {
+            id,
+            entity_id,
+            project_id,
+            revision,
+            action,
+            actor_id,
+            created_at,
+            before,
+            after,
+        })
+    }
+}
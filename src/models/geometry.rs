@@ -0,0 +1,95 @@
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Deserializer};
+
+// 다이어그램 위의 위치/크기/스타일 (엔티티, 노트, 다이어그램 항목 공통)
+// 예전에는 좌표를 문자열로 저장했으므로, 읽을 때는 숫자와 문자열을 모두 받아 숫자로 옮깁니다.
+// 저장할 때는 항상 숫자(N)로 쓰므로, 문자열로 남은 예전 항목은 다음에 저장될 때 숫자로 바뀝니다.
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(f64),
+    String(String),
+}
+
+impl NumberOrString {
+    fn value(self) -> Option<f64> {
+        match self {
+            NumberOrString::Number(value) => Some(value),
+            NumberOrString::String(value) => value.trim().parse::<f64>().ok(),
+        }
+    }
+}
+
+// 저장된 예전 좌표를 읽을 때 씁니다. 숫자로 읽을 수 없는 좌표는 0으로 읽습니다.
+pub fn deserialize_coordinate<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(NumberOrString::deserialize(deserializer)?
+        .value()
+        .filter(|e| e.is_finite())
+        .unwrap_or_default())
+}
+
+// 요청 본문의 좌표를 읽을 때 씁니다.
+// 숫자로 읽을 수 없는 좌표는 NaN으로 두어, validate에서 400으로 거절되게 합니다.
+pub fn deserialize_request_coordinate<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(NumberOrString::deserialize(deserializer)?
+        .value()
+        .unwrap_or(f64::NAN))
+}
+
+pub fn deserialize_size<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<NumberOrString>::deserialize(deserializer)?
+        .and_then(NumberOrString::value)
+        .filter(|e| e.is_finite() && *e > 0.0))
+}
+
+// DynamoDB 항목의 숫자 값 (예전 항목은 문자열)
+pub fn number_from_attribute(value: Option<&AttributeValue>) -> Option<f64> {
+    match value? {
+        AttributeValue::N(value) | AttributeValue::S(value) => value.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+    .filter(|e| e.is_finite())
+}
+
+pub fn is_valid_color(color: &str) -> bool {
+    match color.strip_prefix('#') {
+        Some(hex) => [3, 6].contains(&hex.len()) && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    }
+}
+
+pub fn validate(
+    x: f64,
+    y: f64,
+    width: Option<f64>,
+    height: Option<f64>,
+    color: Option<&str>,
+) -> Result<(), String> {
+    if !x.is_finite() || !y.is_finite() {
+        return Err("좌표가 올바르지 않습니다.".into());
+    }
+
+    for size in [width, height].into_iter().flatten() {
+        if !size.is_finite() || size <= 0.0 {
+            return Err(format!("크기는 0보다 커야 합니다: {size}"));
+        }
+    }
+
+    if let Some(color) = color {
+        if !is_valid_color(color) {
+            return Err(format!("색상 형식이 올바르지 않습니다: {color}"));
+        }
+    }
+
+    Ok(())
+}
@@ -7,6 +7,8 @@ pub use team::*;
 pub mod team_user;
 pub use team_user::*;
 
+pub mod geometry;
+
 pub mod entity;
 pub use entity::*;
 
@@ -18,3 +20,24 @@ pub use project::*;
 
 pub mod team_invite;
 pub use team_invite::*;
+
+pub mod standard_term;
+pub use standard_term::*;
+
+pub mod naming_rule;
+pub use naming_rule::*;
+
+pub mod domain;
+pub use domain::*;
+
+pub mod snapshot;
+pub use snapshot::*;
+
+pub mod entity_history;
+pub use entity_history::*;
+
+pub mod diagram;
+pub use diagram::*;
+
+pub mod project_activity;
+pub use project_activity::*;
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::Dialect;
+
+// 명명 규칙 모델 (project_id가 없으면 팀 전체 규칙, 있으면 해당 프로젝트 전용 규칙)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamingRule {
+    pub id: String,
+    pub team_id: String,
+    pub project_id: Option<String>,
+    pub config: NamingConfig,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamingConfig {
+    #[serde(default)]
+    pub enforcement: NamingEnforcement,
+    #[serde(default)]
+    pub dialect: Dialect,
+    #[serde(default)]
+    pub table: IdentifierRule,
+    #[serde(default)]
+    pub column: IdentifierRule,
+    #[serde(default)]
+    pub table_plurality: Plurality,
+    // 지정하지 않으면 방언의 최대 식별자 길이를 사용합니다.
+    #[serde(default)]
+    pub max_length: Option<usize>,
+    #[serde(default)]
+    pub forbidden_words: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IdentifierRule {
+    #[serde(default)]
+    pub case_style: Option<CaseStyle>,
+    #[serde(default)]
+    pub prefix: String,
+    #[serde(default)]
+    pub suffix: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NamingEnforcement {
+    #[default]
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseStyle {
+    Snake,
+    UpperSnake,
+    Camel,
+    Pascal,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Plurality {
+    #[default]
+    Any,
+    Singular,
+    Plural,
+}
+
+impl NamingRule {
+    pub const NAME: &'static str = "modeler_naming_rule";
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "team_id".to_string(),
+            AttributeValue::S(self.team_id.to_owned()),
+        );
+
+        if let Some(project_id) = self.project_id.clone() {
+            map.insert("project_id".to_string(), AttributeValue::S(project_id));
+        }
+
+        if let Ok(config) = serde_json::to_string(&self.config) {
+            map.insert("config".to_string(), AttributeValue::S(config));
+        }
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let team_id = hashmap.get("team_id")?.as_s().ok()?.to_owned();
+        let project_id = hashmap
+            .get("project_id")
+            .and_then(|e| e.as_s().ok().map(|e| e.to_owned()));
+        let config = hashmap
+            .get("config")
+            .and_then(|e| e.as_s().ok())
+            .and_then(|e| serde_json::from_str(e).ok())
+            .unwrap_or_default();
+
+        Some(Self {
+            id,
+            team_id,
+            project_id,
+            config,
+        })
+    }
+}
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use aws_sdk_dynamodb::model::AttributeValue;
 use serde::{Deserialize, Serialize};
 
+use super::geometry;
+
 // 엔티티 모델
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,8 +12,25 @@ pub struct Note {
     pub id: String,
     pub project_id: String,
     pub content: String,
-    pub x: String,
-    pub y: String,
+    #[serde(deserialize_with = "geometry::deserialize_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_coordinate")]
+    pub y: f64,
+    // 이하 항목은 기존에 저장된 노트에는 없으므로 기본값으로 읽습니다.
+    // 크기가 없으면 내용에 맞춘 기본 크기로 그립니다.
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    // #rgb, #rrggbb 형태
+    #[serde(default)]
+    pub color: Option<String>,
+    // 클수록 위에 그립니다.
+    #[serde(default)]
+    pub z_index: i32,
+    // 접으면 첫 줄만 보입니다.
+    #[serde(default)]
+    pub collapsed: bool,
 }
 
 impl Note {
@@ -28,8 +47,28 @@ impl Note {
             "content".to_string(),
             AttributeValue::S(self.content.to_owned()),
         );
-        map.insert("x".to_string(), AttributeValue::S(self.x.to_owned()));
-        map.insert("y".to_string(), AttributeValue::S(self.y.to_owned()));
+        map.insert("x".to_string(), AttributeValue::N(self.x.to_string()));
+        map.insert("y".to_string(), AttributeValue::N(self.y.to_string()));
+        map.insert(
+            "z_index".to_string(),
+            AttributeValue::N(self.z_index.to_string()),
+        );
+        map.insert(
+            "collapsed".to_string(),
+            AttributeValue::Bool(self.collapsed),
+        );
+
+        if let Some(width) = self.width {
+            map.insert("width".to_string(), AttributeValue::N(width.to_string()));
+        }
+
+        if let Some(height) = self.height {
+            map.insert("height".to_string(), AttributeValue::N(height.to_string()));
+        }
+
+        if let Some(color) = self.color.as_ref() {
+            map.insert("color".to_string(), AttributeValue::S(color.to_owned()));
+        }
 
         Some(map)
     }
@@ -38,8 +77,21 @@ impl Note {
         let id = hashmap.get("id")?.as_s().ok()?.to_owned();
         let project_id = hashmap.get("project_id")?.as_s().ok()?.to_owned();
         let content = hashmap.get("content")?.as_s().ok()?.to_owned();
-        let x = hashmap.get("x")?.as_s().ok()?.to_owned();
-        let y = hashmap.get("y")?.as_s().ok()?.to_owned();
+        let x = geometry::number_from_attribute(hashmap.get("x")).unwrap_or_default();
+        let y = geometry::number_from_attribute(hashmap.get("y")).unwrap_or_default();
+        let width = geometry::number_from_attribute(hashmap.get("width")).filter(|e| *e > 0.0);
+        let height = geometry::number_from_attribute(hashmap.get("height")).filter(|e| *e > 0.0);
+        let color = hashmap
+            .get("color")
+            .and_then(|e| e.as_s().ok())
+            .map(|e| e.to_owned());
+        let z_index = geometry::number_from_attribute(hashmap.get("z_index"))
+            .map(|e| e as i32)
+            .unwrap_or_default();
+        let collapsed = hashmap
+            .get("collapsed")
+            .and_then(|e| e.as_bool().ok().copied())
+            .unwrap_or_default();
 
         Some(Self {
             id,
@@ -47,6 +99,21 @@ impl Note {
             content,
             x,
             y,
+            width,
+            height,
+            color,
+            z_index,
+            collapsed,
         })
     }
+
+    pub fn validate_geometry(&self) -> Result<(), String> {
+        geometry::validate(
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            self.color.as_deref(),
+        )
+    }
 }
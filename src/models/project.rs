@@ -12,6 +12,9 @@ pub struct Project {
     pub description: String,
     pub name: String,
     pub thumbnail_url: Option<String>,
+    // 팀 템플릿 프로젝트 (새 프로젝트를 만들 때 골라서 내용을 복사합니다)
+    #[serde(default)]
+    pub is_template: bool,
 }
 
 impl Project {
@@ -30,6 +33,11 @@ impl Project {
             AttributeValue::S(self.team_id.to_owned()),
         );
 
+        map.insert(
+            "is_template".to_string(),
+            AttributeValue::Bool(self.is_template),
+        );
+
         if let Some(thumbnail_url) = self.thumbnail_url.clone() {
             map.insert(
                 "thumbnail_url".to_string(),
@@ -50,6 +58,10 @@ impl Project {
         let thumbnail_url = hashmap
             .get("thumbnail_url")
             .and_then(|e| e.as_s().ok().map(|e| e.to_owned()));
+        let is_template = hashmap
+            .get("is_template")
+            .and_then(|e| e.as_bool().ok().copied())
+            .unwrap_or_default();
 
         Some(Self {
             id,
@@ -57,6 +69,7 @@ impl Project {
             description,
             team_id,
             thumbnail_url,
+            is_template,
         })
     }
 }
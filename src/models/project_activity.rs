@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+// 프로젝트 활동 이력 모델
+// 팀 이동처럼 엔티티 단위 이력에 남지 않는 프로젝트 단위 변경을 기록합니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectActivityAction {
+    Transfer,
+}
+
+impl From<ProjectActivityAction> for String {
+    fn from(value: ProjectActivityAction) -> Self {
+        match value {
+            ProjectActivityAction::Transfer => "TRANSFER".to_string(),
+        }
+    }
+}
+
+impl TryFrom<String> for ProjectActivityAction {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "TRANSFER" => Ok(ProjectActivityAction::Transfer),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectActivity {
+    pub id: String,
+    pub project_id: String,
+    pub action: ProjectActivityAction,
+    pub actor_id: String,
+    pub created_at: u64,
+    // 팀 이동일 때만 채워집니다.
+    pub from_team_id: Option<String>,
+    pub to_team_id: Option<String>,
+}
+
+impl ProjectActivity {
+    pub const NAME: &'static str = "modeler_project_activity";
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "project_id".to_string(),
+            AttributeValue::S(self.project_id.to_owned()),
+        );
+        map.insert("action".to_string(), AttributeValue::S(self.action.into()));
+        map.insert(
+            "actor_id".to_string(),
+            AttributeValue::S(self.actor_id.to_owned()),
+        );
+        map.insert(
+            "created_at".to_string(),
+            AttributeValue::N(self.created_at.to_string()),
+        );
+
+        if let Some(from_team_id) = self.from_team_id.as_ref() {
+            map.insert(
+                "from_team_id".to_string(),
+                AttributeValue::S(from_team_id.to_owned()),
+            );
+        }
+
+        if let Some(to_team_id) = self.to_team_id.as_ref() {
+            map.insert(
+                "to_team_id".to_string(),
+                AttributeValue::S(to_team_id.to_owned()),
+            );
+        }
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let project_id = hashmap.get("project_id")?.as_s().ok()?.to_owned();
+        let action = hashmap
+            .get("action")?
+            .as_s()
+            .ok()?
+            .to_owned()
+            .try_into()
+            .ok()?;
+        let actor_id = hashmap.get("actor_id")?.as_s().ok()?.to_owned();
+        let created_at = hashmap
+            .get("created_at")
+            .and_then(|e| e.as_n().ok())
+            .and_then(|e| e.parse::<u64>().ok())
+            .unwrap_or_default();
+        let from_team_id = hashmap
+            .get("from_team_id")
+            .and_then(|e| e.as_s().ok())
+            .map(|e| e.to_owned());
+        let to_team_id = hashmap
+            .get("to_team_id")
+            .and_then(|e| e.as_s().ok())
+            .map(|e| e.to_owned());
+
+        Some(Self {
+            id,
+            project_id,
+            action,
+            actor_id,
+            created_at,
+            from_team_id,
+            to_team_id,
+        })
+    }
+}
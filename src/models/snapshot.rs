@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+use super::{Diagram, Entity, Note, Project};
+
+// 프로젝트 스냅샷 모델 (한 번 만들면 수정하지 않습니다)
+// 본문(SnapshotData)은 DynamoDB 항목 크기 제한(400KB)을 넘을 수 있으므로 S3에 JSON으로 저장하고,
+// 테이블에는 목록/권한 확인에 필요한 정보와 S3 키만 둡니다.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub id: String,
+    pub project_id: String,
+    // 스냅샷 당시의 팀 (프로젝트가 삭제된 뒤 복원할 때 사용)
+    pub team_id: String,
+    pub name: String,
+    pub description: String,
+    pub created_by: String,
+    pub created_at: u64,
+    pub entity_count: usize,
+    pub note_count: usize,
+    pub data_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotData {
+    pub project: Project,
+    pub entities: Vec<Entity>,
+    pub notes: Vec<Note>,
+    // 다이어그램을 저장하기 전에 만든 스냅샷에는 없습니다.
+    #[serde(default)]
+    pub diagrams: Vec<Diagram>,
+}
+
+impl Snapshot {
+    pub const NAME: &'static str = "modeler_snapshot";
+
+    // 스냅샷 본문은 공개 이미지 버킷과 분리된 비공개 버킷에 저장합니다.
+    pub fn bucket() -> String {
+        std::env::var("SNAPSHOT_BUCKET").unwrap()
+    }
+
+    pub fn make_data_key(project_id: &str, snapshot_id: &str) -> String {
+        format!("snapshot/{project_id}/{snapshot_id}.json")
+    }
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "project_id".to_string(),
+            AttributeValue::S(self.project_id.to_owned()),
+        );
+        map.insert(
+            "team_id".to_string(),
+            AttributeValue::S(self.team_id.to_owned()),
+        );
+        map.insert("name".to_string(), AttributeValue::S(self.name.to_owned()));
+        map.insert(
+            "description".to_string(),
+            AttributeValue::S(self.description.to_owned()),
+        );
+        map.insert(
+            "created_by".to_string(),
+            AttributeValue::S(self.created_by.to_owned()),
+        );
+        map.insert(
+            "created_at".to_string(),
+            AttributeValue::N(self.created_at.to_string()),
+        );
+        map.insert(
+            "entity_count".to_string(),
+            AttributeValue::N(self.entity_count.to_string()),
+        );
+        map.insert(
+            "note_count".to_string(),
+            AttributeValue::N(self.note_count.to_string()),
+        );
+        map.insert(
+            "data_key".to_string(),
+            AttributeValue::S(self.data_key.to_owned()),
+        );
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let number = |key: &str| {
+            hashmap
+                .get(key)
+                .and_then(|e| e.as_n().ok())
+                .and_then(|e| e.parse::<u64>().ok())
+                .unwrap_or_default()
+        };
+
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let project_id = hashmap.get("project_id")?.as_s().ok()?.to_owned();
+        let team_id = hashmap.get("team_id")?.as_s().ok()?.to_owned();
+        let name = hashmap.get("name")?.as_s().ok()?.to_owned();
+        let description = hashmap
+            .get("description")
+            .and_then(|e| e.as_s().ok().map(|e| e.to_owned()))
+            .unwrap_or_default();
+        let created_by = hashmap.get("created_by")?.as_s().ok()?.to_owned();
+        let created_at = number("created_at");
+        let entity_count = number("entity_count") as usize;
+        let note_count = number("note_count") as usize;
+        let data_key = hashmap.get("data_key")?.as_s().ok()?.to_owned();
+
+        Some(Self {
+            id,
+            project_id,
+            team_id,
+            name,
+            description,
+            created_by,
+            created_at,
+            entity_count,
+            note_count,
+            data_key,
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::model::AttributeValue;
+use serde::{Deserialize, Serialize};
+
+// 표준 단어 사전 모델 (논리 단어 -> 물리 약어)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandardTerm {
+    pub id: String,
+    pub team_id: String,
+    pub logical_name: String,
+    pub physical_name: String,
+    pub description: String,
+}
+
+impl StandardTerm {
+    pub const NAME: &'static str = "modeler_standard_term";
+
+    pub fn to_hashmap(&self) -> Option<HashMap<String, AttributeValue>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), AttributeValue::S(self.id.to_owned()));
+        map.insert(
+            "team_id".to_string(),
+            AttributeValue::S(self.team_id.to_owned()),
+        );
+        map.insert(
+            "logical_name".to_string(),
+            AttributeValue::S(self.logical_name.to_owned()),
+        );
+        map.insert(
+            "physical_name".to_string(),
+            AttributeValue::S(self.physical_name.to_owned()),
+        );
+        map.insert(
+            "description".to_string(),
+            AttributeValue::S(self.description.to_owned()),
+        );
+
+        Some(map)
+    }
+
+    pub fn from_hashmap(hashmap: HashMap<String, AttributeValue>) -> Option<Self> {
+        let id = hashmap.get("id")?.as_s().ok()?.to_owned();
+        let team_id = hashmap.get("team_id")?.as_s().ok()?.to_owned();
+        let logical_name = hashmap.get("logical_name")?.as_s().ok()?.to_owned();
+        let physical_name = hashmap.get("physical_name")?.as_s().ok()?.to_owned();
+        let description = hashmap
+            .get("description")
+            .and_then(|e| e.as_s().ok().map(|e| e.to_owned()))
+            .unwrap_or_default();
+
+        Some(Self {
+            id,
+            team_id,
+            logical_name,
+            physical_name,
+            description,
+        })
+    }
+}
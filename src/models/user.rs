@@ -78,6 +78,7 @@ impl User {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InsertUser {
     pub nickname: String,
@@ -18,7 +18,9 @@ use tracing::{Level, Span};
 use crate::extensions::{CurrentUser, DynamoClient, S3Client};
 
 use crate::middlewares::{auth_middleware, response_header_middleware};
-use crate::routes::{auth, entity, note, project, redirect, team, user, utils};
+use crate::routes::{
+    auth, diagram, domain, entity, note, project, redirect, snapshot, team, term, user, utils,
+};
 use crate::utils::send_email;
 
 pub(crate) async fn router() -> Router {
@@ -45,6 +47,10 @@ pub(crate) async fn router() -> Router {
         .nest("/project", project::router().await)
         .nest("/note", note::router().await)
         .nest("/entity", entity::router().await)
+        .nest("/term", term::router().await)
+        .nest("/domain", domain::router().await)
+        .nest("/snapshot", snapshot::router().await)
+        .nest("/diagram", diagram::router().await)
         .route_layer(from_fn(response_header_middleware))
         .route_layer(middleware::from_fn(auth_middleware))
         .layer(Extension(DynamoClient::get_client().await))
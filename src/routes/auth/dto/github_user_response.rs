@@ -1,3 +1,4 @@
+#[allow(dead_code)]
 #[derive(serde::Deserialize)]
 pub struct GithubUserResponse {
     pub id: i64,
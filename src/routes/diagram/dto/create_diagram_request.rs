@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use super::DiagramItemRequest;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDiagramRequest {
+    pub project_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub items: Vec<DiagramItemRequest>,
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDiagramResponse {
+    pub success: bool,
+    pub diagram_id: String,
+}
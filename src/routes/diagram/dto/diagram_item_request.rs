@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{geometry, DiagramItem};
+
+// 다이어그램을 만들거나 고칠 때 받는 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagramItemRequest {
+    pub entity_id: String,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub y: f64,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl From<DiagramItemRequest> for DiagramItem {
+    fn from(item: DiagramItemRequest) -> Self {
+        Self {
+            entity_id: item.entity_id,
+            x: item.x,
+            y: item.y,
+            width: item.width,
+            height: item.height,
+            color: item.color,
+        }
+    }
+}
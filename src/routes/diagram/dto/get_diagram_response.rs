@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Diagram, DiagramItem};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDiagramItem {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub is_default: bool,
+    pub items: Vec<DiagramItem>,
+}
+
+impl From<Diagram> for GetDiagramItem {
+    fn from(value: Diagram) -> Self {
+        Self {
+            id: value.id,
+            project_id: value.project_id,
+            name: value.name,
+            is_default: value.is_default,
+            items: value.items,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDiagramResponse {
+    pub data: GetDiagramItem,
+}
@@ -0,0 +1,15 @@
+pub mod create_diagram_request;
+pub mod create_diagram_response;
+pub mod diagram_item_request;
+pub mod get_diagram_response;
+pub mod update_diagram_item_request;
+pub mod update_diagram_request;
+pub mod update_diagram_response;
+
+pub use create_diagram_request::CreateDiagramRequest;
+pub use create_diagram_response::CreateDiagramResponse;
+pub use diagram_item_request::DiagramItemRequest;
+pub use get_diagram_response::*;
+pub use update_diagram_item_request::UpdateDiagramItemRequest;
+pub use update_diagram_request::UpdateDiagramRequest;
+pub use update_diagram_response::UpdateDiagramResponse;
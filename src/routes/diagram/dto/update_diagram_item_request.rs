@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::geometry;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDiagramItemRequest {
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub y: f64,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub color: Option<String>,
+}
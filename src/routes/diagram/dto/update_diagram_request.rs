@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::DiagramItemRequest;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDiagramRequest {
+    pub name: String,
+    pub items: Vec<DiagramItemRequest>,
+}
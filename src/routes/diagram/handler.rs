@@ -0,0 +1,578 @@
+use std::sync::Arc;
+
+use aws_sdk_dynamodb::Client;
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Extension, Json, Router,
+};
+
+use crate::{
+    extensions::CurrentUser,
+    models::{Diagram, DiagramItem, Entity, HistoryAction, TeamUserAuthority},
+    routes::{
+        entity::EntityService, entity_history::EntityHistoryService, project::ProjectService,
+        team::TeamService,
+    },
+    utils::{generate_uuid, AllError},
+};
+
+use super::{
+    dto::{
+        CreateDiagramRequest, CreateDiagramResponse, GetDiagramResponse, UpdateDiagramItemRequest,
+        UpdateDiagramRequest, UpdateDiagramResponse,
+    },
+    DiagramService,
+};
+
+pub async fn router() -> Router {
+    Router::new()
+        .route("/", post(create_diagram))
+        .route("/:diagram_id", get(get_diagram))
+        .route("/:diagram_id", put(update_diagram))
+        .route("/:diagram_id", delete(delete_diagram))
+        .route("/:diagram_id/item/:entity_id", put(update_diagram_item))
+        .route("/:diagram_id/item/:entity_id", delete(delete_diagram_item))
+}
+
+// 기본 다이어그램의 배치는 엔티티의 x/y/크기/색으로 저장합니다. (바뀐 엔티티만, 이력 포함)
+async fn save_default_items(
+    database: &Extension<Arc<Client>>,
+    actor_id: &str,
+    diagram: &Diagram,
+    entity_list: &[Entity],
+) -> Result<(), AllError> {
+    let entity_service = EntityService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+
+    let changed = diagram.apply_to_entities(entity_list);
+    let entities = changed.iter().map(|(_, e)| e.clone()).collect::<Vec<_>>();
+
+    entity_service.batch_write_entities(&entities, &[]).await?;
+
+    for (before, after) in changed {
+        if let Err(error) = entity_history_service
+            .record_history(actor_id, HistoryAction::Update, Some(before), Some(after))
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_diagram(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Json(body): Json<CreateDiagramRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&body.project_id).await {
+        Ok(project) => project,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if body.name.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "다이어그램 이름이 비어 있습니다.").into_response();
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project.id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let diagram = Diagram {
+        id: generate_uuid(),
+        project_id: project.id,
+        name: body.name.trim().to_owned(),
+        is_default: false,
+        items: body.items.into_iter().map(Into::into).collect(),
+    };
+
+    if let Err(message) = diagram.validate_items(&entity_list) {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    let mut response = CreateDiagramResponse {
+        success: false,
+        diagram_id: "".into(),
+    };
+
+    match diagram_service.create_diagram(diagram).await {
+        Ok(diagram_id) => {
+            response.diagram_id = diagram_id;
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn get_diagram(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(diagram_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let mut diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+        Ok(diagram) => diagram,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let project = match project_service.get_project_by_id(&diagram.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project.id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    diagram.sync_entities(&entity_list);
+
+    let response = GetDiagramResponse {
+        data: diagram.into(),
+    };
+
+    Json(response).into_response()
+}
+
+async fn update_diagram(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(diagram_id): Path<String>,
+    Json(body): Json<UpdateDiagramRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let mut diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+        Ok(diagram) => diagram,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let project = match project_service.get_project_by_id(&diagram.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if body.name.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "다이어그램 이름이 비어 있습니다.").into_response();
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project.id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    diagram.name = body.name.trim().to_owned();
+    diagram.items = body.items.into_iter().map(Into::into).collect();
+
+    if let Err(message) = diagram.validate_items(&entity_list) {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    if diagram.is_default {
+        if let Err(error) = save_default_items(&database, &user.id, &diagram, &entity_list).await {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let mut response = UpdateDiagramResponse { success: false };
+
+    match diagram_service.create_diagram(diagram).await {
+        Ok(_) => response.success = true,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_diagram(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(diagram_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+        Ok(diagram) => diagram,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let project = match project_service.get_project_by_id(&diagram.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if diagram.is_default {
+        return (
+            StatusCode::BAD_REQUEST,
+            "기본 다이어그램은 삭제할 수 없습니다.",
+        )
+            .into_response();
+    }
+
+    let mut response = UpdateDiagramResponse { success: false };
+
+    match diagram_service.delete_diagram(&diagram_id).await {
+        Ok(_) => response.success = true,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+// 엔티티 하나의 배치만 바꿉니다. (다이어그램에 없던 엔티티면 추가)
+async fn update_diagram_item(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((diagram_id, entity_id)): Path<(String, String)>,
+    Json(body): Json<UpdateDiagramItemRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let mut diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+        Ok(diagram) => diagram,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let project = match project_service.get_project_by_id(&diagram.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project.id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let item = DiagramItem {
+        entity_id: entity_id.clone(),
+        x: body.x,
+        y: body.y,
+        width: body.width,
+        height: body.height,
+        color: body.color,
+    };
+
+    match diagram.items.iter_mut().find(|e| e.entity_id == entity_id) {
+        Some(old_item) => *old_item = item,
+        None => diagram.items.push(item),
+    }
+
+    if let Err(message) = diagram.validate_items(&entity_list) {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    if diagram.is_default {
+        if let Err(error) = save_default_items(&database, &user.id, &diagram, &entity_list).await {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let mut response = UpdateDiagramResponse { success: false };
+
+    match diagram_service.create_diagram(diagram).await {
+        Ok(_) => response.success = true,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+// 다이어그램에서 엔티티를 뺍니다. (엔티티 자체는 지우지 않습니다)
+async fn delete_diagram_item(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((diagram_id, entity_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let mut diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+        Ok(diagram) => diagram,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let project = match project_service.get_project_by_id(&diagram.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if diagram.is_default {
+        return (
+            StatusCode::BAD_REQUEST,
+            "기본 다이어그램에는 모든 엔티티가 놓이므로 뺄 수 없습니다.",
+        )
+            .into_response();
+    }
+
+    let count = diagram.items.len();
+    diagram.items.retain(|e| e.entity_id != entity_id);
+
+    if diagram.items.len() == count {
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    let mut response = UpdateDiagramResponse { success: false };
+
+    match diagram_service.create_diagram(diagram).await {
+        Ok(_) => response.success = true,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
@@ -0,0 +1,9 @@
+#![allow(unused_imports)]
+
+pub mod handler;
+pub use handler::*;
+
+pub mod dto;
+
+pub mod service;
+pub use service::*;
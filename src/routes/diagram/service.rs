@@ -0,0 +1,142 @@
+use std::{str::FromStr, sync::Arc};
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+use std::error::Error;
+
+use crate::{
+    models::{Diagram, Entity},
+    utils::AllError,
+};
+
+pub struct DiagramService {
+    client: Extension<Arc<Client>>,
+}
+
+impl DiagramService {
+    pub fn new(client: Extension<Arc<Client>>) -> Self {
+        Self { client }
+    }
+
+    pub async fn create_diagram(&self, data: Diagram) -> Result<String, AllError> {
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(Diagram::NAME)
+            .set_item(input)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(data.id),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_diagram_by_id(
+        &self,
+        diagram_id: impl Into<String>,
+    ) -> Result<Diagram, AllError> {
+        match self
+            .client
+            .scan()
+            .table_name(Diagram::NAME)
+            .filter_expression("id = :diagram_id")
+            .expression_attribute_values(":diagram_id", AttributeValue::S(diagram_id.into()))
+            .send()
+            .await
+        {
+            Ok(data) => data
+                .items()
+                .and_then(|items| {
+                    items
+                        .first()
+                        .and_then(|item| Diagram::from_hashmap(item.to_owned()))
+                })
+                .ok_or(AllError::NotFound),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn delete_diagram(&self, diagram_id: impl Into<String>) -> Result<(), AllError> {
+        match self
+            .client
+            .delete_item()
+            .table_name(Diagram::NAME)
+            .key("id", AttributeValue::S(diagram_id.into()))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    // 다이어그램을 쓰기 전의 프로젝트는 기본 다이어그램이 없으므로, 엔티티의 x/y로 만들어 저장합니다.
+    pub async fn get_diagram_list_with_default(
+        &self,
+        project_id: impl Into<String>,
+        entities: &[Entity],
+    ) -> Result<Vec<Diagram>, AllError> {
+        let project_id = project_id.into();
+        let mut list = self.get_diagram_list_by_project_id(&project_id).await?;
+
+        if !list.iter().any(|e| e.is_default) {
+            let diagram = Diagram::from_entities(&project_id, entities);
+            self.create_diagram(diagram.clone()).await?;
+            list.push(diagram);
+        }
+
+        for diagram in list.iter_mut() {
+            diagram.sync_entities(entities);
+        }
+
+        // 기본 다이어그램을 맨 앞에 두고 나머지는 이름 순
+        list.sort_by(|a, b| b.is_default.cmp(&a.is_default).then(a.name.cmp(&b.name)));
+
+        Ok(list)
+    }
+
+    pub async fn get_diagram_list_by_project_id(
+        &self,
+        project_id: impl Into<String>,
+    ) -> Result<Vec<Diagram>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let project_id = project_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(Diagram::NAME)
+                .filter_expression("project_id = :project_id")
+                .expression_attribute_values(":project_id", AttributeValue::S(project_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(diagram) = Diagram::from_hashmap(item.to_owned()) {
+                                list.push(diagram);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+}
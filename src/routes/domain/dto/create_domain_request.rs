@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+// project_id가 없으면 팀 전체에서 쓰는 도메인으로 만듭니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDomainRequest {
+    pub team_id: String,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    pub name: String,
+    pub data_type: String,
+    #[serde(default)]
+    pub length: Option<u32>,
+    #[serde(default)]
+    pub precision: Option<u32>,
+    #[serde(default)]
+    pub scale: Option<u32>,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    pub nullable: bool,
+    #[serde(default)]
+    pub comment: String,
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDomainResponse {
+    pub success: bool,
+    pub domain_id: String,
+}
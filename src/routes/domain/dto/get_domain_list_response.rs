@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+use super::GetDomainItem;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDomainListResponse {
+    pub list: Vec<GetDomainItem>,
+}
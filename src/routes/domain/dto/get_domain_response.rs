@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Domain;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDomainItem {
+    pub id: String,
+    pub team_id: String,
+    pub project_id: Option<String>,
+    pub name: String,
+    pub data_type: String,
+    pub length: Option<u32>,
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+    pub default_value: Option<String>,
+    pub nullable: bool,
+    pub comment: String,
+}
+
+impl From<Domain> for GetDomainItem {
+    fn from(e: Domain) -> Self {
+        Self {
+            id: e.id,
+            team_id: e.team_id,
+            project_id: e.project_id,
+            name: e.name,
+            data_type: e.data_type,
+            length: e.length,
+            precision: e.precision,
+            scale: e.scale,
+            default_value: e.default_value,
+            nullable: e.nullable,
+            comment: e.comment,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDomainResponse {
+    pub data: GetDomainItem,
+}
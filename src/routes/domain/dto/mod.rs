@@ -0,0 +1,13 @@
+pub mod create_domain_request;
+pub mod create_domain_response;
+pub mod get_domain_list_response;
+pub mod get_domain_response;
+pub mod update_domain_request;
+pub mod update_domain_response;
+
+pub use create_domain_request::CreateDomainRequest;
+pub use create_domain_response::CreateDomainResponse;
+pub use get_domain_list_response::*;
+pub use get_domain_response::*;
+pub use update_domain_request::UpdateDomainRequest;
+pub use update_domain_response::*;
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+// 도메인 변경(삭제 시 연결 해제)으로 컬럼이 바뀐 엔티티 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffectedEntity {
+    pub entity_id: String,
+    pub project_id: String,
+    pub logical_name: String,
+    pub physical_name: String,
+    pub column_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDomainResponse {
+    pub success: bool,
+    pub affected_entities: Vec<AffectedEntity>,
+}
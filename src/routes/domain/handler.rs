@@ -0,0 +1,457 @@
+use std::sync::Arc;
+
+use aws_sdk_dynamodb::Client;
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Extension, Json, Router,
+};
+use futures::future::join_all;
+
+use crate::{
+    extensions::CurrentUser,
+    models::{Domain, HistoryAction, TeamUserAuthority},
+    routes::{
+        entity::EntityService, entity_history::EntityHistoryService, project::ProjectService,
+        team::TeamService,
+    },
+    utils::{generate_uuid, AllError},
+};
+
+use super::{
+    dto::{
+        AffectedEntity, CreateDomainRequest, CreateDomainResponse, GetDomainResponse,
+        UpdateDomainRequest, UpdateDomainResponse,
+    },
+    DomainService,
+};
+
+pub async fn router() -> Router {
+    Router::new()
+        .route("/", post(create_domain))
+        .route("/:domain_id", get(get_domain))
+        .route("/:domain_id", put(update_domain))
+        .route("/:domain_id", delete(delete_domain))
+}
+
+// 도메인을 참조하는 모든 컬럼에 변경 사항을 반영합니다. detach면 참조만 끊습니다.
+// previous는 수정 전 도메인으로, 그 설명을 그대로 물려받은 컬럼을 찾는 데 씁니다.
+async fn propagate_domain(
+    database: Extension<Arc<Client>>,
+    actor_id: &str,
+    domain: &Domain,
+    previous: Option<&Domain>,
+    detach: bool,
+) -> Result<Vec<AffectedEntity>, AllError> {
+    let entity_service = EntityService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+
+    let project_ids = match domain.project_id.clone() {
+        Some(project_id) => vec![project_id],
+        None => project_service
+            .get_project_list_by_team_id(&domain.team_id)
+            .await?
+            .into_iter()
+            .map(|e| e.id)
+            .collect(),
+    };
+
+    let mut affected_entities = vec![];
+    let mut changed_entities = vec![];
+
+    for project_id in project_ids {
+        let entity_list = entity_service
+            .get_entity_list_by_project_id(&project_id)
+            .await?;
+
+        for mut entity in entity_list {
+            let before = entity.clone();
+            let mut column_ids = vec![];
+
+            for column in entity.columns.iter_mut() {
+                if column.domain_id.as_ref() != Some(&domain.id) {
+                    continue;
+                }
+
+                let changed = if detach {
+                    column.domain_id = None;
+                    true
+                } else {
+                    domain.apply_to(column, previous.map(|e| e.comment.as_str()))
+                };
+
+                if changed {
+                    column_ids.push(column.id.clone());
+                }
+            }
+
+            if column_ids.is_empty() {
+                continue;
+            }
+
+            affected_entities.push(AffectedEntity {
+                entity_id: entity.id.clone(),
+                project_id: entity.project_id.clone(),
+                logical_name: entity.logical_name.clone(),
+                physical_name: entity.physical_name.clone(),
+                column_ids,
+            });
+            changed_entities.push((before, entity));
+        }
+    }
+
+    let results = join_all(
+        changed_entities
+            .iter()
+            .map(|(_, entity)| entity_service.create_entity(entity.clone())),
+    )
+    .await;
+
+    if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+        return Err(error);
+    }
+
+    for (before, after) in changed_entities {
+        if let Err(error) = entity_history_service
+            .record_history(actor_id, HistoryAction::Update, Some(before), Some(after))
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+
+    Ok(affected_entities)
+}
+
+async fn create_domain(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Json(body): Json<CreateDomainRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let mut response = CreateDomainResponse {
+        success: false,
+        domain_id: "".into(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&body.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if let Some(project_id) = body.project_id.as_ref() {
+        match project_service.get_project_by_id(project_id).await {
+            Ok(project) => {
+                if project.team_id != body.team_id {
+                    return (StatusCode::BAD_REQUEST).into_response();
+                }
+            }
+            Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+        }
+    }
+
+    if body.name.trim().is_empty() || body.data_type.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let domain_list = match domain_service
+        .get_domain_list_by_team_id(&body.team_id)
+        .await
+    {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    if domain_list
+        .iter()
+        .any(|e| e.project_id == body.project_id && e.name == body.name.trim())
+    {
+        println!("# 도메인 이름 중복");
+        return (StatusCode::CONFLICT).into_response();
+    }
+
+    let data = Domain {
+        id: generate_uuid(),
+        team_id: body.team_id,
+        project_id: body.project_id,
+        name: body.name.trim().to_owned(),
+        data_type: body.data_type,
+        length: body.length,
+        precision: body.precision,
+        scale: body.scale,
+        default_value: body.default_value,
+        nullable: body.nullable,
+        comment: body.comment,
+    };
+
+    match domain_service.create_domain(data).await {
+        Ok(domain_id) => {
+            response.domain_id = domain_id;
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn update_domain(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(domain_id): Path<String>,
+    Json(body): Json<UpdateDomainRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let mut response = UpdateDomainResponse {
+        success: false,
+        affected_entities: vec![],
+    };
+
+    let domain = match domain_service.get_domain_by_id(&domain_id).await {
+        Ok(domain) => domain,
+        Err(error) => {
+            if let AllError::NotFound = error {
+                println!("# 도메인 없음");
+                return (StatusCode::NOT_FOUND).into_response();
+            } else {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&domain.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if body.name.trim().is_empty() || body.data_type.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let domain_list = match domain_service
+        .get_domain_list_by_team_id(&domain.team_id)
+        .await
+    {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    if domain_list.iter().any(|e| {
+        e.id != domain.id && e.project_id == domain.project_id && e.name == body.name.trim()
+    }) {
+        println!("# 도메인 이름 중복");
+        return (StatusCode::CONFLICT).into_response();
+    }
+
+    let previous = domain.clone();
+
+    let data = Domain {
+        name: body.name.trim().to_owned(),
+        data_type: body.data_type,
+        length: body.length,
+        precision: body.precision,
+        scale: body.scale,
+        default_value: body.default_value,
+        nullable: body.nullable,
+        comment: body.comment,
+        ..domain
+    };
+
+    if let Err(error) = domain_service.create_domain(data.clone()).await {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    match propagate_domain(database.clone(), &user.id, &data, Some(&previous), false).await {
+        Ok(affected_entities) => {
+            response.affected_entities = affected_entities;
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_domain(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(domain_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let mut response = UpdateDomainResponse {
+        success: false,
+        affected_entities: vec![],
+    };
+
+    let domain = match domain_service.get_domain_by_id(&domain_id).await {
+        Ok(domain) => domain,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&domain.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    // 컬럼의 타입 등은 그대로 두고 도메인 참조만 끊습니다.
+    match propagate_domain(database.clone(), &user.id, &domain, None, true).await {
+        Ok(affected_entities) => {
+            response.affected_entities = affected_entities;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match domain_service.delete_domain(&domain_id).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn get_domain(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(domain_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let domain = match domain_service.get_domain_by_id(&domain_id).await {
+        Ok(domain) => domain,
+        Err(error) => {
+            if let AllError::NotFound = error {
+                println!("# 도메인 없음");
+                return (StatusCode::NOT_FOUND).into_response();
+            } else {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&domain.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let response = GetDomainResponse {
+        data: domain.into(),
+    };
+
+    Json(response).into_response()
+}
@@ -0,0 +1,154 @@
+use std::{str::FromStr, sync::Arc};
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+use std::error::Error;
+
+use crate::{models::Domain, utils::AllError};
+
+pub struct DomainService {
+    client: Extension<Arc<Client>>,
+}
+
+impl DomainService {
+    pub fn new(client: Extension<Arc<Client>>) -> Self {
+        Self { client }
+    }
+
+    pub async fn create_domain(&self, data: Domain) -> Result<String, AllError> {
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(Domain::NAME)
+            .set_item(input)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(data.id),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_domain_by_id(&self, domain_id: impl Into<String>) -> Result<Domain, AllError> {
+        match self
+            .client
+            .scan()
+            .table_name(Domain::NAME)
+            .filter_expression("id = :domain_id")
+            .expression_attribute_values(":domain_id", AttributeValue::S(domain_id.into()))
+            .send()
+            .await
+        {
+            Ok(data) => data
+                .items()
+                .and_then(|items| {
+                    items
+                        .first()
+                        .and_then(|item| Domain::from_hashmap(item.to_owned()))
+                })
+                .ok_or(AllError::NotFound),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn delete_domain(&self, domain_id: impl Into<String>) -> Result<(), AllError> {
+        match self
+            .client
+            .delete_item()
+            .table_name(Domain::NAME)
+            .key("id", AttributeValue::S(domain_id.into()))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_domain_list_by_team_id(
+        &self,
+        team_id: impl Into<String>,
+    ) -> Result<Vec<Domain>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let team_id = team_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(Domain::NAME)
+                .filter_expression("team_id = :team_id")
+                .expression_attribute_values(":team_id", AttributeValue::S(team_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(domain) = Domain::from_hashmap(item.to_owned()) {
+                                list.push(domain);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+
+    // 팀과 상관없이 프로젝트 전용 도메인을 찾습니다. (팀을 옮기다 중단된 경우에도 찾을 수 있도록)
+    pub async fn get_domain_list_by_project_id(
+        &self,
+        project_id: impl Into<String>,
+    ) -> Result<Vec<Domain>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let project_id = project_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(Domain::NAME)
+                .filter_expression("project_id = :project_id")
+                .expression_attribute_values(":project_id", AttributeValue::S(project_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(domain) = Domain::from_hashmap(item.to_owned()) {
+                                list.push(domain);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+}
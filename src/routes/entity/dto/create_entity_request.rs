@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::Column;
+use crate::models::{geometry, Column, Index};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateEntityRequest {
@@ -9,6 +9,20 @@ pub struct CreateEntityRequest {
     pub physical_name: String,
     pub comment: String,
     pub columns: Vec<Column>,
-    pub x: String,
-    pub y: String,
+    #[serde(default)]
+    pub indexes: Vec<Index>,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub y: f64,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub z_index: i32,
+    #[serde(default)]
+    pub collapsed: bool,
 }
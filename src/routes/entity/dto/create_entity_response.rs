@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::utils::lint::LintFinding;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateEntityResponse {
     pub success: bool,
+    // 명명 규칙 위반 (Error 수준이 있으면 저장되지 않습니다)
+    pub naming_findings: Vec<LintFinding>,
     pub entity_id: String,
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{IndexColumn, IndexType};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateIndexRequest {
+    pub name: String,
+    pub columns: Vec<IndexColumn>,
+    pub is_unique: bool,
+    #[serde(default)]
+    pub index_type: IndexType,
+}
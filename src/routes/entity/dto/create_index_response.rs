@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateIndexResponse {
+    pub success: bool,
+    pub index_id: String,
+}
@@ -1,11 +1,19 @@
 pub mod create_entity_request;
 pub mod create_entity_response;
+pub mod create_index_request;
+pub mod create_index_response;
 pub mod get_entity_response;
 pub mod update_entity_request;
 pub mod update_entity_response;
+pub mod update_index_request;
+pub mod update_index_response;
 
 pub use create_entity_request::CreateEntityRequest;
 pub use create_entity_response::CreateEntityResponse;
+pub use create_index_request::CreateIndexRequest;
+pub use create_index_response::CreateIndexResponse;
 pub use get_entity_response::*;
 pub use update_entity_request::UpdateEntityRequest;
 pub use update_entity_response::UpdateEntityResponse;
+pub use update_index_request::UpdateIndexRequest;
+pub use update_index_response::UpdateIndexResponse;
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::Column;
+use crate::models::{geometry, Column, Index};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateEntityRequest {
@@ -8,6 +8,23 @@ pub struct UpdateEntityRequest {
     pub physical_name: String,
     pub comment: String,
     pub columns: Vec<Column>,
-    pub x: String,
-    pub y: String,
+    // 생략하면 기존 인덱스를 유지하되, 삭제된 컬럼은 인덱스에서 뺍니다.
+    #[serde(default)]
+    pub indexes: Option<Vec<Index>>,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub y: f64,
+    // 이하 항목은 생략하면 기존 값을 유지합니다.
+    // 크기를 0으로, 색상을 빈 문자열로 보내면 기본값으로 돌아갑니다.
+    #[serde(default)]
+    pub width: Option<f64>,
+    #[serde(default)]
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub z_index: Option<i32>,
+    #[serde(default)]
+    pub collapsed: Option<bool>,
 }
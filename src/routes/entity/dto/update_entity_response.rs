@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use crate::utils::lint::LintFinding;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateEntityResponse {
     pub success: bool,
+    pub naming_findings: Vec<LintFinding>,
 }
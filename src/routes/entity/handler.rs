@@ -2,26 +2,45 @@ use std::sync::Arc;
 
 use aws_sdk_dynamodb::Client;
 use axum::{
-    extract::Path,
+    extract::{Path, Query},
     http::StatusCode,
     response::{Html, IntoResponse},
     routing::{delete, get, post, put},
     Extension, Json, Router,
 };
+use epoch_timestamp::Epoch;
 use futures::future::join_all;
 
 use crate::{
     extensions::CurrentUser,
     middlewares::auth,
-    models::{project, Entity, InsertUser, Note, Project, Team, TeamUser, TeamUserAuthority, User},
-    routes::{auth::AuthService, project::ProjectService, team::TeamService, user::UserService},
-    utils::{generate_uuid, hash_password, AllError},
+    models::{
+        project, Entity, EntityHistory, HistoryAction, Index, InsertUser, Note, Project, Team,
+        TeamUser, TeamUserAuthority, User,
+    },
+    routes::{
+        auth::AuthService,
+        domain::DomainService,
+        entity_history::{
+            dto::{
+                GetEntityHistoryItem, GetEntityHistoryRequest, GetEntityHistoryResponse,
+                RevertEntityResponse,
+            },
+            EntityHistoryService,
+        },
+        naming_rule::NamingRuleService,
+        project::ProjectService,
+        team::TeamService,
+        user::UserService,
+    },
+    utils::{generate_uuid, hash_password, history, lint::LintSeverity, naming, remap, AllError},
 };
 
 use super::{
     dto::{
-        CreateEntityRequest, CreateEntityResponse, GetEntityItem, GetEntityResponse,
-        UpdateEntityRequest, UpdateEntityResponse,
+        CreateEntityRequest, CreateEntityResponse, CreateIndexRequest, CreateIndexResponse,
+        GetEntityItem, GetEntityResponse, UpdateEntityRequest, UpdateEntityResponse,
+        UpdateIndexRequest, UpdateIndexResponse,
     },
     EntityService,
 };
@@ -32,6 +51,11 @@ pub async fn router() -> Router {
         .route("/:entity_id", put(update_entity))
         .route("/:entity_id", get(get_entity))
         .route("/:entity_id", delete(delete_entity))
+        .route("/:entity_id/index", post(create_index))
+        .route("/:entity_id/index/:index_id", put(update_index))
+        .route("/:entity_id/index/:index_id", delete(delete_index))
+        .route("/:entity_id/history", get(get_entity_history))
+        .route("/:entity_id/history/:revision/revert", post(revert_entity))
 }
 
 async fn create_entity(
@@ -49,9 +73,13 @@ async fn create_entity(
     let entity_service = EntityService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
 
+    let naming_rule_service = NamingRuleService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
     let mut response = CreateEntityResponse {
         success: false,
         entity_id: "".into(),
+        naming_findings: vec![],
     };
 
     let project = match project_service
@@ -95,18 +123,67 @@ async fn create_entity(
         }
     }
 
-    let data = Entity {
+    let mut data = Entity {
         id: uuid::Uuid::new_v4().to_string(),
         project_id: body.project_id,
         physical_name: body.physical_name,
         logical_name: body.logical_name,
         comment: body.comment,
         columns: body.columns,
+        indexes: body.indexes,
         x: body.x,
         y: body.y,
+        width: body.width,
+        height: body.height,
+        color: body.color,
+        z_index: body.z_index,
+        collapsed: body.collapsed,
     };
 
-    match entity_service.create_entity(data).await {
+    if let Err(message) = data.validate_geometry() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    if let Err(message) = data.validate_indexes() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    let domain_list = match domain_service.get_domain_list_by_team_id(&team_id).await {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    if let Err(message) = data.apply_domains(&domain_list) {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    match naming_rule_service
+        .get_effective_naming_rule(&team_id, &data.project_id)
+        .await
+    {
+        Ok(Some(naming_rule)) => {
+            response.naming_findings = naming::check_entity(&data, &naming_rule.config);
+
+            if response
+                .naming_findings
+                .iter()
+                .any(|e| e.severity == LintSeverity::Error)
+            {
+                println!("# 명명 규칙 위반");
+                return (StatusCode::BAD_REQUEST, Json(response)).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match entity_service.create_entity(data.clone()).await {
         Ok(entity_id) => {
             response.entity_id = entity_id;
             response.success = true;
@@ -117,6 +194,13 @@ async fn create_entity(
         }
     }
 
+    if let Err(error) = EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Create, None, Some(data))
+        .await
+    {
+        println!("error: {error:?}");
+    }
+
     Json(response).into_response()
 }
 
@@ -136,7 +220,13 @@ async fn update_entity(
     let entity_service = EntityService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
 
-    let mut response = UpdateEntityResponse { success: false };
+    let naming_rule_service = NamingRuleService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let mut response = UpdateEntityResponse {
+        success: false,
+        naming_findings: vec![],
+    };
 
     let entity = match entity_service.get_entity_by_id(&entity_id).await {
         Ok(entity) => entity,
@@ -151,6 +241,7 @@ async fn update_entity(
         }
     };
 
+    let before = entity.clone();
     let project_id = &entity.project_id;
 
     let project = match project_service.get_project_by_id(project_id).await {
@@ -191,18 +282,78 @@ async fn update_entity(
         }
     }
 
-    let data = Entity {
+    let prune_indexes = body.indexes.is_none();
+
+    let mut data = Entity {
         id: entity_id,
         project_id: entity.project_id,
         physical_name: body.physical_name,
         logical_name: body.logical_name,
         comment: body.comment,
         columns: body.columns,
+        indexes: body.indexes.unwrap_or(entity.indexes),
         x: body.x,
         y: body.y,
+        width: body.width.map_or(entity.width, |e| (e != 0.0).then_some(e)),
+        height: body
+            .height
+            .map_or(entity.height, |e| (e != 0.0).then_some(e)),
+        color: body
+            .color
+            .map_or(entity.color, |e| (!e.is_empty()).then_some(e)),
+        z_index: body.z_index.unwrap_or(entity.z_index),
+        collapsed: body.collapsed.unwrap_or(entity.collapsed),
     };
 
-    match entity_service.create_entity(data).await {
+    if let Err(message) = data.validate_geometry() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    // 인덱스를 보내지 않았으면 삭제된 컬럼만 기존 인덱스에서 정리합니다.
+    if prune_indexes {
+        data.prune_indexes();
+    }
+
+    if let Err(message) = data.validate_indexes() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    let domain_list = match domain_service.get_domain_list_by_team_id(team_id).await {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    if let Err(message) = data.apply_domains(&domain_list) {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    match naming_rule_service
+        .get_effective_naming_rule(team_id, &data.project_id)
+        .await
+    {
+        Ok(Some(naming_rule)) => {
+            response.naming_findings = naming::check_entity(&data, &naming_rule.config);
+
+            if response
+                .naming_findings
+                .iter()
+                .any(|e| e.severity == LintSeverity::Error)
+            {
+                println!("# 명명 규칙 위반");
+                return (StatusCode::BAD_REQUEST, Json(response)).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match entity_service.create_entity(data.clone()).await {
         Ok(_) => {
             response.success = true;
         }
@@ -212,6 +363,13 @@ async fn update_entity(
         }
     }
 
+    if let Err(error) = EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Update, Some(before), Some(data))
+        .await
+    {
+        println!("error: {error:?}");
+    }
+
     Json(response).into_response()
 }
 
@@ -230,7 +388,10 @@ async fn delete_entity(
     let entity_service = EntityService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
 
-    let mut response = UpdateEntityResponse { success: false };
+    let mut response = UpdateEntityResponse {
+        success: false,
+        naming_findings: vec![],
+    };
 
     let entity = match entity_service.get_entity_by_id(&entity_id).await {
         Ok(entity) => entity,
@@ -275,6 +436,13 @@ async fn delete_entity(
         }
     }
 
+    if let Err(error) = EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Delete, Some(entity), None)
+        .await
+    {
+        println!("error: {error:?}");
+    }
+
     Json(response).into_response()
 }
 
@@ -301,8 +469,14 @@ async fn get_entity(
                 logical_name: entity.logical_name,
                 comment: entity.comment,
                 columns: entity.columns,
+                indexes: entity.indexes,
                 x: entity.x,
                 y: entity.y,
+                width: entity.width,
+                height: entity.height,
+                color: entity.color,
+                z_index: entity.z_index,
+                collapsed: entity.collapsed,
             },
             entity.project_id,
         ),
@@ -347,3 +521,518 @@ async fn get_entity(
 
     Json(response).into_response()
 }
+
+async fn create_index(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(entity_id): Path<String>,
+    Json(body): Json<CreateIndexRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let mut entity = match entity_service.get_entity_by_id(&entity_id).await {
+        Ok(entity) => entity,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let project = match project_service.get_project_by_id(&entity.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let before = entity.clone();
+
+    let mut response = CreateIndexResponse {
+        success: false,
+        index_id: uuid::Uuid::new_v4().to_string(),
+    };
+
+    entity.indexes.push(Index {
+        id: response.index_id.clone(),
+        name: body.name,
+        columns: body.columns,
+        is_unique: body.is_unique,
+        index_type: body.index_type,
+    });
+
+    if let Err(message) = entity.validate_indexes() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    match entity_service.create_entity(entity.clone()).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if let Err(error) = EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Update, Some(before), Some(entity))
+        .await
+    {
+        println!("error: {error:?}");
+    }
+
+    Json(response).into_response()
+}
+
+async fn update_index(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((entity_id, index_id)): Path<(String, String)>,
+    Json(body): Json<UpdateIndexRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let mut entity = match entity_service.get_entity_by_id(&entity_id).await {
+        Ok(entity) => entity,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let project = match project_service.get_project_by_id(&entity.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let before = entity.clone();
+
+    let mut response = UpdateIndexResponse { success: false };
+
+    match entity.indexes.iter_mut().find(|e| e.id == index_id) {
+        Some(index) => {
+            index.name = body.name;
+            index.columns = body.columns;
+            index.is_unique = body.is_unique;
+            index.index_type = body.index_type;
+        }
+        None => {
+            println!("# 인덱스 없음");
+            return (StatusCode::NOT_FOUND).into_response();
+        }
+    }
+
+    if let Err(message) = entity.validate_indexes() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    match entity_service.create_entity(entity.clone()).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if let Err(error) = EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Update, Some(before), Some(entity))
+        .await
+    {
+        println!("error: {error:?}");
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_index(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((entity_id, index_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let mut entity = match entity_service.get_entity_by_id(&entity_id).await {
+        Ok(entity) => entity,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let project = match project_service.get_project_by_id(&entity.project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let before = entity.clone();
+
+    let mut response = UpdateIndexResponse { success: false };
+
+    if !entity.indexes.iter().any(|e| e.id == index_id) {
+        println!("# 인덱스 없음");
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    entity.indexes.retain(|e| e.id != index_id);
+
+    match entity_service.create_entity(entity.clone()).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if let Err(error) = EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Update, Some(before), Some(entity))
+        .await
+    {
+        println!("error: {error:?}");
+    }
+
+    Json(response).into_response()
+}
+
+// 엔티티가 삭제된 뒤에도 이력으로 프로젝트를 찾을 수 있습니다.
+async fn find_history_team_id(
+    database: Extension<Arc<Client>>,
+    entity_id: &str,
+    history_list: &[EntityHistory],
+) -> Result<String, AllError> {
+    let entity_service = EntityService::new(database.clone());
+    let project_service = ProjectService::new(database);
+
+    let project_id = match history_list.first() {
+        Some(history) => history.project_id.clone(),
+        None => entity_service.get_entity_by_id(entity_id).await?.project_id,
+    };
+
+    Ok(project_service.get_project_by_id(project_id).await?.team_id)
+}
+
+async fn get_entity_history(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(entity_id): Path<String>,
+    Query(query): Query<GetEntityHistoryRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+
+    let mut history_list = match entity_history_service
+        .get_entity_history_list_by_entity_id(&entity_id)
+        .await
+    {
+        Ok(history_list) => history_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let team_id = match find_history_team_id(database.clone(), &entity_id, &history_list).await {
+        Ok(team_id) => team_id,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    history_list.sort_by_key(|e| std::cmp::Reverse(e.revision));
+
+    let page = query.page.unwrap_or(1).max(1);
+    let page_size = query.page_size.unwrap_or(20).clamp(1, 100);
+
+    let response = GetEntityHistoryResponse {
+        total: history_list.len(),
+        page,
+        page_size,
+        list: history_list
+            .into_iter()
+            .skip((page - 1) * page_size)
+            .take(page_size)
+            .map(|e| GetEntityHistoryItem {
+                revision: e.revision,
+                action: e.action,
+                actor_id: e.actor_id,
+                created_at: e.created_at,
+                changes: match (e.before.as_ref(), e.after.as_ref()) {
+                    (Some(before), Some(after)) => Some(history::entity_changes(before, after)),
+                    _ => None,
+                },
+                before: e.before,
+                after: e.after,
+            })
+            .collect(),
+    };
+
+    Json(response).into_response()
+}
+
+// 해당 리비전 직후의 상태로 되돌립니다. 삭제된 엔티티도 다시 만들어집니다.
+async fn revert_entity(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((entity_id, revision)): Path<(String, u64)>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let history_list = match entity_history_service
+        .get_entity_history_list_by_entity_id(&entity_id)
+        .await
+    {
+        Ok(history_list) => history_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let team_id = match find_history_team_id(database.clone(), &entity_id, &history_list).await {
+        Ok(team_id) => team_id,
+        Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let mut target = match history_list.into_iter().find(|e| e.revision == revision) {
+        Some(history) => match history.after {
+            Some(target) => target,
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "삭제 이력으로는 되돌릴 수 없습니다.",
+                )
+                    .into_response()
+            }
+        },
+        None => {
+            println!("# 이력 없음");
+            return (StatusCode::NOT_FOUND).into_response();
+        }
+    };
+
+    let before = match entity_service.get_entity_by_id(&entity_id).await {
+        Ok(entity) => Some(entity),
+        Err(AllError::NotFound) => None,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let mut response = RevertEntityResponse {
+        success: false,
+        revision: 0,
+        naming_findings: vec![],
+    };
+
+    // 수정할 때와 같은 검사를 거칩니다. 그 사이 삭제된 도메인은 연결만 끊고, 남은 도메인은 현재 속성으로 맞춥니다.
+    if let Err(message) = target.validate_geometry() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    if let Err(message) = target.validate_indexes() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    let domain_list = match domain_service.get_domain_list_by_team_id(&team_id).await {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    remap::detach_foreign_domains(std::slice::from_mut(&mut target), &domain_list);
+
+    if let Err(message) = target.apply_domains(&domain_list) {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    match naming_rule_service
+        .get_effective_naming_rule(&team_id, &target.project_id)
+        .await
+    {
+        Ok(Some(naming_rule)) => {
+            response.naming_findings = naming::check_entity(&target, &naming_rule.config);
+
+            if response
+                .naming_findings
+                .iter()
+                .any(|e| e.severity == LintSeverity::Error)
+            {
+                println!("# 명명 규칙 위반");
+                return (StatusCode::BAD_REQUEST, Json(response)).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match entity_service.create_entity(target.clone()).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match EntityHistoryService::new(database)
+        .record_history(&user.id, HistoryAction::Revert, before, Some(target))
+        .await
+    {
+        Ok(revision) => response.revision = revision,
+        Err(error) => println!("error: {error:?}"),
+    }
+
+    Json(response).into_response()
+}
@@ -6,7 +6,7 @@ use std::error::Error;
 
 use crate::{
     models::{Entity, Note, Project, Team, TeamUser},
-    utils::AllError,
+    utils::{batch_write, AllError},
 };
 
 pub struct EntityService {
@@ -70,6 +70,21 @@ impl EntityService {
         }
     }
 
+    // 저장할 엔티티와 지울 id를 한꺼번에 씁니다.
+    pub async fn batch_write_entities(
+        &self,
+        put_list: &[Entity],
+        delete_id_list: &[String],
+    ) -> Result<(), AllError> {
+        let requests = put_list
+            .iter()
+            .map(|e| batch_write::put_request(e.to_hashmap()))
+            .chain(delete_id_list.iter().map(batch_write::delete_request))
+            .collect::<Vec<_>>();
+
+        batch_write::batch_write(&self.client, Entity::NAME, requests).await
+    }
+
     pub async fn get_entity_list_by_project_id(
         &self,
         project_id: impl Into<String>,
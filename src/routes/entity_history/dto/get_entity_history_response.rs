@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{Entity, HistoryAction},
+    utils::history::EntityChanges,
+};
+
+// changes는 변경 전/후가 모두 있을 때(수정, 되돌리기)만 채워집니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEntityHistoryItem {
+    pub revision: u64,
+    pub action: HistoryAction,
+    pub actor_id: String,
+    pub created_at: u64,
+    pub changes: Option<EntityChanges>,
+    pub before: Option<Entity>,
+    pub after: Option<Entity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEntityHistoryResponse {
+    pub list: Vec<GetEntityHistoryItem>,
+    pub total: usize,
+    pub page: usize,
+    pub page_size: usize,
+}
@@ -0,0 +1,7 @@
+pub mod get_entity_history_request;
+pub mod get_entity_history_response;
+pub mod revert_entity_response;
+
+pub use get_entity_history_request::GetEntityHistoryRequest;
+pub use get_entity_history_response::*;
+pub use revert_entity_response::RevertEntityResponse;
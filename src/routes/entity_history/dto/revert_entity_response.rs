@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::lint::LintFinding;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevertEntityResponse {
+    pub success: bool,
+    pub revision: u64,
+    pub naming_findings: Vec<LintFinding>,
+}
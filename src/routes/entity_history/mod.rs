@@ -0,0 +1,6 @@
+#![allow(unused_imports)]
+
+pub mod dto;
+
+pub mod service;
+pub use service::*;
@@ -0,0 +1,162 @@
+use std::{str::FromStr, sync::Arc};
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+use std::error::Error;
+
+use epoch_timestamp::Epoch;
+
+use crate::{
+    models::{Entity, EntityHistory, HistoryAction},
+    utils::AllError,
+};
+
+// 같은 리비전을 동시에 기록하려다 밀린 경우 다시 시도하는 횟수
+const RECORD_RETRIES: usize = 5;
+
+pub struct EntityHistoryService {
+    client: Extension<Arc<Client>>,
+}
+
+impl EntityHistoryService {
+    pub fn new(client: Extension<Arc<Client>>) -> Self {
+        Self { client }
+    }
+
+    // 같은 엔티티/리비전의 이력이 이미 있으면 덮어쓰지 않고 false를 돌려줍니다.
+    pub async fn create_entity_history(&self, data: EntityHistory) -> Result<bool, AllError> {
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(EntityHistory::NAME)
+            .set_item(input)
+            .condition_expression("attribute_not_exists(revision)")
+            .send()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(error) => {
+                let error = error.into_service_error();
+
+                if error.is_conditional_check_failed_exception() {
+                    Ok(false)
+                } else {
+                    Err(AllError::AWSError(format!("{error:?}")))
+                }
+            }
+        }
+    }
+
+    // 엔티티의 다음 리비전으로 이력을 남깁니다.
+    // 동시에 같은 리비전을 기록하면 한쪽만 성공하므로, 밀린 쪽은 최신 리비전을 다시 읽어 재시도합니다.
+    // 이력 기록에 실패해도 호출한 쪽에서 이미 반영한 변경은 되돌리지 않습니다.
+    pub async fn record_history(
+        &self,
+        actor_id: &str,
+        action: HistoryAction,
+        before: Option<Entity>,
+        after: Option<Entity>,
+    ) -> Result<u64, AllError> {
+        let (entity_id, project_id) = match after.as_ref().or(before.as_ref()) {
+            Some(entity) => (entity.id.clone(), entity.project_id.clone()),
+            None => return Err(AllError::InvalidInput("엔티티 상태가 없습니다.".into())),
+        };
+
+        let mut data = EntityHistory {
+            id: uuid::Uuid::new_v4().to_string(),
+            entity_id,
+            project_id,
+            revision: 0,
+            action,
+            actor_id: actor_id.to_owned(),
+            created_at: Epoch::now(),
+            before,
+            after,
+        };
+
+        for _ in 0..RECORD_RETRIES {
+            data.revision = self.get_latest_revision(&data.entity_id).await? + 1;
+
+            if self.create_entity_history(data.clone()).await? {
+                return Ok(data.revision);
+            }
+        }
+
+        Err(AllError::AWSError(format!(
+            "이력 리비전 충돌: {}",
+            data.entity_id
+        )))
+    }
+
+    // 이력이 없으면 0
+    pub async fn get_latest_revision(&self, entity_id: impl Into<String>) -> Result<u64, AllError> {
+        match self
+            .client
+            .query()
+            .table_name(EntityHistory::NAME)
+            .key_condition_expression("entity_id = :entity_id")
+            .expression_attribute_values(":entity_id", AttributeValue::S(entity_id.into()))
+            .projection_expression("revision")
+            .scan_index_forward(false)
+            .limit(1)
+            .consistent_read(true)
+            .send()
+            .await
+        {
+            Ok(data) => Ok(data
+                .items()
+                .and_then(|items| items.first())
+                .and_then(|item| item.get("revision"))
+                .and_then(|e| e.as_n().ok())
+                .and_then(|e| e.parse::<u64>().ok())
+                .unwrap_or_default()),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_entity_history_list_by_entity_id(
+        &self,
+        entity_id: impl Into<String>,
+    ) -> Result<Vec<EntityHistory>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let entity_id = entity_id.into();
+
+        loop {
+            match self
+                .client
+                .query()
+                .table_name(EntityHistory::NAME)
+                .key_condition_expression("entity_id = :entity_id")
+                .expression_attribute_values(":entity_id", AttributeValue::S(entity_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(entity_history) =
+                                EntityHistory::from_hashmap(item.to_owned())
+                            {
+                                list.push(entity_history);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+}
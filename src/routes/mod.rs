@@ -1,9 +1,16 @@
 pub mod app;
 pub mod auth;
+pub mod diagram;
+pub mod domain;
 pub mod entity;
+pub mod entity_history;
+pub mod naming_rule;
 pub mod note;
 pub mod project;
+pub mod project_activity;
 pub mod redirect;
+pub mod snapshot;
 pub mod team;
+pub mod term;
 pub mod user;
 pub mod utils;
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixNamesRequest {
+    #[serde(default)]
+    pub dry_run: bool,
+}
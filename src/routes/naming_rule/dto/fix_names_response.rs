@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{lint::LintFinding, naming::NameFix};
+
+// remaining: 자동으로 고칠 수 없어 남은 위반 (금지어 등)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixNamesResponse {
+    pub success: bool,
+    pub dry_run: bool,
+    pub changes: Vec<NameFix>,
+    pub remaining: Vec<LintFinding>,
+}
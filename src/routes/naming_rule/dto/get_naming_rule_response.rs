@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::NamingConfig;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetNamingRuleItem {
+    pub id: String,
+    pub team_id: String,
+    pub project_id: Option<String>,
+    pub config: NamingConfig,
+}
+
+// 프로젝트 조회 시 data가 없고 inherited가 있으면 팀 규칙을 상속받은 것입니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetNamingRuleResponse {
+    pub data: Option<GetNamingRuleItem>,
+    pub inherited: Option<GetNamingRuleItem>,
+}
@@ -0,0 +1,11 @@
+pub mod fix_names_request;
+pub mod fix_names_response;
+pub mod get_naming_rule_response;
+pub mod update_naming_rule_request;
+pub mod update_naming_rule_response;
+
+pub use fix_names_request::FixNamesRequest;
+pub use fix_names_response::FixNamesResponse;
+pub use get_naming_rule_response::*;
+pub use update_naming_rule_request::UpdateNamingRuleRequest;
+pub use update_naming_rule_response::UpdateNamingRuleResponse;
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::NamingConfig;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateNamingRuleRequest {
+    pub config: NamingConfig,
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+
+use crate::{models::NamingRule, utils::AllError};
+
+pub struct NamingRuleService {
+    client: Extension<Arc<Client>>,
+}
+
+impl NamingRuleService {
+    pub fn new(client: Extension<Arc<Client>>) -> Self {
+        Self { client }
+    }
+
+    pub async fn save_naming_rule(&self, data: NamingRule) -> Result<String, AllError> {
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(NamingRule::NAME)
+            .set_item(input)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(data.id),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn delete_naming_rule(&self, rule_id: impl Into<String>) -> Result<(), AllError> {
+        match self
+            .client
+            .delete_item()
+            .table_name(NamingRule::NAME)
+            .key("id", AttributeValue::S(rule_id.into()))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_naming_rule_list_by_team_id(
+        &self,
+        team_id: impl Into<String>,
+    ) -> Result<Vec<NamingRule>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let team_id = team_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(NamingRule::NAME)
+                .filter_expression("team_id = :team_id")
+                .expression_attribute_values(":team_id", AttributeValue::S(team_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(rule) = NamingRule::from_hashmap(item.to_owned()) {
+                                list.push(rule);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+
+    // 팀과 상관없이 프로젝트 규칙을 찾습니다. (팀을 옮기다 중단된 경우에도 찾을 수 있도록)
+    pub async fn get_naming_rule_list_by_project_id(
+        &self,
+        project_id: impl Into<String>,
+    ) -> Result<Vec<NamingRule>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let project_id = project_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(NamingRule::NAME)
+                .filter_expression("project_id = :project_id")
+                .expression_attribute_values(":project_id", AttributeValue::S(project_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(rule) = NamingRule::from_hashmap(item.to_owned()) {
+                                list.push(rule);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+
+    // project_id가 None이면 팀 규칙을 찾습니다.
+    pub async fn find_naming_rule(
+        &self,
+        team_id: impl Into<String>,
+        project_id: Option<&str>,
+    ) -> Result<Option<NamingRule>, AllError> {
+        let list = self.get_naming_rule_list_by_team_id(team_id).await?;
+
+        Ok(list
+            .into_iter()
+            .find(|e| e.project_id.as_deref() == project_id))
+    }
+
+    // 프로젝트 규칙이 있으면 그것을, 없으면 팀 규칙을 적용합니다.
+    pub async fn get_effective_naming_rule(
+        &self,
+        team_id: impl Into<String>,
+        project_id: &str,
+    ) -> Result<Option<NamingRule>, AllError> {
+        let list = self.get_naming_rule_list_by_team_id(team_id).await?;
+
+        let project_rule = list
+            .iter()
+            .find(|e| e.project_id.as_deref() == Some(project_id))
+            .cloned();
+
+        Ok(project_rule.or_else(|| list.into_iter().find(|e| e.project_id.is_none())))
+    }
+}
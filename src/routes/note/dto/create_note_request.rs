@@ -1,9 +1,23 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::geometry;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateNoteRequest {
     pub project_id: String,
     pub content: String,
-    pub x: String,
-    pub y: String,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub y: f64,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub width: Option<f64>,
+    #[serde(default, deserialize_with = "geometry::deserialize_size")]
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub z_index: i32,
+    #[serde(default)]
+    pub collapsed: bool,
 }
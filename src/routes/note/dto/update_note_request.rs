@@ -1,8 +1,24 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::geometry;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateNoteRequest {
     pub content: String,
-    pub x: String,
-    pub y: String,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub x: f64,
+    #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+    pub y: f64,
+    // 이하 항목은 생략하면 기존 값을 유지합니다.
+    // 크기를 0으로, 색상을 빈 문자열로 보내면 기본값으로 돌아갑니다.
+    #[serde(default)]
+    pub width: Option<f64>,
+    #[serde(default)]
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub z_index: Option<i32>,
+    #[serde(default)]
+    pub collapsed: Option<bool>,
 }
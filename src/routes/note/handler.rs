@@ -101,8 +101,17 @@ async fn create_note(
         content: body.content.clone(),
         x: body.x,
         y: body.y,
+        width: body.width,
+        height: body.height,
+        color: body.color,
+        z_index: body.z_index,
+        collapsed: body.collapsed,
     };
 
+    if let Err(message) = data.validate_geometry() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
     match note_service.create_note(data).await {
         Ok(note_id) => {
             response.note_id = note_id;
@@ -135,14 +144,16 @@ async fn update_note(
 
     let mut response = UpdateNoteResponse { success: false };
 
-    let project_id = match note_service.get_note_by_id(note_id.clone()).await {
-        Ok(note) => note.project_id,
+    let note = match note_service.get_note_by_id(note_id.clone()).await {
+        Ok(note) => note,
         Err(error) => {
             println!("error: {error:?}");
             return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
         }
     };
 
+    let project_id = note.project_id.clone();
+
     let team_id = match project_service.get_project_by_id(project_id.clone()).await {
         Ok(project) => project.team_id,
         Err(error) => {
@@ -185,8 +196,19 @@ async fn update_note(
         content: body.content.clone(),
         x: body.x,
         y: body.y,
+        width: body.width.map_or(note.width, |e| (e != 0.0).then_some(e)),
+        height: body.height.map_or(note.height, |e| (e != 0.0).then_some(e)),
+        color: body
+            .color
+            .map_or(note.color, |e| (!e.is_empty()).then_some(e)),
+        z_index: body.z_index.unwrap_or(note.z_index),
+        collapsed: body.collapsed.unwrap_or(note.collapsed),
     };
 
+    if let Err(message) = data.validate_geometry() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
     match note_service.create_note(data).await {
         Ok(_) => {
             response.success = true;
@@ -285,6 +307,11 @@ async fn get_note(
                 content: note.content,
                 x: note.x,
                 y: note.y,
+                width: note.width,
+                height: note.height,
+                color: note.color,
+                z_index: note.z_index,
+                collapsed: note.collapsed,
             },
             note.project_id,
         ),
@@ -6,7 +6,7 @@ use std::error::Error;
 
 use crate::{
     models::{Note, Project, Team, TeamUser},
-    utils::AllError,
+    utils::{batch_write, AllError},
 };
 
 pub struct NoteService {
@@ -70,6 +70,21 @@ impl NoteService {
         }
     }
 
+    // 저장할 노트와 지울 id를 한꺼번에 씁니다.
+    pub async fn batch_write_notes(
+        &self,
+        put_list: &[Note],
+        delete_id_list: &[String],
+    ) -> Result<(), AllError> {
+        let requests = put_list
+            .iter()
+            .map(|e| batch_write::put_request(e.to_hashmap()))
+            .chain(delete_id_list.iter().map(batch_write::delete_request))
+            .collect::<Vec<_>>();
+
+        batch_write::batch_write(&self.client, Note::NAME, requests).await
+    }
+
     pub async fn get_note_list_by_project_id(
         &self,
         project_id: impl Into<String>,
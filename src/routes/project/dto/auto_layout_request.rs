@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoLayoutRequest {
+    // 없으면 프로젝트 전체 (엔티티의 x/y와 기본 다이어그램)
+    #[serde(default)]
+    pub diagram_id: Option<String>,
+    // 노트도 함께 배치할지 (노트는 다이어그램별 위치가 없어 기본 다이어그램에서만)
+    #[serde(default)]
+    pub include_notes: bool,
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::layout::Position;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoLayoutItem {
+    pub id: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl From<Position> for AutoLayoutItem {
+    fn from(position: Position) -> Self {
+        Self {
+            id: position.id,
+            x: position.x,
+            y: position.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoLayoutResponse {
+    pub success: bool,
+    pub dry_run: bool,
+    pub entities: Vec<AutoLayoutItem>,
+    pub notes: Vec<AutoLayoutItem>,
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::geometry,
+    routes::{
+        entity::dto::{CreateEntityRequest, UpdateEntityRequest},
+        note::dto::{CreateNoteRequest, UpdateNoteRequest},
+    },
+};
+
+// 작업 하나의 본문은 단건 API 요청과 같습니다. (type으로 종류를 구분)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BatchOperation {
+    MoveEntity {
+        id: String,
+        #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+        x: f64,
+        #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+        y: f64,
+    },
+    CreateEntity {
+        #[serde(flatten)]
+        data: CreateEntityRequest,
+    },
+    UpdateEntity {
+        id: String,
+        #[serde(flatten)]
+        data: UpdateEntityRequest,
+    },
+    DeleteEntity {
+        id: String,
+    },
+    MoveNote {
+        id: String,
+        #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+        x: f64,
+        #[serde(deserialize_with = "geometry::deserialize_request_coordinate")]
+        y: f64,
+    },
+    CreateNote {
+        #[serde(flatten)]
+        data: CreateNoteRequest,
+    },
+    UpdateNote {
+        id: String,
+        #[serde(flatten)]
+        data: UpdateNoteRequest,
+    },
+    DeleteNote {
+        id: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchRequest {
+    pub operations: Vec<BatchOperation>,
+}
+
+impl BatchRequest {
+    pub const MAX_OPERATIONS: usize = 500;
+}
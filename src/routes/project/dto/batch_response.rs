@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::lint::LintFinding;
+
+// 요청한 순서(index)대로 작업마다 하나씩 돌려줍니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchOperationResult {
+    pub index: usize,
+    pub success: bool,
+    // 대상 엔티티/노트 id (생성이면 새로 발급한 id)
+    pub id: Option<String>,
+    pub message: Option<String>,
+    pub naming_findings: Vec<LintFinding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResponse {
+    // 모든 작업이 반영되었는지
+    pub success: bool,
+    pub results: Vec<BatchOperationResult>,
+    // 100건이 넘어 나눠 쓰다 실패해서 일부만 저장되었는지
+    pub partial_write: bool,
+    // 일부만 저장된 경우, 실제로 저장/삭제된 엔티티/노트 id
+    pub written_ids: Vec<String>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneProjectRequest {
+    // 없으면 원본 프로젝트와 같은 팀
+    #[serde(default)]
+    pub team_id: Option<String>,
+    // 없으면 "원본 이름 복사본"
+    #[serde(default)]
+    pub name: Option<String>,
+}
@@ -6,4 +6,7 @@ pub struct CreateProjectRequest {
     pub name: String,
     pub description: String,
     pub thumbnail_url: Option<String>,
+    // 같은 팀의 템플릿 프로젝트를 고르면 그 내용(엔티티/노트/다이어그램)을 복사해서 시작합니다.
+    #[serde(default)]
+    pub template_id: Option<String>,
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{models::Entity, utils::Dialect};
+
+// base 상태에서 target 상태로 가는 차이를 계산합니다.
+// 각 상태는 엔티티 목록을 직접 주거나 스냅샷 id로 지정합니다. target을 지정하지 않으면 현재 프로젝트 상태를 사용합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffProjectRequest {
+    #[serde(default)]
+    pub dialect: Dialect,
+    #[serde(default)]
+    pub base: Option<Vec<Entity>>,
+    #[serde(default)]
+    pub base_snapshot_id: Option<String>,
+    #[serde(default)]
+    pub target: Option<Vec<Entity>>,
+    #[serde(default)]
+    pub target_snapshot_id: Option<String>,
+}
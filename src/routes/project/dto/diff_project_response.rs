@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::diff::TableDiff;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffProjectResponse {
+    pub tables: Vec<TableDiff>,
+    pub statements: Vec<String>,
+    pub warnings: Vec<String>,
+    pub sql: String,
+}
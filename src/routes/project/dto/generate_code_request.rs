@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::Dialect;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateCodeRequest {
+    #[serde(default)]
+    pub dialect: Dialect,
+    // true면 파일별로 나눈 zip, 아니면 한 파일
+    #[serde(default)]
+    pub archive: bool,
+    // JPA 클래스 패키지
+    pub package: Option<String>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratePhysicalNameRequest {
+    #[serde(default)]
+    pub overwrite: bool,
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::glossary::PhysicalNameChange;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratePhysicalNameResponse {
+    pub success: bool,
+    pub dry_run: bool,
+    pub changes: Vec<PhysicalNameChange>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDiagramListItem {
+    pub id: String,
+    pub name: String,
+    pub is_default: bool,
+    pub entity_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDiagramListResponse {
+    pub list: Vec<GetDiagramListItem>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDiagramSvgRequest {
+    // 없으면 기본 다이어그램 (엔티티의 x/y)
+    #[serde(default)]
+    pub diagram_id: Option<String>,
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::Column;
+use crate::models::{Column, Index};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetEntityListItem {
@@ -9,8 +9,14 @@ pub struct GetEntityListItem {
     pub physical_name: String,
     pub comment: String,
     pub columns: Vec<Column>,
-    pub x: String,
-    pub y: String,
+    pub indexes: Vec<Index>,
+    pub x: f64,
+    pub y: f64,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub color: Option<String>,
+    pub z_index: i32,
+    pub collapsed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,8 +6,13 @@ use crate::models::Column;
 pub struct GetNoteListItem {
     pub id: String,
     pub content: String,
-    pub x: String,
-    pub y: String,
+    pub x: f64,
+    pub y: f64,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub color: Option<String>,
+    pub z_index: i32,
+    pub collapsed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
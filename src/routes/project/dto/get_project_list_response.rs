@@ -6,6 +6,7 @@ pub struct GetProjectListItem {
     pub name: String,
     pub description: String,
     pub thumbnail_url: Option<String>,
+    pub is_template: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,6 +6,7 @@ pub struct GetProjectItem {
     pub description: String,
     pub name: String,
     pub thumbnail_url: Option<String>,
+    pub is_template: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+// csv: 컬럼 카탈로그 내보내기 형식의 CSV 본문 (entity_id, column_id 열 필수)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportColumnCatalogRequest {
+    pub csv: String,
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::column_catalog::{CatalogChange, CatalogError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportColumnCatalogResponse {
+    pub success: bool,
+    pub dry_run: bool,
+    pub updated_entities: usize,
+    pub changes: Vec<CatalogChange>,
+    pub errors: Vec<CatalogError>,
+}
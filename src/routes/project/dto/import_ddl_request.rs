@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::Dialect;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportDdlRequest {
+    #[serde(default)]
+    pub dialect: Dialect,
+    pub sql: String,
+    #[serde(default)]
+    pub dry_run: bool,
+}
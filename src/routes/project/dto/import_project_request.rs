@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::bundle::ProjectBundle;
+
+// name을 지정하지 않으면 번들의 프로젝트 이름을 사용합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProjectRequest {
+    pub team_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub bundle: ProjectBundle,
+}
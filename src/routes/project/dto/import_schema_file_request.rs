@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::schema_file::SchemaFileFormat;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSchemaFileRequest {
+    pub format: SchemaFileFormat,
+    pub source: String,
+    #[serde(default)]
+    pub dry_run: bool,
+}
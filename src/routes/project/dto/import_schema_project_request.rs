@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::schema_file::SchemaFileFormat;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSchemaProjectRequest {
+    pub team_id: String,
+    pub name: Option<String>,
+    pub format: SchemaFileFormat,
+    pub source: String,
+    #[serde(default)]
+    pub dry_run: bool,
+}
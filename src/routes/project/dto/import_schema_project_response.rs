@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use super::ImportSchemaItem;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSchemaProjectResponse {
+    pub success: bool,
+    pub dry_run: bool,
+    // 미리보기(dry_run)에서는 프로젝트를 만들지 않으므로 None
+    pub project_id: Option<String>,
+    pub name: String,
+    pub created: Vec<ImportSchemaItem>,
+    pub warnings: Vec<String>,
+}
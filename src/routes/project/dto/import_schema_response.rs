@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{models::Column, utils::schema_import::ImportedEntity};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSchemaItem {
+    pub id: String,
+    pub logical_name: String,
+    pub physical_name: String,
+    pub comment: String,
+    pub columns: Vec<Column>,
+    pub x: f64,
+    pub y: f64,
+    pub added_columns: Vec<String>,
+    pub removed_columns: Vec<String>,
+}
+
+impl From<ImportedEntity> for ImportSchemaItem {
+    fn from(value: ImportedEntity) -> Self {
+        Self {
+            id: value.entity.id,
+            logical_name: value.entity.logical_name,
+            physical_name: value.entity.physical_name,
+            comment: value.entity.comment,
+            columns: value.entity.columns,
+            x: value.entity.x,
+            y: value.entity.y,
+            added_columns: value.added_columns,
+            removed_columns: value.removed_columns,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSchemaResponse {
+    pub success: bool,
+    pub dry_run: bool,
+    pub created: Vec<ImportSchemaItem>,
+    pub updated: Vec<ImportSchemaItem>,
+    pub warnings: Vec<String>,
+}
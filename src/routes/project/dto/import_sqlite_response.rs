@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSqliteResponse {
+    pub success: bool,
+    pub project_id: String,
+    pub entity_count: usize,
+    pub warnings: Vec<String>,
+}
@@ -1,17 +1,71 @@
+pub mod auto_layout_request;
+pub mod auto_layout_response;
+pub mod batch_request;
+pub mod batch_response;
+pub mod clone_project_request;
+pub mod clone_project_response;
 pub mod create_project_request;
 pub mod create_project_response;
+pub mod diff_project_request;
+pub mod diff_project_response;
+pub mod generate_code_request;
+pub mod generate_physical_name_request;
+pub mod generate_physical_name_response;
+pub mod get_diagram_list_response;
+pub mod get_diagram_svg_request;
 pub mod get_entity_list_response;
 pub mod get_note_list_response;
 pub mod get_project_list_response;
 pub mod get_project_response;
+pub mod import_column_catalog_request;
+pub mod import_column_catalog_response;
+pub mod import_ddl_request;
+pub mod import_project_request;
+pub mod import_project_response;
+pub mod import_schema_file_request;
+pub mod import_schema_project_request;
+pub mod import_schema_project_response;
+pub mod import_schema_response;
+pub mod import_sqlite_response;
+pub mod transfer_project_request;
+pub mod transfer_project_response;
 pub mod update_project_request;
 pub mod update_project_response;
+pub mod validate_project_request;
+pub mod validate_project_response;
 
+pub use auto_layout_request::AutoLayoutRequest;
+pub use auto_layout_response::*;
+pub use batch_request::*;
+pub use batch_response::*;
+pub use clone_project_request::CloneProjectRequest;
+pub use clone_project_response::CloneProjectResponse;
 pub use create_project_request::CreateProjectRequest;
 pub use create_project_response::CreateProjectResponse;
+pub use diff_project_request::DiffProjectRequest;
+pub use diff_project_response::DiffProjectResponse;
+pub use generate_code_request::GenerateCodeRequest;
+pub use generate_physical_name_request::GeneratePhysicalNameRequest;
+pub use generate_physical_name_response::GeneratePhysicalNameResponse;
+pub use get_diagram_list_response::*;
+pub use get_diagram_svg_request::GetDiagramSvgRequest;
 pub use get_entity_list_response::*;
 pub use get_note_list_response::*;
 pub use get_project_list_response::*;
 pub use get_project_response::*;
+pub use import_column_catalog_request::ImportColumnCatalogRequest;
+pub use import_column_catalog_response::ImportColumnCatalogResponse;
+pub use import_ddl_request::ImportDdlRequest;
+pub use import_project_request::ImportProjectRequest;
+pub use import_project_response::ImportProjectResponse;
+pub use import_schema_file_request::ImportSchemaFileRequest;
+pub use import_schema_project_request::ImportSchemaProjectRequest;
+pub use import_schema_project_response::ImportSchemaProjectResponse;
+pub use import_schema_response::*;
+pub use import_sqlite_response::ImportSqliteResponse;
+pub use transfer_project_request::TransferProjectRequest;
+pub use transfer_project_response::TransferProjectResponse;
 pub use update_project_request::UpdateProjectRequest;
 pub use update_project_response::UpdateProjectResponse;
+pub use validate_project_request::ValidateProjectRequest;
+pub use validate_project_response::ValidateProjectResponse;
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+// detached_domain_count: 옮긴 팀에 없는 도메인이라 연결을 끊은 컬럼 수
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferProjectResponse {
+    pub success: bool,
+    pub detached_domain_count: usize,
+}
@@ -5,4 +5,7 @@ pub struct UpdateProjectRequest {
     pub name: String,
     pub description: String,
     pub thumbnail_url: Option<String>,
+    // 생략하면 기존 값을 유지합니다.
+    #[serde(default)]
+    pub is_template: Option<bool>,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::Dialect;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateProjectRequest {
+    #[serde(default)]
+    pub dialect: Dialect,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::lint::LintFinding;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateProjectResponse {
+    pub valid: bool,
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub findings: Vec<LintFinding>,
+}
@@ -2,30 +2,82 @@ use std::sync::Arc;
 
 use aws_sdk_dynamodb::Client;
 use axum::{
-    extract::Path,
-    http::StatusCode,
+    extract::{Multipart, Path, Query},
+    http::{header, StatusCode},
     response::{Html, IntoResponse},
     routing::{delete, get, post, put},
     Extension, Json, Router,
 };
+use epoch_timestamp::Epoch;
 use futures::future::join_all;
 
 use crate::{
     extensions::CurrentUser,
     middlewares::auth,
-    models::{InsertUser, Project, Team, TeamUser, TeamUserAuthority, User},
+    models::{
+        Diagram, Entity, HistoryAction, InsertUser, NamingRule, Note, Project, Snapshot,
+        SnapshotData, Team, TeamUser, TeamUserAuthority, User,
+    },
     routes::{
-        auth::AuthService, entity::EntityService, note::NoteService, team::TeamService,
+        auth::AuthService,
+        diagram::DiagramService,
+        domain::{dto::GetDomainListResponse, DomainService},
+        entity::EntityService,
+        entity_history::EntityHistoryService,
+        naming_rule::{
+            dto::{
+                FixNamesRequest, FixNamesResponse, GetNamingRuleItem, GetNamingRuleResponse,
+                UpdateNamingRuleRequest, UpdateNamingRuleResponse,
+            },
+            NamingRuleService,
+        },
+        note::NoteService,
+        project_activity::{
+            dto::{
+                GetProjectActivityItem, GetProjectActivityListRequest,
+                GetProjectActivityListResponse,
+            },
+            ProjectActivityService,
+        },
+        snapshot::{
+            dto::{
+                CreateSnapshotRequest, CreateSnapshotResponse, GetSnapshotListItem,
+                GetSnapshotListResponse,
+            },
+            SnapshotService,
+        },
+        team::TeamService,
+        term::TermService,
         user::UserService,
     },
-    utils::{generate_uuid, hash_password},
+    utils::{
+        batch_write,
+        bundle::ProjectBundle,
+        codegen::{self, jpa, CodegenOptions, CodegenTarget},
+        column_catalog, csv, ddl,
+        dictionary::{self, DictionaryFormat},
+        diff, generate_uuid, glossary, hash_password, layout,
+        lint::{self, LintFinding, LintSeverity},
+        naming, remap, schema_file, schema_import, sqlite_import, svg,
+        text_diagram::{self, TextDiagramFormat},
+        AllError,
+    },
 };
 
 use super::{
     dto::{
-        CreateProjectRequest, CreateProjectResponse, GetEntityListItem, GetEntityListResponse,
+        AutoLayoutRequest, AutoLayoutResponse, BatchOperation, BatchOperationResult, BatchRequest,
+        BatchResponse, CloneProjectRequest, CloneProjectResponse, CreateProjectRequest,
+        CreateProjectResponse, DiffProjectRequest, DiffProjectResponse, GenerateCodeRequest,
+        GeneratePhysicalNameRequest, GeneratePhysicalNameResponse, GetDiagramListItem,
+        GetDiagramListResponse, GetDiagramSvgRequest, GetEntityListItem, GetEntityListResponse,
         GetNoteListItem, GetNoteListResponse, GetProjectItem, GetProjectResponse,
-        UpdateProjectRequest, UpdateProjectResponse,
+        ImportColumnCatalogRequest, ImportColumnCatalogResponse, ImportDdlRequest,
+        ImportProjectRequest, ImportProjectResponse, ImportSchemaFileRequest,
+        ImportSchemaProjectRequest, ImportSchemaProjectResponse, ImportSchemaResponse,
+        ImportSqliteResponse, TransferProjectRequest, TransferProjectResponse,
+        UpdateProjectRequest, UpdateProjectResponse, ValidateProjectRequest,
+        ValidateProjectResponse,
     },
     ProjectService,
 };
@@ -33,11 +85,51 @@ use super::{
 pub async fn router() -> Router {
     Router::new()
         .route("/", post(create_project))
+        .route("/import", post(import_project))
+        .route("/import/sqlite", post(import_sqlite))
+        .route("/import/schema", post(import_schema_project))
         .route("/:project_id", put(update_project))
         .route("/:project_id", delete(delete_project))
         .route("/:project_id", get(get_project))
+        .route("/:project_id/clone", post(clone_project))
+        .route("/:project_id/transfer", post(transfer_project))
+        .route("/:project_id/activity/list", get(get_project_activity_list))
+        .route("/:project_id/export", get(export_project))
+        .route("/:project_id/export/:format", get(export_text_diagram))
+        .route("/:project_id/diagram.svg", get(get_diagram_svg))
+        .route("/:project_id/dictionary/:format", get(get_dictionary))
+        .route("/:project_id/codegen/:target", get(generate_code))
+        .route(
+            "/:project_id/column-catalog/export",
+            get(export_column_catalog),
+        )
+        .route(
+            "/:project_id/column-catalog/import",
+            post(import_column_catalog),
+        )
         .route("/:project_id/entity/list", get(get_entity_list))
         .route("/:project_id/note/list", get(get_note_list))
+        .route("/:project_id/diagram/list", get(get_diagram_list))
+        .route("/:project_id/layout", post(auto_layout))
+        .route("/:project_id/batch", post(batch_operations))
+        .route("/:project_id/import/ddl", post(import_ddl))
+        .route("/:project_id/import/schema", post(import_schema_file))
+        .route("/:project_id/validate", get(validate_project))
+        .route("/:project_id/diff", post(diff_project))
+        .route("/:project_id/snapshot", post(create_snapshot))
+        .route("/:project_id/snapshot/list", get(get_snapshot_list))
+        .route(
+            "/:project_id/physical-name/generate",
+            post(generate_physical_name),
+        )
+        .route("/:project_id/naming-rule", get(get_project_naming_rule))
+        .route("/:project_id/naming-rule", put(update_project_naming_rule))
+        .route(
+            "/:project_id/naming-rule",
+            delete(delete_project_naming_rule),
+        )
+        .route("/:project_id/naming-rule/fix", post(fix_names))
+        .route("/:project_id/domain/list", get(get_domain_list))
 }
 
 async fn create_project(
@@ -82,18 +174,36 @@ async fn create_project(
         }
     }
 
+    // 템플릿은 같은 팀의 템플릿 프로젝트만 고를 수 있습니다.
+    let template = match body.template_id.as_ref() {
+        Some(template_id) => match project_service.get_project_by_id(template_id).await {
+            Ok(template) if template.is_template && template.team_id == body.team_id => {
+                Some(template)
+            }
+            Ok(_) | Err(AllError::NotFound) => {
+                return (StatusCode::BAD_REQUEST, "팀의 템플릿 프로젝트가 아닙니다.")
+                    .into_response()
+            }
+            Err(error) => {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        },
+        None => None,
+    };
+
     let data = Project {
         id: uuid::Uuid::new_v4().to_string(),
         name: body.name,
         description: body.description,
         thumbnail_url: body.thumbnail_url,
         team_id: body.team_id,
+        is_template: false,
     };
 
-    match project_service.create_project(data).await {
+    match project_service.create_project(data.clone()).await {
         Ok(project_id) => {
             response.project_id = project_id;
-            response.success = true;
         }
         Err(error) => {
             println!("error: {error:?}");
@@ -101,14 +211,96 @@ async fn create_project(
         }
     }
 
+    if let Some(template) = template {
+        if let Err(error) = copy_project_contents(&database, &template.id, &data).await {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    response.success = true;
+
     Json(response).into_response()
 }
 
-async fn update_project(
+// 저장한 엔티티마다 이력을 남깁니다. 이전 목록에 없던 엔티티는 생성, 있던 엔티티는 수정으로 기록합니다.
+// 이력 기록에 실패해도 저장은 되돌리지 않습니다.
+async fn record_entity_history_list(
+    database: &Extension<Arc<Client>>,
+    actor_id: &str,
+    before_list: &[Entity],
+    after_list: Vec<Entity>,
+) {
+    let entity_history_service = EntityHistoryService::new(database.clone());
+
+    for entity in after_list {
+        let (action, before) = match before_list.iter().find(|e| e.id == entity.id) {
+            Some(before) => (HistoryAction::Update, Some(before.clone())),
+            None => (HistoryAction::Create, None),
+        };
+
+        if let Err(error) = entity_history_service
+            .record_history(actor_id, action, before, Some(entity))
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+}
+
+// 원본 프로젝트의 엔티티/노트/다이어그램을 새 id로 대상 프로젝트에 복사합니다. (복제, 템플릿)
+// 대상 팀에서 쓸 수 없는 도메인 연결은 끊습니다. 복사한 (엔티티 수, 노트 수)를 돌려줍니다.
+async fn copy_project_contents(
+    database: &Extension<Arc<Client>>,
+    source_project_id: &str,
+    target: &Project,
+) -> Result<(usize, usize), AllError> {
+    let entity_service = EntityService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let entity_list = entity_service
+        .get_entity_list_by_project_id(source_project_id)
+        .await?;
+    let note_list = note_service
+        .get_note_list_by_project_id(source_project_id)
+        .await?;
+    let diagram_list = diagram_service
+        .get_diagram_list_by_project_id(source_project_id)
+        .await?;
+    let domain_list = domain_service
+        .get_domain_list_by_team_id(&target.team_id)
+        .await?;
+
+    let (mut entities, notes) = remap::copy_to_project(&entity_list, &note_list, &target.id);
+    let diagrams = remap::copy_diagrams(&diagram_list, &entity_list, &entities, &target.id);
+
+    remap::detach_foreign_domains(&mut entities, &domain_list);
+
+    entity_service.batch_write_entities(&entities, &[]).await?;
+    note_service.batch_write_notes(&notes, &[]).await?;
+
+    for result in join_all(
+        diagrams
+            .into_iter()
+            .map(|diagram| diagram_service.create_diagram(diagram)),
+    )
+    .await
+    {
+        result?;
+    }
+
+    Ok((entities.len(), notes.len()))
+}
+
+// 프로젝트를 같은 팀이나 다른 팀으로 복제합니다.
+// 원본 팀의 구성원이어야 하고, 대상 팀에서는 OWNER/ADMIN이어야 합니다.
+async fn clone_project(
     current_user: Extension<CurrentUser>,
     database: Extension<Arc<Client>>,
     Path(project_id): Path<String>,
-    Json(body): Json<UpdateProjectRequest>,
+    Json(body): Json<CloneProjectRequest>,
 ) -> impl IntoResponse {
     let user = if let Some(user) = current_user.user.clone() {
         user
@@ -119,15 +311,30 @@ async fn update_project(
     let team_service = TeamService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
 
-    let mut response = UpdateProjectResponse { success: false };
-
-    let old_team = match project_service.get_project_by_id(project_id.clone()).await {
-        Ok(team) => team,
+    let source = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
         Err(_) => return (StatusCode::NOT_FOUND).into_response(),
     };
 
     match team_service
-        .find_team_user_by_team_and_user_id(old_team.team_id.clone(), user.id.clone())
+        .find_team_user_by_team_and_user_id(&source.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let team_id = body.team_id.unwrap_or(source.team_id.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
         .await
     {
         Ok(Some(team_user)) => match team_user.authority {
@@ -149,17 +356,33 @@ async fn update_project(
         }
     }
 
-    let data = Project {
-        id: project_id,
-        name: body.name,
-        description: body.description,
-        thumbnail_url: body.thumbnail_url,
-        team_id: old_team.team_id,
+    let project = Project {
+        id: generate_uuid(),
+        team_id,
+        name: body
+            .name
+            .unwrap_or_else(|| format!("{} 복사본", source.name)),
+        description: source.description.clone(),
+        thumbnail_url: source.thumbnail_url.clone(),
+        is_template: false,
     };
 
-    match project_service.create_project(data).await {
-        Ok(_) => {
-            response.success = true;
+    let mut response = CloneProjectResponse {
+        success: false,
+        project_id: project.id.clone(),
+        entity_count: 0,
+        note_count: 0,
+    };
+
+    if let Err(error) = project_service.create_project(project.clone()).await {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    match copy_project_contents(&database, &source.id, &project).await {
+        Ok((entity_count, note_count)) => {
+            response.entity_count = entity_count;
+            response.note_count = note_count;
         }
         Err(error) => {
             println!("error: {error:?}");
@@ -167,13 +390,104 @@ async fn update_project(
         }
     }
 
+    response.success = true;
+
     Json(response).into_response()
 }
 
-async fn delete_project(
+// 프로젝트 전용 도메인과 명명 규칙을 대상 팀으로 옮기고,
+// 대상 팀에서 쓸 수 없게 된 컬럼의 도메인 연결을 끊습니다. 연결을 끊은 컬럼 수를 돌려줍니다.
+// 어느 팀에 있든 프로젝트 id로 찾으므로, 중간에 실패해도 다시 실행하면 마저 옮깁니다.
+async fn transfer_project_contents(
+    database: &Extension<Arc<Client>>,
+    actor_id: &str,
+    project_id: &str,
+    team_id: &str,
+) -> Result<usize, AllError> {
+    let entity_service = EntityService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let project_domains = domain_service
+        .get_domain_list_by_project_id(project_id)
+        .await?;
+
+    for mut domain in project_domains.iter().cloned() {
+        if domain.team_id != team_id {
+            domain.team_id = team_id.to_owned();
+            domain_service.create_domain(domain).await?;
+        }
+    }
+
+    for mut naming_rule in naming_rule_service
+        .get_naming_rule_list_by_project_id(project_id)
+        .await?
+    {
+        if naming_rule.team_id != team_id {
+            naming_rule.team_id = team_id.to_owned();
+            naming_rule_service.save_naming_rule(naming_rule).await?;
+        }
+    }
+
+    let team_domains = domain_service.get_domain_list_by_team_id(team_id).await?;
+
+    let mut detached_count = 0;
+    let mut changed = vec![];
+
+    for entity in entity_service
+        .get_entity_list_by_project_id(project_id)
+        .await?
+    {
+        let mut after = entity.clone();
+        let mut detached = false;
+
+        for column in after.columns.iter_mut() {
+            let domain_id = match column.domain_id.as_ref() {
+                Some(domain_id) => domain_id,
+                None => continue,
+            };
+
+            let is_project_domain = project_domains.iter().any(|e| &e.id == domain_id);
+            let is_team_domain = team_domains
+                .iter()
+                .any(|e| &e.id == domain_id && e.project_id.is_none());
+
+            if !is_project_domain && !is_team_domain {
+                column.domain_id = None;
+                detached_count += 1;
+                detached = true;
+            }
+        }
+
+        if detached {
+            changed.push((entity, after));
+        }
+    }
+
+    let entities = changed.iter().map(|(_, e)| e.clone()).collect::<Vec<_>>();
+    entity_service.batch_write_entities(&entities, &[]).await?;
+
+    for (before, after) in changed {
+        if let Err(error) = entity_history_service
+            .record_history(actor_id, HistoryAction::Update, Some(before), Some(after))
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+
+    Ok(detached_count)
+}
+
+// 프로젝트를 엔티티/노트/다이어그램째로 다른 팀으로 옮깁니다.
+// 원본 팀과 대상 팀 모두에서 OWNER/ADMIN이어야 합니다.
+// 이미 옮겨진 프로젝트에 다시 요청하면 남은 도메인/명명 규칙 이동을 마저 처리합니다.
+async fn transfer_project(
     current_user: Extension<CurrentUser>,
     database: Extension<Arc<Client>>,
     Path(project_id): Path<String>,
+    Json(body): Json<TransferProjectRequest>,
 ) -> impl IntoResponse {
     let user = if let Some(user) = current_user.user.clone() {
         user
@@ -183,40 +497,73 @@ async fn delete_project(
 
     let team_service = TeamService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
+    let project_activity_service = ProjectActivityService::new(database.clone());
 
-    let mut response = UpdateProjectResponse { success: false };
-
-    let old_team = match project_service.get_project_by_id(project_id.clone()).await {
-        Ok(team) => team,
+    let mut project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
         Err(_) => return (StatusCode::NOT_FOUND).into_response(),
     };
 
-    match team_service
-        .find_team_user_by_team_and_user_id(old_team.team_id.clone(), user.id.clone())
-        .await
-    {
-        Ok(Some(team_user)) => match team_user.authority {
-            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
-                println!("# 권한 허용: OWNER OR ADMIN");
-            }
-            _ => {
-                println!("# 권한 부족: NOT OWNER OR ADMIN");
+    // 이미 대상 팀으로 옮겨진 프로젝트는 이전 이동이 중간에 실패한 경우이므로, 대상 팀 권한만 보고 나머지를 마저 옮깁니다.
+    let is_moved = project.team_id == body.team_id;
+    let team_ids = if is_moved {
+        vec![&body.team_id]
+    } else {
+        vec![&project.team_id, &body.team_id]
+    };
+
+    for team_id in team_ids {
+        match team_service
+            .find_team_user_by_team_and_user_id(team_id, &user.id)
+            .await
+        {
+            Ok(Some(team_user)) => match team_user.authority {
+                TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                    println!("# 권한 허용: OWNER OR ADMIN");
+                }
+                _ => {
+                    println!("# 권한 부족: NOT OWNER OR ADMIN");
+                    return (StatusCode::FORBIDDEN).into_response();
+                }
+            },
+            Ok(None) => {
+                println!("# 권한 부족: NOT TEAM MEMBER");
                 return (StatusCode::FORBIDDEN).into_response();
             }
-        },
-        Ok(None) => {
-            println!("# 권한 부족: NOT TEAM MEMBER");
-            return (StatusCode::FORBIDDEN).into_response();
+            Err(error) => {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
         }
-        Err(error) => {
+    }
+
+    let mut response = TransferProjectResponse {
+        success: false,
+        detached_domain_count: 0,
+    };
+
+    // 프로젝트를 먼저 옮겨서, 내용을 옮기다 실패해도 프로젝트와 내용이 서로 다른 팀에 남지 않게 합니다.
+    if !is_moved {
+        let from_team_id = std::mem::replace(&mut project.team_id, body.team_id.clone());
+
+        if let Err(error) = project_service.create_project(project).await {
             println!("error: {error:?}");
             return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
         }
+
+        // 이력 기록에 실패해도 이동은 되돌리지 않습니다.
+        if let Err(error) = project_activity_service
+            .record_transfer(&user.id, &project_id, &from_team_id, &body.team_id)
+            .await
+        {
+            println!("error: {error:?}");
+        }
     }
 
-    match project_service.delete_project(project_id).await {
-        Ok(_) => {
-            response.success = true;
+    // 실패하면 같은 요청을 다시 보내서 마저 옮길 수 있습니다.
+    match transfer_project_contents(&database, &user.id, &project_id, &body.team_id).await {
+        Ok(detached_domain_count) => {
+            response.detached_domain_count = detached_domain_count;
         }
         Err(error) => {
             println!("error: {error:?}");
@@ -224,13 +571,17 @@ async fn delete_project(
         }
     }
 
+    response.success = true;
+
     Json(response).into_response()
 }
 
-async fn get_project(
+// 프로젝트 활동 이력 (최신순)
+async fn get_project_activity_list(
     current_user: Extension<CurrentUser>,
     database: Extension<Arc<Client>>,
     Path(project_id): Path<String>,
+    Query(query): Query<GetProjectActivityListRequest>,
 ) -> impl IntoResponse {
     let user = if let Some(user) = current_user.user.clone() {
         user
@@ -238,31 +589,23 @@ async fn get_project(
         return (StatusCode::UNAUTHORIZED).into_response();
     };
 
-    let project_service = ProjectService::new(database.clone());
     let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let project_activity_service = ProjectActivityService::new(database.clone());
 
-    let (project_data, team_id) = match project_service.get_project_by_id(project_id).await {
-        Ok(project) => (
-            GetProjectItem {
-                id: project.id,
-                name: project.name,
-                description: project.description,
-                thumbnail_url: project.thumbnail_url,
-            },
-            project.team_id,
-        ),
-        Err(error) => {
-            println!("error: {error:?}");
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
-        }
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
     };
 
     match team_service
-        .find_team_user_by_team_and_user_id(user.id.clone(), team_id)
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
         .await
     {
-        Ok(_) => {
-            println!("# 권한 허용");
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
         }
         Err(error) => {
             println!("error: {error:?}");
@@ -270,12 +613,45 @@ async fn get_project(
         }
     }
 
-    let response = GetProjectResponse { data: project_data };
+    let mut activity_list = match project_activity_service
+        .get_project_activity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(activity_list) => activity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    activity_list.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+
+    let page = query.page.unwrap_or(1).max(1);
+    let page_size = query.page_size.unwrap_or(20).clamp(1, 100);
+
+    let response = GetProjectActivityListResponse {
+        total: activity_list.len(),
+        page,
+        page_size,
+        list: activity_list
+            .into_iter()
+            .skip((page - 1) * page_size)
+            .take(page_size)
+            .map(|e| GetProjectActivityItem {
+                id: e.id,
+                action: e.action,
+                actor_id: e.actor_id,
+                created_at: e.created_at,
+                from_team_id: e.from_team_id,
+                to_team_id: e.to_team_id,
+            })
+            .collect(),
+    };
 
     Json(response).into_response()
 }
 
-async fn get_entity_list(
+async fn export_project(
     current_user: Extension<CurrentUser>,
     database: Extension<Arc<Client>>,
     Path(project_id): Path<String>,
@@ -286,17 +662,19 @@ async fn get_entity_list(
         return (StatusCode::UNAUTHORIZED).into_response();
     };
 
-    let entity_service = EntityService::new(database.clone());
     let team_service = TeamService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
 
-    let team_id = match project_service.get_project_by_id(&project_id).await {
-        Ok(project) => project.team_id,
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
         Err(_) => return (StatusCode::NOT_FOUND).into_response(),
     };
 
     match team_service
-        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
         .await
     {
         Ok(Some(_)) => {}
@@ -309,31 +687,56 @@ async fn get_entity_list(
         .await
     {
         Ok(entity_list) => entity_list,
-        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
     };
 
-    let entity_list = entity_list
-        .into_iter()
-        .map(|e| GetEntityListItem {
-            id: e.id,
-            logical_name: e.logical_name,
-            physical_name: e.physical_name,
-            comment: e.comment,
-            columns: e.columns,
-            x: e.x,
-            y: e.y,
-        })
-        .collect::<Vec<_>>();
+    let note_list = match note_service.get_note_list_by_project_id(&project_id).await {
+        Ok(note_list) => note_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
 
-    let response = GetEntityListResponse { list: entity_list };
+    let diagram_list = match diagram_service
+        .get_diagram_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(diagram_list) => diagram_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
 
-    Json(response).into_response()
+    let bundle = ProjectBundle::new(project, entity_list, note_list, diagram_list, Epoch::now());
+
+    let body = match serde_json::to_string_pretty(&bundle) {
+        Ok(body) => body,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let headers = [
+        (header::CONTENT_TYPE, "application/json".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"project-{project_id}.json\""),
+        ),
+    ];
+
+    (headers, body).into_response()
 }
 
-async fn get_note_list(
+async fn export_text_diagram(
     current_user: Extension<CurrentUser>,
     database: Extension<Arc<Client>>,
-    Path(project_id): Path<String>,
+    Path((project_id, format)): Path<(String, TextDiagramFormat)>,
 ) -> impl IntoResponse {
     let user = if let Some(user) = current_user.user.clone() {
         user
@@ -341,17 +744,17 @@ async fn get_note_list(
         return (StatusCode::UNAUTHORIZED).into_response();
     };
 
-    let note_service = NoteService::new(database.clone());
     let team_service = TeamService::new(database.clone());
     let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
 
-    let team_id = match project_service.get_project_by_id(&project_id).await {
-        Ok(project) => project.team_id,
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
         Err(_) => return (StatusCode::NOT_FOUND).into_response(),
     };
 
     match team_service
-        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
         .await
     {
         Ok(Some(_)) => {}
@@ -359,22 +762,2819 @@ async fn get_note_list(
         Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
     }
 
-    let note_list = match note_service.get_note_list_by_project_id(&project_id).await {
-        Ok(note_list) => note_list,
-        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
     };
 
-    let note_list = note_list
-        .into_iter()
-        .map(|e| GetNoteListItem {
-            id: e.id,
-            content: e.content,
+    let body = text_diagram::render(format, &project, &entity_list);
+
+    let headers = [
+        (header::CONTENT_TYPE, "text/plain; charset=utf-8".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!(
+                "inline; filename=\"project-{project_id}.{}\"",
+                format.extension()
+            ),
+        ),
+    ];
+
+    (headers, body).into_response()
+}
+
+async fn get_diagram_svg(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Query(query): Query<GetDiagramSvgRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let note_list = match note_service.get_note_list_by_project_id(&project_id).await {
+        Ok(note_list) => note_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 다이어그램을 고르면 그 다이어그램의 엔티티만 다이어그램 좌표로 그립니다.
+    // 노트는 다이어그램별 위치가 없으므로 기본 다이어그램에만 그립니다.
+    let (entity_list, note_list) = match query.diagram_id {
+        Some(diagram_id) => {
+            let diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+                Ok(diagram) if diagram.project_id == project_id => diagram,
+                Ok(_) | Err(AllError::NotFound) => return (StatusCode::NOT_FOUND).into_response(),
+                Err(error) => {
+                    println!("error: {error:?}");
+                    return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+                }
+            };
+
+            if diagram.is_default {
+                (entity_list, note_list)
+            } else {
+                (diagram.place_entities(&entity_list), vec![])
+            }
+        }
+        None => (entity_list, note_list),
+    };
+
+    let headers = [(header::CONTENT_TYPE, "image/svg+xml; charset=utf-8")];
+
+    (headers, svg::render(&entity_list, &note_list)).into_response()
+}
+
+async fn get_dictionary(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((project_id, format)): Path<(String, DictionaryFormat)>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let body = dictionary::render(format, &project, &entity_list);
+
+    let headers = [
+        (header::CONTENT_TYPE, format.content_type().to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"dictionary-{project_id}.{}\"",
+                format.extension()
+            ),
+        ),
+    ];
+
+    (headers, body).into_response()
+}
+
+async fn generate_code(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path((project_id, target)): Path<(String, CodegenTarget)>,
+    Query(query): Query<GenerateCodeRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let package = match query.package {
+        Some(package) if !package.trim().is_empty() => package.trim().to_owned(),
+        _ => jpa::DEFAULT_PACKAGE.to_owned(),
+    };
+
+    if !jpa::is_valid_package(&package) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("올바른 패키지 이름이 아닙니다: {package}"),
+        )
+            .into_response();
+    }
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let options = CodegenOptions {
+        dialect: query.dialect,
+        package,
+    };
+
+    let files = codegen::generate(target, &entity_list, &options);
+
+    if query.archive {
+        let body = match codegen::to_zip(&files) {
+            Ok(body) => body,
+            Err(error) => {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        };
+
+        let headers = [
+            (header::CONTENT_TYPE, "application/zip".to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"codegen-{project_id}.zip\""),
+            ),
+        ];
+
+        return (headers, body).into_response();
+    }
+
+    let (file_name, body) = codegen::to_single_file(target, &files);
+
+    let headers = [
+        (header::CONTENT_TYPE, "text/plain; charset=utf-8".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{file_name}\""),
+        ),
+    ];
+
+    (headers, body).into_response()
+}
+
+async fn export_column_catalog(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let rows = column_catalog::export_rows(&entity_list);
+
+    let headers = [
+        (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"columns-{project_id}.csv\""),
+        ),
+    ];
+
+    (headers, csv::write_csv(&rows)).into_response()
+}
+
+// 편집한 컬럼 카탈로그 CSV를 반영합니다. dry_run이면 바뀔 내역만 돌려줍니다.
+async fn import_column_catalog(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<ImportColumnCatalogRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let rows = match csv::parse_csv(&body.csv) {
+        Ok(rows) => rows,
+        Err(AllError::InvalidInput(message)) => {
+            return (StatusCode::BAD_REQUEST, message).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let original_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let mut entity_list = original_list.clone();
+
+    let (changes, errors) = match column_catalog::apply_rows(&rows, &mut entity_list) {
+        Ok(result) => result,
+        Err(message) => return (StatusCode::BAD_REQUEST, message).into_response(),
+    };
+
+    let updated_list = entity_list
+        .into_iter()
+        .filter(|entity| changes.iter().any(|e| e.entity_id == entity.id))
+        .collect::<Vec<_>>();
+
+    let mut response = ImportColumnCatalogResponse {
+        success: false,
+        dry_run: body.dry_run,
+        updated_entities: updated_list.len(),
+        changes,
+        errors,
+    };
+
+    if !body.dry_run {
+        let results = join_all(
+            updated_list
+                .iter()
+                .map(|entity| entity_service.create_entity(entity.clone())),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        for entity in updated_list {
+            let before = original_list.iter().find(|e| e.id == entity.id).cloned();
+
+            if let Err(error) = entity_history_service
+                .record_history(&user.id, HistoryAction::Update, before, Some(entity))
+                .await
+            {
+                println!("error: {error:?}");
+            }
+        }
+    }
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+// 가져온 엔티티/노트/다이어그램을 새 프로젝트에 저장합니다.
+async fn write_imported_contents(
+    database: &Extension<Arc<Client>>,
+    entities: &[Entity],
+    notes: &[Note],
+    diagrams: Vec<Diagram>,
+) -> Result<(), AllError> {
+    let entity_service = EntityService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    entity_service.batch_write_entities(entities, &[]).await?;
+    note_service.batch_write_notes(notes, &[]).await?;
+
+    for result in join_all(
+        diagrams
+            .into_iter()
+            .map(|diagram| diagram_service.create_diagram(diagram)),
+    )
+    .await
+    {
+        result?;
+    }
+
+    Ok(())
+}
+
+// 번들을 지정한 팀의 새 프로젝트로 가져옵니다. 모든 id를 새로 발급하고 FK가 새 id를 가리키도록 바꿉니다.
+async fn import_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Json(body): Json<ImportProjectRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&body.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let bundle = body.bundle;
+
+    if let Err(message) = bundle.validate() {
+        return (StatusCode::BAD_REQUEST, message).into_response();
+    }
+
+    let domain_list = match domain_service
+        .get_domain_list_by_team_id(&body.team_id)
+        .await
+    {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let project = Project {
+        id: generate_uuid(),
+        team_id: body.team_id,
+        name: body.name.unwrap_or(bundle.project.name),
+        description: bundle.project.description,
+        thumbnail_url: bundle.project.thumbnail_url,
+        is_template: false,
+    };
+
+    let (mut entities, notes) =
+        remap::copy_to_project(&bundle.entities, &bundle.notes, &project.id);
+    let diagrams = remap::copy_diagrams(&bundle.diagrams, &bundle.entities, &entities, &project.id);
+
+    remap::detach_foreign_domains(&mut entities, &domain_list);
+
+    let mut response = ImportProjectResponse {
+        success: false,
+        project_id: project.id.clone(),
+        entity_count: entities.len(),
+        note_count: notes.len(),
+    };
+
+    if let Err(error) = project_service.create_project(project).await {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    // 내용을 쓰다 실패하면 반쯤 채워진 프로젝트가 남지 않도록 프로젝트를 지웁니다.
+    if let Err(error) = write_imported_contents(&database, &entities, &notes, diagrams).await {
+        println!("error: {error:?}");
+
+        if let Err(error) = project_service.delete_project(&response.project_id).await {
+            println!("error: {error:?}");
+        }
+
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    record_entity_history_list(&database, &user.id, &[], entities).await;
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+// multipart 필드: team_id, name(선택), file(.db 파일)
+async fn import_sqlite(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let mut team_id = None;
+    let mut name = None;
+    let mut file = None;
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(error) => return (StatusCode::BAD_REQUEST, error.to_string()).into_response(),
+        };
+
+        match field.name().unwrap_or_default() {
+            "team_id" => team_id = field.text().await.ok(),
+            "name" => name = field.text().await.ok(),
+            "file" => {
+                let file_name = field.file_name().unwrap_or_default().to_owned();
+
+                match field.bytes().await {
+                    Ok(data) => file = Some((file_name, data)),
+                    Err(error) => {
+                        return (StatusCode::BAD_REQUEST, error.to_string()).into_response()
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (Some(team_id), Some((file_name, data))) = (team_id, file) else {
+        return (StatusCode::BAD_REQUEST, "team_id와 file이 필요합니다.").into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let tables = match sqlite_import::read_tables(&data) {
+        Ok(tables) => tables,
+        Err(AllError::InvalidInput(message)) => {
+            return (StatusCode::BAD_REQUEST, message).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 이름을 주지 않으면 파일 이름(확장자 제외)을 프로젝트 이름으로 씁니다.
+    let name = match name.filter(|e| !e.trim().is_empty()) {
+        Some(name) => name.trim().to_owned(),
+        None => file_name
+            .rsplit_once('.')
+            .map(|(stem, _)| stem.to_owned())
+            .filter(|e| !e.is_empty())
+            .unwrap_or(file_name),
+    };
+
+    let project = Project {
+        id: generate_uuid(),
+        team_id,
+        name,
+        description: "".into(),
+        thumbnail_url: None,
+        is_template: false,
+    };
+
+    // 새 프로젝트라 모든 테이블이 새 엔티티가 되고, 격자 배치로 자리를 잡습니다.
+    let plan = schema_import::build_import_plan(&project.id, &[], tables);
+
+    let mut response = ImportSqliteResponse {
+        success: false,
+        project_id: project.id.clone(),
+        entity_count: plan.created.len(),
+        warnings: plan.warnings.clone(),
+    };
+
+    if let Err(error) = project_service.create_project(project).await {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    let results = join_all(
+        plan.entities()
+            .map(|entity| entity_service.create_entity(entity.clone())),
+    )
+    .await;
+
+    if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    record_entity_history_list(&database, &user.id, &[], plan.entities().cloned().collect()).await;
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+async fn import_schema_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Json(body): Json<ImportSchemaProjectRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&body.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let file = match schema_file::parse(body.format, &body.source) {
+        Ok(file) => file,
+        Err(AllError::InvalidInput(message)) => {
+            return (StatusCode::BAD_REQUEST, message).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 이름을 주지 않으면 DBML Project 이름, 그것도 없으면 형식 이름을 씁니다.
+    let name = body
+        .name
+        .filter(|e| !e.trim().is_empty())
+        .or(file.name)
+        .unwrap_or_else(|| format!("{} 가져오기", body.format.label()));
+
+    let project = Project {
+        id: generate_uuid(),
+        team_id: body.team_id,
+        name: name.clone(),
+        description: file.description,
+        thumbnail_url: None,
+        is_template: false,
+    };
+
+    let plan = schema_import::build_import_plan(&project.id, &[], file.tables);
+
+    let mut warnings = file.warnings;
+    warnings.extend(plan.warnings.iter().cloned());
+
+    let mut response = ImportSchemaProjectResponse {
+        success: false,
+        dry_run: body.dry_run,
+        project_id: None,
+        name,
+        created: plan.created.iter().cloned().map(Into::into).collect(),
+        warnings,
+    };
+
+    if !body.dry_run {
+        response.project_id = Some(project.id.clone());
+
+        if let Err(error) = project_service.create_project(project).await {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        let results = join_all(
+            plan.entities()
+                .map(|entity| entity_service.create_entity(entity.clone())),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        record_entity_history_list(&database, &user.id, &[], plan.entities().cloned().collect())
+            .await;
+    }
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+async fn update_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<UpdateProjectRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let mut response = UpdateProjectResponse { success: false };
+
+    let old_team = match project_service.get_project_by_id(project_id.clone()).await {
+        Ok(team) => team,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(old_team.team_id.clone(), user.id.clone())
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let data = Project {
+        id: project_id,
+        name: body.name,
+        description: body.description,
+        thumbnail_url: body.thumbnail_url,
+        is_template: body.is_template.unwrap_or(old_team.is_template),
+        team_id: old_team.team_id,
+    };
+
+    match project_service.create_project(data).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let mut response = UpdateProjectResponse { success: false };
+
+    let old_team = match project_service.get_project_by_id(project_id.clone()).await {
+        Ok(team) => team,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(old_team.team_id.clone(), user.id.clone())
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match project_service.delete_project(project_id).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn get_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let project_service = ProjectService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+
+    let (project_data, team_id) = match project_service.get_project_by_id(project_id).await {
+        Ok(project) => (
+            GetProjectItem {
+                id: project.id,
+                name: project.name,
+                description: project.description,
+                thumbnail_url: project.thumbnail_url,
+                is_template: project.is_template,
+            },
+            project.team_id,
+        ),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(user.id.clone(), team_id)
+        .await
+    {
+        Ok(_) => {
+            println!("# 권한 허용");
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let response = GetProjectResponse { data: project_data };
+
+    Json(response).into_response()
+}
+
+async fn get_entity_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let entity_list = entity_list
+        .into_iter()
+        .map(|e| GetEntityListItem {
+            id: e.id,
+            logical_name: e.logical_name,
+            physical_name: e.physical_name,
+            comment: e.comment,
+            columns: e.columns,
+            indexes: e.indexes,
+            x: e.x,
+            y: e.y,
+            width: e.width,
+            height: e.height,
+            color: e.color,
+            z_index: e.z_index,
+            collapsed: e.collapsed,
+        })
+        .collect::<Vec<_>>();
+
+    let response = GetEntityListResponse { list: entity_list };
+
+    Json(response).into_response()
+}
+
+async fn get_note_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let note_service = NoteService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let note_list = match note_service.get_note_list_by_project_id(&project_id).await {
+        Ok(note_list) => note_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let note_list = note_list
+        .into_iter()
+        .map(|e| GetNoteListItem {
+            id: e.id,
+            content: e.content,
             x: e.x,
             y: e.y,
+            width: e.width,
+            height: e.height,
+            color: e.color,
+            z_index: e.z_index,
+            collapsed: e.collapsed,
+        })
+        .collect::<Vec<_>>();
+
+    let response = GetNoteListResponse { list: note_list };
+
+    Json(response).into_response()
+}
+
+async fn get_diagram_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 다이어그램이 하나도 없던 프로젝트는 여기서 기본 다이어그램이 만들어집니다.
+    let diagram_list = match diagram_service
+        .get_diagram_list_with_default(&project_id, &entity_list)
+        .await
+    {
+        Ok(diagram_list) => diagram_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let list = diagram_list
+        .into_iter()
+        .map(|e| GetDiagramListItem {
+            entity_count: e.items.len(),
+            id: e.id,
+            name: e.name,
+            is_default: e.is_default,
+        })
+        .collect::<Vec<_>>();
+
+    let response = GetDiagramListResponse { list };
+
+    Json(response).into_response()
+}
+
+// 관계를 따라 엔티티(선택적으로 노트)를 자동 배치합니다. dry_run이면 좌표만 돌려주고 저장하지 않습니다.
+async fn auto_layout(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<AutoLayoutRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let note_list = match note_service.get_note_list_by_project_id(&project_id).await {
+        Ok(note_list) => note_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 다이어그램을 고르지 않으면 기본 다이어그램(엔티티/노트의 x/y)을 고칩니다.
+    let diagram_id = body
+        .diagram_id
+        .clone()
+        .unwrap_or_else(|| Diagram::default_id(&project_id));
+
+    let diagram = match diagram_service.get_diagram_by_id(&diagram_id).await {
+        Ok(diagram) if diagram.project_id == project_id => Some(diagram),
+        Ok(_) | Err(AllError::NotFound) if body.diagram_id.is_some() => {
+            return (StatusCode::NOT_FOUND).into_response()
+        }
+        Ok(_) | Err(AllError::NotFound) => None,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let is_default = diagram.as_ref().is_none_or(|e| e.is_default);
+
+    let target_list = match diagram.as_ref() {
+        Some(diagram) if !diagram.is_default => diagram.place_entities(&entity_list),
+        _ => entity_list.clone(),
+    };
+
+    let target_note_list = if body.include_notes && is_default {
+        note_list.clone()
+    } else {
+        vec![]
+    };
+
+    let result = layout::auto_layout(&target_list, &target_note_list);
+
+    let mut response = AutoLayoutResponse {
+        success: false,
+        dry_run: body.dry_run,
+        entities: vec![],
+        notes: vec![],
+    };
+
+    // 기본 다이어그램의 배치는 엔티티에 있으므로 다른 다이어그램만 따로 저장합니다.
+    let mut diagram = diagram.filter(|e| !e.is_default);
+
+    if !body.dry_run {
+        if let Some(diagram) = diagram.as_mut() {
+            diagram.sync_entities(&entity_list);
+
+            for item in diagram.items.iter_mut() {
+                if let Some(position) = result.entities.iter().find(|e| e.id == item.entity_id) {
+                    item.x = position.x;
+                    item.y = position.y;
+                }
+            }
+        }
+
+        // 기본 다이어그램의 배치는 엔티티/노트의 x/y에 씁니다. (좌표가 바뀐 것만)
+        let mut updated_list = vec![];
+        let mut updated_note_list = vec![];
+
+        if is_default {
+            for entity in entity_list.iter() {
+                if let Some(position) = result.entities.iter().find(|e| e.id == entity.id) {
+                    if entity.x != position.x || entity.y != position.y {
+                        let mut entity = entity.clone();
+                        entity.x = position.x;
+                        entity.y = position.y;
+                        updated_list.push(entity);
+                    }
+                }
+            }
+
+            for note in note_list.iter() {
+                if let Some(position) = result.notes.iter().find(|e| e.id == note.id) {
+                    let mut note = note.clone();
+                    note.x = position.x;
+                    note.y = position.y;
+                    updated_note_list.push(note);
+                }
+            }
+        }
+
+        // 좌표 변경은 한꺼번에 저장합니다. (100건까지는 한 트랜잭션)
+        let requests = updated_list
+            .iter()
+            .map(|e| (Entity::NAME, batch_write::put_request(e.to_hashmap())))
+            .chain(
+                updated_note_list
+                    .iter()
+                    .map(|e| (Note::NAME, batch_write::put_request(e.to_hashmap()))),
+            )
+            .chain(
+                diagram
+                    .iter()
+                    .map(|e| (Diagram::NAME, batch_write::put_request(e.to_hashmap()))),
+            )
+            .collect::<Vec<_>>();
+
+        if let Err(error) = batch_write::write_all(&database, requests).await {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        for entity in updated_list {
+            let before = entity_list.iter().find(|e| e.id == entity.id).cloned();
+
+            if let Err(error) = entity_history_service
+                .record_history(&user.id, HistoryAction::Update, before, Some(entity))
+                .await
+            {
+                println!("error: {error:?}");
+            }
+        }
+    }
+
+    response.entities = result.entities.into_iter().map(Into::into).collect();
+    response.notes = result.notes.into_iter().map(Into::into).collect();
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+// 엔티티/노트 작업 여러 건을 한 번의 권한 확인으로 처리합니다.
+// 작업은 순서대로 메모리에서 적용하고(실패한 작업은 건너뜀), 최종 상태만 한꺼번에 저장합니다.
+async fn batch_operations(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<BatchRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    if body.operations.len() > BatchRequest::MAX_OPERATIONS {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "한 번에 처리할 수 있는 작업은 {}건까지입니다.",
+                BatchRequest::MAX_OPERATIONS
+            ),
+        )
+            .into_response();
+    }
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let original_entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let original_note_list = match note_service.get_note_list_by_project_id(&project_id).await {
+        Ok(note_list) => note_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let domain_list = match domain_service
+        .get_domain_list_by_team_id(&project.team_id)
+        .await
+    {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let naming_rule = match naming_rule_service
+        .get_effective_naming_rule(&project.team_id, &project_id)
+        .await
+    {
+        Ok(naming_rule) => naming_rule,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 생성/수정되는 엔티티는 단건 API와 같은 검사를 거칩니다.
+    let check_entity =
+        |entity: &mut Entity| -> Result<Vec<LintFinding>, (String, Vec<LintFinding>)> {
+            entity.validate_geometry().map_err(|e| (e, vec![]))?;
+            entity.validate_indexes().map_err(|e| (e, vec![]))?;
+            entity
+                .apply_domains(&domain_list)
+                .map_err(|e| (e, vec![]))?;
+
+            let findings = match naming_rule.as_ref() {
+                Some(naming_rule) => naming::check_entity(entity, &naming_rule.config),
+                None => vec![],
+            };
+
+            if findings.iter().any(|e| e.severity == LintSeverity::Error) {
+                return Err(("명명 규칙 위반".into(), findings));
+            }
+
+            Ok(findings)
+        };
+
+    let mut entity_list = original_entity_list.clone();
+    let mut note_list = original_note_list.clone();
+    let mut changed_entity_ids: Vec<String> = vec![];
+    let mut changed_note_ids: Vec<String> = vec![];
+
+    let mut response = BatchResponse {
+        success: false,
+        results: vec![],
+        partial_write: false,
+        written_ids: vec![],
+    };
+
+    for (index, operation) in body.operations.into_iter().enumerate() {
+        let outcome = match operation {
+            BatchOperation::MoveEntity { id, x, y } => {
+                match entity_list.iter_mut().find(|e| e.id == id) {
+                    Some(entity) => {
+                        let mut moved = entity.clone();
+                        moved.x = x;
+                        moved.y = y;
+
+                        match moved.validate_geometry() {
+                            Ok(_) => {
+                                *entity = moved;
+                                changed_entity_ids.push(id.clone());
+                                Ok((id, vec![]))
+                            }
+                            Err(message) => Err((Some(id), message, vec![])),
+                        }
+                    }
+                    None => Err((Some(id), "엔티티를 찾을 수 없습니다.".into(), vec![])),
+                }
+            }
+            BatchOperation::CreateEntity { data } => {
+                let mut entity = Entity {
+                    id: generate_uuid(),
+                    project_id: data.project_id,
+                    logical_name: data.logical_name,
+                    physical_name: data.physical_name,
+                    comment: data.comment,
+                    columns: data.columns,
+                    indexes: data.indexes,
+                    x: data.x,
+                    y: data.y,
+                    width: data.width,
+                    height: data.height,
+                    color: data.color,
+                    z_index: data.z_index,
+                    collapsed: data.collapsed,
+                };
+
+                if entity.project_id != project_id {
+                    Err((None, "다른 프로젝트의 엔티티입니다.".into(), vec![]))
+                } else {
+                    match check_entity(&mut entity) {
+                        Ok(findings) => {
+                            let id = entity.id.clone();
+                            entity_list.push(entity);
+                            changed_entity_ids.push(id.clone());
+                            Ok((id, findings))
+                        }
+                        Err((message, findings)) => Err((None, message, findings)),
+                    }
+                }
+            }
+            BatchOperation::UpdateEntity { id, data } => {
+                match entity_list.iter_mut().find(|e| e.id == id) {
+                    Some(entity) => {
+                        let old = entity.clone();
+                        let prune_indexes = data.indexes.is_none();
+                        let mut updated = Entity {
+                            id: old.id,
+                            project_id: old.project_id,
+                            logical_name: data.logical_name,
+                            physical_name: data.physical_name,
+                            comment: data.comment,
+                            columns: data.columns,
+                            indexes: data.indexes.unwrap_or(old.indexes),
+                            x: data.x,
+                            y: data.y,
+                            width: data.width.map_or(old.width, |e| (e != 0.0).then_some(e)),
+                            height: data.height.map_or(old.height, |e| (e != 0.0).then_some(e)),
+                            color: data
+                                .color
+                                .map_or(old.color, |e| (!e.is_empty()).then_some(e)),
+                            z_index: data.z_index.unwrap_or(old.z_index),
+                            collapsed: data.collapsed.unwrap_or(old.collapsed),
+                        };
+
+                        if prune_indexes {
+                            updated.prune_indexes();
+                        }
+
+                        match check_entity(&mut updated) {
+                            Ok(findings) => {
+                                *entity = updated;
+                                changed_entity_ids.push(id.clone());
+                                Ok((id, findings))
+                            }
+                            Err((message, findings)) => Err((Some(id), message, findings)),
+                        }
+                    }
+                    None => Err((Some(id), "엔티티를 찾을 수 없습니다.".into(), vec![])),
+                }
+            }
+            BatchOperation::DeleteEntity { id } => {
+                match entity_list.iter().position(|e| e.id == id) {
+                    Some(position) => {
+                        entity_list.remove(position);
+                        changed_entity_ids.push(id.clone());
+                        Ok((id, vec![]))
+                    }
+                    None => Err((Some(id), "엔티티를 찾을 수 없습니다.".into(), vec![])),
+                }
+            }
+            BatchOperation::MoveNote { id, x, y } => {
+                match note_list.iter_mut().find(|e| e.id == id) {
+                    Some(note) => {
+                        let mut moved = note.clone();
+                        moved.x = x;
+                        moved.y = y;
+
+                        match moved.validate_geometry() {
+                            Ok(_) => {
+                                *note = moved;
+                                changed_note_ids.push(id.clone());
+                                Ok((id, vec![]))
+                            }
+                            Err(message) => Err((Some(id), message, vec![])),
+                        }
+                    }
+                    None => Err((Some(id), "노트를 찾을 수 없습니다.".into(), vec![])),
+                }
+            }
+            BatchOperation::CreateNote { data } => {
+                let note = Note {
+                    id: generate_uuid(),
+                    project_id: data.project_id,
+                    content: data.content,
+                    x: data.x,
+                    y: data.y,
+                    width: data.width,
+                    height: data.height,
+                    color: data.color,
+                    z_index: data.z_index,
+                    collapsed: data.collapsed,
+                };
+
+                if note.project_id != project_id {
+                    Err((None, "다른 프로젝트의 노트입니다.".into(), vec![]))
+                } else {
+                    match note.validate_geometry() {
+                        Ok(_) => {
+                            let id = note.id.clone();
+                            note_list.push(note);
+                            changed_note_ids.push(id.clone());
+                            Ok((id, vec![]))
+                        }
+                        Err(message) => Err((None, message, vec![])),
+                    }
+                }
+            }
+            BatchOperation::UpdateNote { id, data } => {
+                match note_list.iter_mut().find(|e| e.id == id) {
+                    Some(note) => {
+                        let old = note.clone();
+                        let updated = Note {
+                            id: old.id,
+                            project_id: old.project_id,
+                            content: data.content,
+                            x: data.x,
+                            y: data.y,
+                            width: data.width.map_or(old.width, |e| (e != 0.0).then_some(e)),
+                            height: data.height.map_or(old.height, |e| (e != 0.0).then_some(e)),
+                            color: data
+                                .color
+                                .map_or(old.color, |e| (!e.is_empty()).then_some(e)),
+                            z_index: data.z_index.unwrap_or(old.z_index),
+                            collapsed: data.collapsed.unwrap_or(old.collapsed),
+                        };
+
+                        match updated.validate_geometry() {
+                            Ok(_) => {
+                                *note = updated;
+                                changed_note_ids.push(id.clone());
+                                Ok((id, vec![]))
+                            }
+                            Err(message) => Err((Some(id), message, vec![])),
+                        }
+                    }
+                    None => Err((Some(id), "노트를 찾을 수 없습니다.".into(), vec![])),
+                }
+            }
+            BatchOperation::DeleteNote { id } => match note_list.iter().position(|e| e.id == id) {
+                Some(position) => {
+                    note_list.remove(position);
+                    changed_note_ids.push(id.clone());
+                    Ok((id, vec![]))
+                }
+                None => Err((Some(id), "노트를 찾을 수 없습니다.".into(), vec![])),
+            },
+        };
+
+        response.results.push(match outcome {
+            Ok((id, naming_findings)) => BatchOperationResult {
+                index,
+                success: true,
+                id: Some(id),
+                message: None,
+                naming_findings,
+            },
+            Err((id, message, naming_findings)) => BatchOperationResult {
+                index,
+                success: false,
+                id,
+                message: Some(message),
+                naming_findings,
+            },
+        });
+    }
+
+    // 같은 항목을 여러 번 바꿔도 최종 상태로 한 번만 씁니다.
+    // 배치 안에서 만들고 지운 항목은 저장할 것도 지울 것도 없습니다.
+    let put_entity_list = entity_list
+        .iter()
+        .filter(|e| changed_entity_ids.contains(&e.id))
+        .cloned()
+        .collect::<Vec<_>>();
+    let deleted_entity_list = original_entity_list
+        .iter()
+        .filter(|e| !entity_list.iter().any(|entity| entity.id == e.id))
+        .cloned()
+        .collect::<Vec<_>>();
+    let deleted_entity_ids = deleted_entity_list
+        .iter()
+        .map(|e| e.id.clone())
+        .collect::<Vec<_>>();
+
+    let put_note_list = note_list
+        .iter()
+        .filter(|e| changed_note_ids.contains(&e.id))
+        .cloned()
+        .collect::<Vec<_>>();
+    let deleted_note_ids = original_note_list
+        .iter()
+        .filter(|e| !note_list.iter().any(|note| note.id == e.id))
+        .map(|e| e.id.clone())
+        .collect::<Vec<_>>();
+
+    // 100건까지는 한 트랜잭션으로 저장합니다.
+    // 그보다 많으면 나눠 쓰므로, 도중에 실패하면 반영된 id를 응답에 담습니다.
+    let requests = put_entity_list
+        .iter()
+        .map(|e| (Entity::NAME, batch_write::put_request(e.to_hashmap())))
+        .chain(
+            deleted_entity_ids
+                .iter()
+                .map(|id| (Entity::NAME, batch_write::delete_request(id))),
+        )
+        .chain(
+            put_note_list
+                .iter()
+                .map(|e| (Note::NAME, batch_write::put_request(e.to_hashmap()))),
+        )
+        .chain(
+            deleted_note_ids
+                .iter()
+                .map(|id| (Note::NAME, batch_write::delete_request(id))),
+        )
+        .collect::<Vec<_>>();
+
+    let requested_ids = requests
+        .iter()
+        .filter_map(|(_, e)| batch_write::request_id(e))
+        .collect::<Vec<_>>();
+
+    if let Err(error) = batch_write::write_all(&database, requests).await {
+        println!("error: {:?}", error.error);
+
+        if error.written.is_empty() {
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        let written_ids = error
+            .written
+            .iter()
+            .filter_map(batch_write::request_id)
+            .collect::<Vec<_>>();
+
+        // 저장되지 않은 항목을 다룬 작업은 실패로 돌려서, 어떤 작업을 다시 보내야 하는지 알 수 있게 합니다.
+        for result in response.results.iter_mut().filter(|e| e.success) {
+            let is_unwritten = result
+                .id
+                .as_ref()
+                .is_some_and(|id| requested_ids.contains(id) && !written_ids.contains(id));
+
+            if is_unwritten {
+                result.success = false;
+                result.message = Some("저장 중 오류로 반영되지 않았습니다.".into());
+            }
+        }
+
+        response.partial_write = true;
+        response.written_ids = written_ids;
+    }
+
+    let is_written = |id: &String| !response.partial_write || response.written_ids.contains(id);
+
+    let history_list = put_entity_list
+        .into_iter()
+        .filter(|e| is_written(&e.id))
+        .map(
+            |entity| match original_entity_list.iter().find(|e| e.id == entity.id) {
+                Some(before) => (HistoryAction::Update, Some(before.clone()), Some(entity)),
+                None => (HistoryAction::Create, None, Some(entity)),
+            },
+        )
+        .chain(
+            deleted_entity_list
+                .into_iter()
+                .filter(|e| is_written(&e.id))
+                .map(|entity| (HistoryAction::Delete, Some(entity), None)),
+        );
+
+    for (action, before, after) in history_list {
+        if let Err(error) = entity_history_service
+            .record_history(&user.id, action, before, after)
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+
+    response.success = !response.partial_write && response.results.iter().all(|e| e.success);
+
+    Json(response).into_response()
+}
+
+async fn import_ddl(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<ImportDdlRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(error) => {
+            if let AllError::NotFound = error {
+                println!("# 프로젝트 없음");
+                return (StatusCode::NOT_FOUND).into_response();
+            } else {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let tables = match ddl::parse_ddl(&body.sql, body.dialect) {
+        Ok(tables) => tables,
+        Err(AllError::InvalidInput(message)) => {
+            return (StatusCode::BAD_REQUEST, message).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let plan = schema_import::build_import_plan(&project_id, &entity_list, tables);
+
+    if !body.dry_run {
+        let results = join_all(
+            plan.entities()
+                .map(|entity| entity_service.create_entity(entity.clone())),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        record_entity_history_list(
+            &database,
+            &user.id,
+            &entity_list,
+            plan.entities().cloned().collect(),
+        )
+        .await;
+    }
+
+    let response = ImportSchemaResponse {
+        success: true,
+        dry_run: body.dry_run,
+        created: plan.created.into_iter().map(Into::into).collect(),
+        updated: plan.updated.into_iter().map(Into::into).collect(),
+        warnings: plan.warnings,
+    };
+
+    Json(response).into_response()
+}
+
+async fn import_schema_file(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<ImportSchemaFileRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let file = match schema_file::parse(body.format, &body.source) {
+        Ok(file) => file,
+        Err(AllError::InvalidInput(message)) => {
+            return (StatusCode::BAD_REQUEST, message).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let plan = schema_import::build_import_plan(&project_id, &entity_list, file.tables);
+
+    if !body.dry_run {
+        let results = join_all(
+            plan.entities()
+                .map(|entity| entity_service.create_entity(entity.clone())),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        record_entity_history_list(
+            &database,
+            &user.id,
+            &entity_list,
+            plan.entities().cloned().collect(),
+        )
+        .await;
+    }
+
+    let mut warnings = file.warnings;
+    warnings.extend(plan.warnings);
+
+    let response = ImportSchemaResponse {
+        success: true,
+        dry_run: body.dry_run,
+        created: plan.created.into_iter().map(Into::into).collect(),
+        updated: plan.updated.into_iter().map(Into::into).collect(),
+        warnings,
+    };
+
+    Json(response).into_response()
+}
+
+async fn validate_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Query(query): Query<ValidateProjectRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let naming_rule = match naming_rule_service
+        .get_effective_naming_rule(&team_id, &project_id)
+        .await
+    {
+        Ok(naming_rule) => naming_rule,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let mut findings = lint::lint_entities(&entity_list, query.dialect);
+
+    if let Some(naming_rule) = naming_rule {
+        findings.extend(
+            entity_list
+                .iter()
+                .flat_map(|e| naming::check_entity(e, &naming_rule.config)),
+        );
+    }
+
+    let error_count = findings
+        .iter()
+        .filter(|e| e.severity == LintSeverity::Error)
+        .count();
+    let warning_count = findings.len() - error_count;
+
+    let response = ValidateProjectResponse {
+        valid: error_count == 0,
+        error_count,
+        warning_count,
+        findings,
+    };
+
+    Json(response).into_response()
+}
+
+async fn generate_physical_name(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<GeneratePhysicalNameRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let term_list = match term_service
+        .get_term_list_by_team_id(&project.team_id)
+        .await
+    {
+        Ok(term_list) => term_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let mut entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let original_list = entity_list.clone();
+    let changes = glossary::generate_physical_names(&mut entity_list, &term_list, body.overwrite);
+
+    if !body.dry_run {
+        let updated_list = entity_list
+            .into_iter()
+            .filter(|entity| changes.iter().any(|e| e.entity_id == entity.id))
+            .collect::<Vec<_>>();
+
+        let results = join_all(
+            updated_list
+                .iter()
+                .map(|entity| entity_service.create_entity(entity.clone())),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        record_entity_history_list(&database, &user.id, &original_list, updated_list).await;
+    }
+
+    let response = GeneratePhysicalNameResponse {
+        success: true,
+        dry_run: body.dry_run,
+        changes,
+    };
+
+    Json(response).into_response()
+}
+
+async fn get_project_naming_rule(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let naming_rule_list = match naming_rule_service
+        .get_naming_rule_list_by_team_id(&team_id)
+        .await
+    {
+        Ok(naming_rule_list) => naming_rule_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let to_item = |e: &NamingRule| GetNamingRuleItem {
+        id: e.id.clone(),
+        team_id: e.team_id.clone(),
+        project_id: e.project_id.clone(),
+        config: e.config.clone(),
+    };
+
+    let response = GetNamingRuleResponse {
+        data: naming_rule_list
+            .iter()
+            .find(|e| e.project_id.as_deref() == Some(project_id.as_str()))
+            .map(to_item),
+        inherited: naming_rule_list
+            .iter()
+            .find(|e| e.project_id.is_none())
+            .map(to_item),
+    };
+
+    Json(response).into_response()
+}
+
+async fn update_project_naming_rule(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<UpdateNamingRuleRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let mut response = UpdateNamingRuleResponse { success: false };
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let rule_id = match naming_rule_service
+        .find_naming_rule(&team_id, Some(&project_id))
+        .await
+    {
+        Ok(Some(naming_rule)) => naming_rule.id,
+        Ok(None) => generate_uuid(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let data = NamingRule {
+        id: rule_id,
+        team_id,
+        project_id: Some(project_id),
+        config: body.config,
+    };
+
+    match naming_rule_service.save_naming_rule(data).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_project_naming_rule(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let mut response = UpdateNamingRuleResponse { success: false };
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match naming_rule_service
+        .find_naming_rule(&team_id, Some(&project_id))
+        .await
+    {
+        Ok(Some(naming_rule)) => {
+            if let Err(error) = naming_rule_service
+                .delete_naming_rule(&naming_rule.id)
+                .await
+            {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+        Ok(None) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+async fn fix_names(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<FixNamesRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let naming_rule = match naming_rule_service
+        .get_effective_naming_rule(&team_id, &project_id)
+        .await
+    {
+        Ok(Some(naming_rule)) => naming_rule,
+        Ok(None) => {
+            println!("# 명명 규칙 없음");
+            return (StatusCode::NOT_FOUND).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let mut entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let original_list = entity_list.clone();
+    let changes = naming::fix_entities(&mut entity_list, &naming_rule.config);
+
+    let remaining = entity_list
+        .iter()
+        .flat_map(|e| naming::check_entity(e, &naming_rule.config))
+        .collect::<Vec<_>>();
+
+    if !body.dry_run {
+        let updated_list = entity_list
+            .into_iter()
+            .filter(|entity| changes.iter().any(|e| e.entity_id == entity.id))
+            .collect::<Vec<_>>();
+
+        let results = join_all(
+            updated_list
+                .iter()
+                .map(|entity| entity_service.create_entity(entity.clone())),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+
+        record_entity_history_list(&database, &user.id, &original_list, updated_list).await;
+    }
+
+    let response = FixNamesResponse {
+        success: true,
+        dry_run: body.dry_run,
+        changes,
+        remaining,
+    };
+
+    Json(response).into_response()
+}
+
+async fn get_domain_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let domain_list = match domain_service.get_domain_list_by_team_id(&team_id).await {
+        Ok(domain_list) => domain_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    // 팀 전체 도메인과 이 프로젝트 전용 도메인
+    let domain_list = domain_list
+        .into_iter()
+        .filter(|e| e.project_id.is_none() || e.project_id.as_ref() == Some(&project_id))
+        .map(Into::into)
+        .collect::<Vec<_>>();
+
+    let response = GetDomainListResponse { list: domain_list };
+
+    Json(response).into_response()
+}
+
+async fn diff_project(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<DiffProjectRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let snapshot_service = SnapshotService::new(database.clone(), s3_client);
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let mut states = vec![];
+
+    for (entities, snapshot_id) in [
+        (body.base, body.base_snapshot_id),
+        (body.target, body.target_snapshot_id),
+    ] {
+        let state = match (entities, snapshot_id) {
+            (Some(entities), _) => Some(entities),
+            (None, Some(snapshot_id)) => {
+                let snapshot = match snapshot_service.get_snapshot_by_id(&snapshot_id).await {
+                    Ok(snapshot) if snapshot.project_id == project_id => snapshot,
+                    Ok(_) => return (StatusCode::BAD_REQUEST).into_response(),
+                    Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+                };
+
+                match snapshot_service.get_snapshot_data(&snapshot).await {
+                    Ok(data) => Some(data.entities),
+                    Err(error) => {
+                        println!("error: {error:?}");
+                        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+                    }
+                }
+            }
+            (None, None) => None,
+        };
+
+        states.push(state);
+    }
+
+    let target = match states.pop().flatten() {
+        Some(target) => target,
+        None => match entity_service
+            .get_entity_list_by_project_id(&project_id)
+            .await
+        {
+            Ok(entity_list) => entity_list,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+        },
+    };
+
+    let base = match states.pop().flatten() {
+        Some(base) => base,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                "base 또는 base_snapshot_id가 필요합니다.",
+            )
+                .into_response()
+        }
+    };
+
+    let tables = diff::diff_entities(&base, &target);
+    let migration = diff::generate_migration(&base, &target, body.dialect);
+
+    let response = DiffProjectResponse {
+        tables,
+        sql: migration.to_sql(),
+        statements: migration.statements,
+        warnings: migration.warnings,
+    };
+
+    Json(response).into_response()
+}
+
+async fn create_snapshot(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    Path(project_id): Path<String>,
+    Json(body): Json<CreateSnapshotRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let entity_service = EntityService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let snapshot_service = SnapshotService::new(database.clone(), s3_client);
+
+    let mut response = CreateSnapshotResponse {
+        success: false,
+        snapshot_id: "".into(),
+    };
+
+    let project = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&project.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if body.name.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let snapshot_list = match snapshot_service
+        .get_snapshot_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(snapshot_list) => snapshot_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    if snapshot_list.iter().any(|e| e.name == body.name.trim()) {
+        println!("# 스냅샷 이름 중복");
+        return (StatusCode::CONFLICT).into_response();
+    }
+
+    let entity_list = match entity_service
+        .get_entity_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(entity_list) => entity_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let note_list = match note_service.get_note_list_by_project_id(&project_id).await {
+        Ok(note_list) => note_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let diagram_list = match diagram_service
+        .get_diagram_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(diagram_list) => diagram_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let snapshot_id = generate_uuid();
+
+    let data = Snapshot {
+        data_key: Snapshot::make_data_key(&project_id, &snapshot_id),
+        id: snapshot_id,
+        project_id,
+        team_id: project.team_id.clone(),
+        name: body.name.trim().to_owned(),
+        description: body.description,
+        created_by: user.id,
+        created_at: Epoch::now(),
+        entity_count: entity_list.len(),
+        note_count: note_list.len(),
+    };
+
+    let payload = SnapshotData {
+        project,
+        entities: entity_list,
+        notes: note_list,
+        diagrams: diagram_list,
+    };
+
+    match snapshot_service.create_snapshot(data, &payload).await {
+        Ok(snapshot_id) => {
+            response.snapshot_id = snapshot_id;
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn get_snapshot_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let snapshot_service = SnapshotService::new(database.clone(), s3_client);
+
+    let team_id = match project_service.get_project_by_id(&project_id).await {
+        Ok(project) => project.team_id,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let mut snapshot_list = match snapshot_service
+        .get_snapshot_list_by_project_id(&project_id)
+        .await
+    {
+        Ok(snapshot_list) => snapshot_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    snapshot_list.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+
+    let snapshot_list = snapshot_list
+        .into_iter()
+        .map(|e| GetSnapshotListItem {
+            id: e.id,
+            name: e.name,
+            description: e.description,
+            created_by: e.created_by,
+            created_at: e.created_at,
+            entity_count: e.entity_count,
+            note_count: e.note_count,
         })
         .collect::<Vec<_>>();
 
-    let response = GetNoteListResponse { list: note_list };
+    let response = GetSnapshotListResponse {
+        list: snapshot_list,
+    };
 
     Json(response).into_response()
 }
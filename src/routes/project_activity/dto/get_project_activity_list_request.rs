@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+// page는 1부터 시작합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetProjectActivityListRequest {
+    pub page: Option<usize>,
+    pub page_size: Option<usize>,
+}
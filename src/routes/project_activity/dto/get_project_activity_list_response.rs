@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ProjectActivityAction;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetProjectActivityItem {
+    pub id: String,
+    pub action: ProjectActivityAction,
+    pub actor_id: String,
+    pub created_at: u64,
+    pub from_team_id: Option<String>,
+    pub to_team_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetProjectActivityListResponse {
+    pub list: Vec<GetProjectActivityItem>,
+    pub total: usize,
+    pub page: usize,
+    pub page_size: usize,
+}
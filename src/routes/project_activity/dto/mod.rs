@@ -0,0 +1,5 @@
+pub mod get_project_activity_list_request;
+pub mod get_project_activity_list_response;
+
+pub use get_project_activity_list_request::GetProjectActivityListRequest;
+pub use get_project_activity_list_response::*;
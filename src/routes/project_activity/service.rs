@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+
+use epoch_timestamp::Epoch;
+
+use crate::{
+    models::{ProjectActivity, ProjectActivityAction},
+    utils::AllError,
+};
+
+pub struct ProjectActivityService {
+    client: Extension<Arc<Client>>,
+}
+
+impl ProjectActivityService {
+    pub fn new(client: Extension<Arc<Client>>) -> Self {
+        Self { client }
+    }
+
+    pub async fn create_project_activity(&self, data: ProjectActivity) -> Result<String, AllError> {
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(ProjectActivity::NAME)
+            .set_item(input)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(data.id),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    // 팀 이동 이력을 남깁니다.
+    pub async fn record_transfer(
+        &self,
+        actor_id: &str,
+        project_id: &str,
+        from_team_id: &str,
+        to_team_id: &str,
+    ) -> Result<String, AllError> {
+        let data = ProjectActivity {
+            id: uuid::Uuid::new_v4().to_string(),
+            project_id: project_id.to_owned(),
+            action: ProjectActivityAction::Transfer,
+            actor_id: actor_id.to_owned(),
+            created_at: Epoch::now(),
+            from_team_id: Some(from_team_id.to_owned()),
+            to_team_id: Some(to_team_id.to_owned()),
+        };
+
+        self.create_project_activity(data).await
+    }
+
+    pub async fn get_project_activity_list_by_project_id(
+        &self,
+        project_id: impl Into<String>,
+    ) -> Result<Vec<ProjectActivity>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let project_id = project_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(ProjectActivity::NAME)
+                .filter_expression("project_id = :project_id")
+                .expression_attribute_values(":project_id", AttributeValue::S(project_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(project_activity) =
+                                ProjectActivity::from_hashmap(item.to_owned())
+                            {
+                                list.push(project_activity);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::SnapshotData;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSnapshotItem {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub description: String,
+    pub created_by: String,
+    pub created_at: u64,
+    pub data: SnapshotData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSnapshotResponse {
+    pub data: GetSnapshotItem,
+}
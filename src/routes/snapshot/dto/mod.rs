@@ -0,0 +1,13 @@
+pub mod create_snapshot_request;
+pub mod create_snapshot_response;
+pub mod get_snapshot_list_response;
+pub mod get_snapshot_response;
+pub mod restore_snapshot_request;
+pub mod restore_snapshot_response;
+
+pub use create_snapshot_request::CreateSnapshotRequest;
+pub use create_snapshot_response::CreateSnapshotResponse;
+pub use get_snapshot_list_response::*;
+pub use get_snapshot_response::*;
+pub use restore_snapshot_request::RestoreSnapshotRequest;
+pub use restore_snapshot_response::RestoreSnapshotResponse;
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+// new_project가 true면 현재 프로젝트를 덮어쓰지 않고 새 프로젝트로 복원합니다. (name이 없으면 스냅샷 당시 이름 사용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreSnapshotRequest {
+    #[serde(default)]
+    pub new_project: bool,
+    #[serde(default)]
+    pub name: Option<String>,
+}
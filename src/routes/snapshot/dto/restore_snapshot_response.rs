@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreSnapshotResponse {
+    pub success: bool,
+    pub project_id: String,
+}
@@ -0,0 +1,447 @@
+use std::sync::Arc;
+
+use aws_sdk_dynamodb::Client;
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use futures::future::join_all;
+
+use crate::{
+    extensions::CurrentUser,
+    models::{HistoryAction, Project, Snapshot, TeamUserAuthority},
+    routes::{
+        diagram::DiagramService, domain::DomainService, entity::EntityService,
+        entity_history::EntityHistoryService, note::NoteService, project::ProjectService,
+        team::TeamService,
+    },
+    utils::{generate_uuid, remap, AllError},
+};
+
+use super::{
+    dto::{GetSnapshotItem, GetSnapshotResponse, RestoreSnapshotRequest, RestoreSnapshotResponse},
+    SnapshotService,
+};
+
+pub async fn router() -> Router {
+    Router::new()
+        .route("/:snapshot_id", get(get_snapshot))
+        .route("/:snapshot_id/download", get(download_snapshot))
+        .route("/:snapshot_id/restore", post(restore_snapshot))
+}
+
+// 프로젝트가 삭제된 뒤에도 새 프로젝트로 복원할 수 있도록 스냅샷 당시의 팀을 사용합니다.
+async fn find_team_id(database: Extension<Arc<Client>>, snapshot: &Snapshot) -> String {
+    let project_service = ProjectService::new(database);
+
+    match project_service
+        .get_project_by_id(&snapshot.project_id)
+        .await
+    {
+        Ok(project) => project.team_id,
+        Err(_) => snapshot.team_id.clone(),
+    }
+}
+
+async fn get_snapshot(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    Path(snapshot_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let snapshot_service = SnapshotService::new(database.clone(), s3_client);
+
+    let snapshot = match snapshot_service.get_snapshot_by_id(&snapshot_id).await {
+        Ok(snapshot) => snapshot,
+        Err(error) => {
+            if let AllError::NotFound = error {
+                println!("# 스냅샷 없음");
+                return (StatusCode::NOT_FOUND).into_response();
+            } else {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+    };
+
+    let team_id = find_team_id(database.clone(), &snapshot).await;
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let data = match snapshot_service.get_snapshot_data(&snapshot).await {
+        Ok(data) => data,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let response = GetSnapshotResponse {
+        data: GetSnapshotItem {
+            id: snapshot.id,
+            project_id: snapshot.project_id,
+            name: snapshot.name,
+            description: snapshot.description,
+            created_by: snapshot.created_by,
+            created_at: snapshot.created_at,
+            data,
+        },
+    };
+
+    Json(response).into_response()
+}
+
+async fn download_snapshot(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    Path(snapshot_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let snapshot_service = SnapshotService::new(database.clone(), s3_client);
+
+    let snapshot = match snapshot_service.get_snapshot_by_id(&snapshot_id).await {
+        Ok(snapshot) => snapshot,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let team_id = find_team_id(database.clone(), &snapshot).await;
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let data = match snapshot_service.get_snapshot_data(&snapshot).await {
+        Ok(data) => data,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let snapshot_id = snapshot.id.clone();
+
+    let item = GetSnapshotItem {
+        id: snapshot.id,
+        project_id: snapshot.project_id,
+        name: snapshot.name,
+        description: snapshot.description,
+        created_by: snapshot.created_by,
+        created_at: snapshot.created_at,
+        data,
+    };
+
+    let body = match serde_json::to_string_pretty(&item) {
+        Ok(body) => body,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let headers = [
+        (header::CONTENT_TYPE, "application/json".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"snapshot-{snapshot_id}.json\""),
+        ),
+    ];
+
+    (headers, body).into_response()
+}
+
+async fn restore_snapshot(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    Path(snapshot_id): Path<String>,
+    Json(body): Json<RestoreSnapshotRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+    let entity_service = EntityService::new(database.clone());
+    let note_service = NoteService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+    let diagram_service = DiagramService::new(database.clone());
+    let entity_history_service = EntityHistoryService::new(database.clone());
+    let snapshot_service = SnapshotService::new(database.clone(), s3_client);
+
+    let mut response = RestoreSnapshotResponse {
+        success: false,
+        project_id: "".into(),
+    };
+
+    let snapshot = match snapshot_service.get_snapshot_by_id(&snapshot_id).await {
+        Ok(snapshot) => snapshot,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let current_project = project_service
+        .get_project_by_id(&snapshot.project_id)
+        .await
+        .ok();
+
+    if current_project.is_none() && !body.new_project {
+        println!("# 원본 프로젝트 없음: 새 프로젝트로만 복원 가능");
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    let team_id = current_project
+        .as_ref()
+        .map(|e| e.team_id.clone())
+        .unwrap_or_else(|| snapshot.team_id.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let data = match snapshot_service.get_snapshot_data(&snapshot).await {
+        Ok(data) => data,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let domain_list = match domain_service.get_domain_list_by_team_id(&team_id).await {
+        Ok(domain_list) => domain_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    // 새 프로젝트면 현재 상태가 비어 있으므로 모두 새로 만든 것으로 기록됩니다.
+    let (project, mut entities, notes, diagrams, entity_list, note_list, diagram_list) = if body
+        .new_project
+    {
+        let project_id = generate_uuid();
+
+        let (entities, notes) = remap::copy_to_project(&data.entities, &data.notes, &project_id);
+        let diagrams = remap::copy_diagrams(&data.diagrams, &data.entities, &entities, &project_id);
+
+        let project = Project {
+            id: project_id,
+            team_id,
+            name: body.name.unwrap_or(data.project.name),
+            description: data.project.description,
+            thumbnail_url: data.project.thumbnail_url,
+            is_template: false,
+        };
+
+        (project, entities, notes, diagrams, vec![], vec![], vec![])
+    } else {
+        let current_project = current_project.unwrap();
+
+        let entity_list = match entity_service
+            .get_entity_list_by_project_id(&current_project.id)
+            .await
+        {
+            Ok(entity_list) => entity_list,
+            Err(error) => {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        };
+
+        let note_list = match note_service
+            .get_note_list_by_project_id(&current_project.id)
+            .await
+        {
+            Ok(note_list) => note_list,
+            Err(error) => {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        };
+
+        let diagram_list = match diagram_service
+            .get_diagram_list_by_project_id(&current_project.id)
+            .await
+        {
+            Ok(diagram_list) => diagram_list,
+            Err(error) => {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        };
+
+        let project = Project {
+            id: current_project.id,
+            team_id: current_project.team_id,
+            name: data.project.name,
+            description: data.project.description,
+            thumbnail_url: data.project.thumbnail_url,
+            is_template: current_project.is_template,
+        };
+
+        (
+            project,
+            data.entities,
+            data.notes,
+            data.diagrams,
+            entity_list,
+            note_list,
+            diagram_list,
+        )
+    };
+
+    // 스냅샷 이후 삭제되었거나 이 프로젝트에서 쓸 수 없는 도메인 연결은 끊고, 남은 도메인은 현재 속성으로 맞춥니다.
+    remap::detach_foreign_domains(&mut entities, &domain_list);
+
+    for entity in entities.iter_mut() {
+        if let Err(message) = entity.apply_domains(&domain_list) {
+            println!("error: {message}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let project_id = project.id.clone();
+
+    if let Err(error) = project_service.create_project(project).await {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    // 스냅샷 이후에 생긴 엔티티/노트/다이어그램은 지웁니다.
+    let deleted_entity_ids = entity_list
+        .iter()
+        .filter(|entity| !entities.iter().any(|e| e.id == entity.id))
+        .map(|e| e.id.clone())
+        .collect::<Vec<_>>();
+
+    let deleted_note_ids = note_list
+        .iter()
+        .filter(|note| !notes.iter().any(|e| e.id == note.id))
+        .map(|e| e.id.clone())
+        .collect::<Vec<_>>();
+
+    if let Err(error) = entity_service
+        .batch_write_entities(&entities, &deleted_entity_ids)
+        .await
+    {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    if let Err(error) = note_service
+        .batch_write_notes(&notes, &deleted_note_ids)
+        .await
+    {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    let diagram_results = join_all(
+        diagram_list
+            .iter()
+            .filter(|diagram| !diagrams.iter().any(|e| e.id == diagram.id))
+            .map(|diagram| diagram_service.delete_diagram(&diagram.id)),
+    )
+    .await;
+
+    let diagram_results = diagram_results.into_iter().chain(
+        join_all(diagrams.into_iter().map(|mut diagram| {
+            diagram.sync_entities(&entities);
+            async { diagram_service.create_diagram(diagram).await.map(|_| ()) }
+        }))
+        .await,
+    );
+
+    if let Some(Err(error)) = diagram_results.into_iter().find(|e| e.is_err()) {
+        println!("error: {error:?}");
+        return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    }
+
+    // 이력 기록에 실패해도 복원은 되돌리지 않습니다.
+    for entity in entities {
+        let before = entity_list.iter().find(|e| e.id == entity.id).cloned();
+
+        let action = match before.as_ref() {
+            None => HistoryAction::Create,
+            Some(before)
+                if serde_json::to_value(before).ok() != serde_json::to_value(&entity).ok() =>
+            {
+                HistoryAction::Update
+            }
+            Some(_) => continue,
+        };
+
+        if let Err(error) = entity_history_service
+            .record_history(&user.id, action, before, Some(entity))
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+
+    for entity in entity_list
+        .into_iter()
+        .filter(|e| deleted_entity_ids.contains(&e.id))
+    {
+        if let Err(error) = entity_history_service
+            .record_history(&user.id, HistoryAction::Delete, Some(entity), None)
+            .await
+        {
+            println!("error: {error:?}");
+        }
+    }
+
+    response.project_id = project_id;
+    response.success = true;
+
+    Json(response).into_response()
+}
@@ -0,0 +1,151 @@
+use std::{str::FromStr, sync::Arc};
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+use std::error::Error;
+
+use aws_sdk_s3::types::ByteStream;
+
+use crate::{
+    models::{Snapshot, SnapshotData},
+    utils::AllError,
+};
+
+pub struct SnapshotService {
+    client: Extension<Arc<Client>>,
+    s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+}
+
+impl SnapshotService {
+    pub fn new(
+        client: Extension<Arc<Client>>,
+        s3_client: Extension<Arc<aws_sdk_s3::Client>>,
+    ) -> Self {
+        Self { client, s3_client }
+    }
+
+    // 본문을 S3에 먼저 올린 뒤 테이블에 기록합니다.
+    pub async fn create_snapshot(
+        &self,
+        data: Snapshot,
+        payload: &SnapshotData,
+    ) -> Result<String, AllError> {
+        let body = serde_json::to_vec(payload)
+            .map_err(|error| AllError::InvalidInput(format!("{error:?}")))?;
+
+        if let Err(error) = self
+            .s3_client
+            .put_object()
+            .bucket(Snapshot::bucket())
+            .key(&data.data_key)
+            .content_type("application/json")
+            .body(ByteStream::from(body))
+            .send()
+            .await
+        {
+            return Err(AllError::AWSError(format!("{error:?}")));
+        }
+
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(Snapshot::NAME)
+            .set_item(input)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(data.id),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_snapshot_by_id(
+        &self,
+        snapshot_id: impl Into<String>,
+    ) -> Result<Snapshot, AllError> {
+        match self
+            .client
+            .scan()
+            .table_name(Snapshot::NAME)
+            .filter_expression("id = :snapshot_id")
+            .expression_attribute_values(":snapshot_id", AttributeValue::S(snapshot_id.into()))
+            .send()
+            .await
+        {
+            Ok(data) => data
+                .items()
+                .and_then(|items| {
+                    items
+                        .first()
+                        .and_then(|item| Snapshot::from_hashmap(item.to_owned()))
+                })
+                .ok_or(AllError::NotFound),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_snapshot_data(&self, snapshot: &Snapshot) -> Result<SnapshotData, AllError> {
+        let output = match self
+            .s3_client
+            .get_object()
+            .bucket(Snapshot::bucket())
+            .key(&snapshot.data_key)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+        };
+
+        let bytes = match output.body.collect().await {
+            Ok(bytes) => bytes.into_bytes(),
+            Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+        };
+
+        serde_json::from_slice(&bytes).map_err(|error| AllError::InvalidInput(format!("{error:?}")))
+    }
+
+    pub async fn get_snapshot_list_by_project_id(
+        &self,
+        project_id: impl Into<String>,
+    ) -> Result<Vec<Snapshot>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let project_id = project_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(Snapshot::NAME)
+                .filter_expression("project_id = :project_id")
+                .expression_attribute_values(":project_id", AttributeValue::S(project_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(snapshot) = Snapshot::from_hashmap(item.to_owned()) {
+                                list.push(snapshot);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+}
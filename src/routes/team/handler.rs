@@ -4,7 +4,7 @@ use std::sync::Arc;
 use aws_sdk_dynamodb::Client;
 use axum::{
     extract::Path,
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{Html, IntoResponse, Redirect},
     routing::{delete, get, post, put},
     Extension, Json, Router,
@@ -15,16 +15,33 @@ use uuid::Uuid;
 use crate::{
     extensions::CurrentUser,
     middlewares::auth,
-    models::{InsertUser, Team, TeamInvite, TeamUser, TeamUserAuthority, User},
+    models::{
+        InsertUser, NamingRule, StandardTerm, Team, TeamInvite, TeamUser, TeamUserAuthority, User,
+    },
     routes::{
         auth::AuthService,
+        domain::{dto::GetDomainListResponse, DomainService},
+        naming_rule::{
+            dto::{
+                GetNamingRuleItem, GetNamingRuleResponse, UpdateNamingRuleRequest,
+                UpdateNamingRuleResponse,
+            },
+            NamingRuleService,
+        },
         project::{
             dto::{GetProjectListItem, GetProjectListResponse},
             ProjectService,
         },
+        term::{
+            dto::{
+                GetTermListItem, GetTermListResponse, ImportTermConflict, ImportTermRequest,
+                ImportTermResponse,
+            },
+            TermService,
+        },
         user::UserService,
     },
-    utils::{generate_uuid, hash_password, send_email, AllError},
+    utils::{csv, generate_uuid, glossary, hash_password, send_email, AllError},
 };
 
 use super::{
@@ -50,6 +67,14 @@ pub async fn router() -> Router {
         .route("/:team_id/user/authority", put(change_authority))
         .route("/:team_id/user/:user_id", delete(delete_team_user))
         .route("/:team_id/project/list", get(get_team_project_list))
+        .route("/:team_id/template/list", get(get_team_template_list))
+        .route("/:team_id/term/list", get(get_team_term_list))
+        .route("/:team_id/term/export", get(export_team_term_list))
+        .route("/:team_id/term/import", post(import_team_term_list))
+        .route("/:team_id/naming-rule", get(get_team_naming_rule))
+        .route("/:team_id/naming-rule", put(update_team_naming_rule))
+        .route("/:team_id/naming-rule", delete(delete_team_naming_rule))
+        .route("/:team_id/domain/list", get(get_team_domain_list))
         .route("/my/list", get(get_my_team_list))
 }
 
@@ -253,12 +278,11 @@ async fn get_my_team_list(
         Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
     };
 
-    let team_list = join_all(team_user_list.into_iter().map(|team_user| async {
-        match team_service.get_team_by_id(team_user.team_id).await {
-            Ok(team) => Some(team),
-            Err(_) => None,
-        }
-    }))
+    let team_list = join_all(
+        team_user_list
+            .into_iter()
+            .map(|team_user| async { team_service.get_team_by_id(team_user.team_id).await.ok() }),
+    )
     .await;
 
     let team_list = team_list
@@ -355,6 +379,53 @@ async fn get_team_project_list(
             name: e.name,
             description: e.description,
             thumbnail_url: e.thumbnail_url,
+            is_template: e.is_template,
+        })
+        .collect::<Vec<_>>();
+
+    let response = GetProjectListResponse { list: project_list };
+
+    Json(response).into_response()
+}
+
+// 새 프로젝트를 만들 때 고를 수 있는 팀 템플릿 프로젝트
+async fn get_team_template_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let project_service = ProjectService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let project_list = match project_service.get_project_list_by_team_id(&team_id).await {
+        Ok(team_user_list) => team_user_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let project_list = project_list
+        .into_iter()
+        .filter(|e| e.is_template)
+        .map(|e| GetProjectListItem {
+            id: e.id,
+            name: e.name,
+            description: e.description,
+            thumbnail_url: e.thumbnail_url,
+            is_template: e.is_template,
         })
         .collect::<Vec<_>>();
 
@@ -755,3 +826,499 @@ async fn change_authority(
         }
     }
 }
+
+async fn get_team_term_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let term_list = match term_service.get_term_list_by_team_id(&team_id).await {
+        Ok(term_list) => term_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let term_list = term_list
+        .into_iter()
+        .map(|e| GetTermListItem {
+            id: e.id,
+            logical_name: e.logical_name,
+            physical_name: e.physical_name,
+            description: e.description,
+        })
+        .collect::<Vec<_>>();
+
+    let response = GetTermListResponse { list: term_list };
+
+    Json(response).into_response()
+}
+
+async fn export_team_term_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let mut term_list = match term_service.get_term_list_by_team_id(&team_id).await {
+        Ok(term_list) => term_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    term_list.sort_by(|a, b| a.logical_name.cmp(&b.logical_name));
+
+    let mut rows = vec![vec![
+        "logical_name".to_owned(),
+        "physical_name".to_owned(),
+        "description".to_owned(),
+    ]];
+
+    rows.extend(
+        term_list
+            .into_iter()
+            .map(|e| vec![e.logical_name, e.physical_name, e.description]),
+    );
+
+    let headers = [
+        (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"standard_terms.csv\"".to_owned(),
+        ),
+    ];
+
+    (headers, csv::write_csv(&rows)).into_response()
+}
+
+async fn import_team_term_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+    Json(body): Json<ImportTermRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let rows = match csv::parse_csv(&body.csv) {
+        Ok(rows) => rows,
+        Err(AllError::InvalidInput(message)) => {
+            return (StatusCode::BAD_REQUEST, message).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let mut term_list = match term_service.get_term_list_by_team_id(&team_id).await {
+        Ok(term_list) => term_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let mut response = ImportTermResponse {
+        success: false,
+        dry_run: body.dry_run,
+        created: 0,
+        updated: 0,
+        skipped: 0,
+        conflicts: vec![],
+    };
+
+    // 첫 줄이 헤더이면 건너뜁니다.
+    let skip_header = rows
+        .first()
+        .and_then(|row| row.first())
+        .map(|e| e.trim().eq_ignore_ascii_case("logical_name"))
+        .unwrap_or(false);
+
+    let mut changed_terms: Vec<StandardTerm> = vec![];
+    let mut seen_logical_names: Vec<String> = vec![];
+
+    for (index, row) in rows.into_iter().enumerate() {
+        if index == 0 && skip_header {
+            continue;
+        }
+
+        let line = index + 1;
+        let field = |i: usize| row.get(i).map(|e| e.trim().to_owned()).unwrap_or_default();
+        let (logical_name, physical_name, description) = (field(0), field(1), field(2));
+
+        let conflict = |message: &str| ImportTermConflict {
+            line,
+            logical_name: logical_name.clone(),
+            physical_name: physical_name.clone(),
+            message: message.to_owned(),
+        };
+
+        if logical_name.is_empty() || physical_name.is_empty() {
+            response
+                .conflicts
+                .push(conflict("논리명과 물리명은 필수입니다."));
+            continue;
+        }
+
+        if seen_logical_names
+            .iter()
+            .any(|e| glossary::is_same_name(e, &logical_name))
+        {
+            response
+                .conflicts
+                .push(conflict("CSV 안에서 논리명이 중복됩니다."));
+            continue;
+        }
+
+        seen_logical_names.push(logical_name.clone());
+
+        let candidate = match term_list
+            .iter()
+            .find(|e| glossary::is_same_name(&e.logical_name, &logical_name))
+        {
+            Some(existing) => {
+                if existing.logical_name == logical_name
+                    && existing.physical_name == physical_name
+                    && existing.description == description
+                {
+                    response.skipped += 1;
+                    continue;
+                }
+
+                if !glossary::is_same_name(&existing.physical_name, &physical_name)
+                    && !body.overwrite
+                {
+                    response.conflicts.push(conflict(
+                        "같은 논리명이 다른 물리명으로 이미 등록되어 있습니다.",
+                    ));
+                    continue;
+                }
+
+                StandardTerm {
+                    description,
+                    logical_name: logical_name.clone(),
+                    physical_name: physical_name.clone(),
+                    ..existing.clone()
+                }
+            }
+            None => StandardTerm {
+                id: generate_uuid(),
+                team_id: team_id.clone(),
+                logical_name: logical_name.clone(),
+                physical_name: physical_name.clone(),
+                description,
+            },
+        };
+
+        if !glossary::find_conflicts(&candidate, &term_list).is_empty() {
+            response
+                .conflicts
+                .push(conflict("물리명이 다른 논리명에서 이미 사용 중입니다."));
+            continue;
+        }
+
+        match term_list.iter_mut().find(|e| e.id == candidate.id) {
+            Some(existing) => {
+                *existing = candidate.clone();
+                response.updated += 1;
+            }
+            None => {
+                term_list.push(candidate.clone());
+                response.created += 1;
+            }
+        }
+
+        changed_terms.push(candidate);
+    }
+
+    if !body.dry_run {
+        let results = join_all(
+            changed_terms
+                .into_iter()
+                .map(|term| term_service.create_term(term)),
+        )
+        .await;
+
+        if let Some(Err(error)) = results.into_iter().find(|e| e.is_err()) {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+async fn get_team_naming_rule(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let naming_rule = match naming_rule_service.find_naming_rule(&team_id, None).await {
+        Ok(naming_rule) => naming_rule,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let response = GetNamingRuleResponse {
+        data: naming_rule.map(|e| GetNamingRuleItem {
+            id: e.id,
+            team_id: e.team_id,
+            project_id: e.project_id,
+            config: e.config,
+        }),
+        inherited: None,
+    };
+
+    Json(response).into_response()
+}
+
+async fn update_team_naming_rule(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+    Json(body): Json<UpdateNamingRuleRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let mut response = UpdateNamingRuleResponse { success: false };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let rule_id = match naming_rule_service.find_naming_rule(&team_id, None).await {
+        Ok(Some(naming_rule)) => naming_rule.id,
+        Ok(None) => generate_uuid(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let data = NamingRule {
+        id: rule_id,
+        team_id,
+        project_id: None,
+        config: body.config,
+    };
+
+    match naming_rule_service.save_naming_rule(data).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_team_naming_rule(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let naming_rule_service = NamingRuleService::new(database.clone());
+
+    let mut response = UpdateNamingRuleResponse { success: false };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin => {
+                println!("# 권한 허용: OWNER OR ADMIN");
+            }
+            _ => {
+                println!("# 권한 부족: NOT OWNER OR ADMIN");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match naming_rule_service.find_naming_rule(&team_id, None).await {
+        Ok(Some(naming_rule)) => {
+            if let Err(error) = naming_rule_service
+                .delete_naming_rule(&naming_rule.id)
+                .await
+            {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+        Ok(None) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    response.success = true;
+
+    Json(response).into_response()
+}
+
+async fn get_team_domain_list(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(team_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let domain_service = DomainService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let domain_list = match domain_service.get_domain_list_by_team_id(&team_id).await {
+        Ok(domain_list) => domain_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    // 팀 전체 도메인만 반환합니다. 프로젝트 전용 도메인은 프로젝트 목록에서 조회합니다.
+    let domain_list = domain_list
+        .into_iter()
+        .filter(|e| e.project_id.is_none())
+        .map(Into::into)
+        .collect::<Vec<_>>();
+
+    let response = GetDomainListResponse { list: domain_list };
+
+    Json(response).into_response()
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTermRequest {
+    pub team_id: String,
+    pub logical_name: String,
+    pub physical_name: String,
+    #[serde(default)]
+    pub description: String,
+}
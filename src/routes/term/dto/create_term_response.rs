@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use super::GetTermListItem;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTermResponse {
+    pub success: bool,
+    pub term_id: String,
+    pub conflicts: Vec<GetTermListItem>,
+}
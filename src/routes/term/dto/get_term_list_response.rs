@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTermListItem {
+    pub id: String,
+    pub logical_name: String,
+    pub physical_name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTermListResponse {
+    pub list: Vec<GetTermListItem>,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTermItem {
+    pub id: String,
+    pub team_id: String,
+    pub logical_name: String,
+    pub physical_name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTermResponse {
+    pub data: GetTermItem,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+// csv: logical_name,physical_name,description 헤더를 가진 CSV 본문
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportTermRequest {
+    pub csv: String,
+    #[serde(default)]
+    pub overwrite: bool,
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,21 @@
+pub mod create_term_request;
+pub mod create_term_response;
+pub mod get_term_list_response;
+pub mod get_term_response;
+pub mod import_term_request;
+pub mod import_term_response;
+pub mod suggest_physical_name_request;
+pub mod suggest_physical_name_response;
+pub mod update_term_request;
+pub mod update_term_response;
+
+pub use create_term_request::CreateTermRequest;
+pub use create_term_response::CreateTermResponse;
+pub use get_term_list_response::*;
+pub use get_term_response::*;
+pub use import_term_request::ImportTermRequest;
+pub use import_term_response::*;
+pub use suggest_physical_name_request::SuggestPhysicalNameRequest;
+pub use suggest_physical_name_response::*;
+pub use update_term_request::UpdateTermRequest;
+pub use update_term_response::UpdateTermResponse;
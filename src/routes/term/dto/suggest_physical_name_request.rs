@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestPhysicalNameRequest {
+    pub team_id: String,
+    pub logical_names: Vec<String>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestPhysicalNameItem {
+    pub logical_name: String,
+    pub physical_name: String,
+    pub matched_terms: Vec<String>,
+    pub unmatched_words: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestPhysicalNameResponse {
+    pub list: Vec<SuggestPhysicalNameItem>,
+}
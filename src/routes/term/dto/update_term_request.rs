@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTermRequest {
+    pub logical_name: String,
+    pub physical_name: String,
+    #[serde(default)]
+    pub description: String,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::GetTermListItem;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTermResponse {
+    pub success: bool,
+    pub conflicts: Vec<GetTermListItem>,
+}
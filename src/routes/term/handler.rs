@@ -0,0 +1,392 @@
+use std::sync::Arc;
+
+use aws_sdk_dynamodb::Client;
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Extension, Json, Router,
+};
+
+use crate::{
+    extensions::CurrentUser,
+    models::{StandardTerm, TeamUserAuthority},
+    routes::team::TeamService,
+    utils::{generate_uuid, glossary, AllError},
+};
+
+use super::{
+    dto::{
+        CreateTermRequest, CreateTermResponse, GetTermItem, GetTermListItem, GetTermResponse,
+        SuggestPhysicalNameItem, SuggestPhysicalNameRequest, SuggestPhysicalNameResponse,
+        UpdateTermRequest, UpdateTermResponse,
+    },
+    TermService,
+};
+
+pub async fn router() -> Router {
+    Router::new()
+        .route("/", post(create_term))
+        .route("/suggest", post(suggest_physical_name))
+        .route("/:term_id", get(get_term))
+        .route("/:term_id", put(update_term))
+        .route("/:term_id", delete(delete_term))
+}
+
+async fn create_term(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Json(body): Json<CreateTermRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    let mut response = CreateTermResponse {
+        success: false,
+        term_id: "".into(),
+        conflicts: vec![],
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&body.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if body.logical_name.trim().is_empty() || body.physical_name.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let term_list = match term_service.get_term_list_by_team_id(&body.team_id).await {
+        Ok(term_list) => term_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let data = StandardTerm {
+        id: generate_uuid(),
+        team_id: body.team_id,
+        logical_name: body.logical_name.trim().to_owned(),
+        physical_name: body.physical_name.trim().to_owned(),
+        description: body.description,
+    };
+
+    let conflicts = glossary::find_conflicts(&data, &term_list);
+
+    if !conflicts.is_empty() {
+        println!("# 표준 단어 충돌");
+        response.conflicts = conflicts
+            .into_iter()
+            .map(|e| GetTermListItem {
+                id: e.id.clone(),
+                logical_name: e.logical_name.clone(),
+                physical_name: e.physical_name.clone(),
+                description: e.description.clone(),
+            })
+            .collect();
+
+        return (StatusCode::CONFLICT, Json(response)).into_response();
+    }
+
+    match term_service.create_term(data).await {
+        Ok(term_id) => {
+            response.term_id = term_id;
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn update_term(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(term_id): Path<String>,
+    Json(body): Json<UpdateTermRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    let mut response = UpdateTermResponse {
+        success: false,
+        conflicts: vec![],
+    };
+
+    let term = match term_service.get_term_by_id(&term_id).await {
+        Ok(term) => term,
+        Err(error) => {
+            if let AllError::NotFound = error {
+                println!("# 표준 단어 없음");
+                return (StatusCode::NOT_FOUND).into_response();
+            } else {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&term.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    if body.logical_name.trim().is_empty() || body.physical_name.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let term_list = match term_service.get_term_list_by_team_id(&term.team_id).await {
+        Ok(term_list) => term_list,
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let data = StandardTerm {
+        id: term_id,
+        team_id: term.team_id,
+        logical_name: body.logical_name.trim().to_owned(),
+        physical_name: body.physical_name.trim().to_owned(),
+        description: body.description,
+    };
+
+    let conflicts = glossary::find_conflicts(&data, &term_list);
+
+    if !conflicts.is_empty() {
+        println!("# 표준 단어 충돌");
+        response.conflicts = conflicts
+            .into_iter()
+            .map(|e| GetTermListItem {
+                id: e.id.clone(),
+                logical_name: e.logical_name.clone(),
+                physical_name: e.physical_name.clone(),
+                description: e.description.clone(),
+            })
+            .collect();
+
+        return (StatusCode::CONFLICT, Json(response)).into_response();
+    }
+
+    match term_service.create_term(data).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn delete_term(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(term_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    let mut response = UpdateTermResponse {
+        success: false,
+        conflicts: vec![],
+    };
+
+    let term = match term_service.get_term_by_id(&term_id).await {
+        Ok(term) => term,
+        Err(_) => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&term.team_id, &user.id)
+        .await
+    {
+        Ok(Some(team_user)) => match team_user.authority {
+            TeamUserAuthority::Owner | TeamUserAuthority::Admin | TeamUserAuthority::Write => {
+                println!("# 권한 허용: OWNER OR ADMIN OR WRITE");
+            }
+            _ => {
+                println!("# 권한 부족: NEED WRITE");
+                return (StatusCode::FORBIDDEN).into_response();
+            }
+        },
+        Ok(None) => {
+            println!("# 권한 부족: NOT TEAM MEMBER");
+            return (StatusCode::FORBIDDEN).into_response();
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    match term_service.delete_term(&term_id).await {
+        Ok(_) => {
+            response.success = true;
+        }
+        Err(error) => {
+            println!("error: {error:?}");
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    Json(response).into_response()
+}
+
+async fn get_term(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Path(term_id): Path<String>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    let term = match term_service.get_term_by_id(&term_id).await {
+        Ok(term) => term,
+        Err(error) => {
+            if let AllError::NotFound = error {
+                println!("# 표준 단어 없음");
+                return (StatusCode::NOT_FOUND).into_response();
+            } else {
+                println!("error: {error:?}");
+                return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            }
+        }
+    };
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&term.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let response = GetTermResponse {
+        data: GetTermItem {
+            id: term.id,
+            team_id: term.team_id,
+            logical_name: term.logical_name,
+            physical_name: term.physical_name,
+            description: term.description,
+        },
+    };
+
+    Json(response).into_response()
+}
+
+async fn suggest_physical_name(
+    current_user: Extension<CurrentUser>,
+    database: Extension<Arc<Client>>,
+    Json(body): Json<SuggestPhysicalNameRequest>,
+) -> impl IntoResponse {
+    let user = if let Some(user) = current_user.user.clone() {
+        user
+    } else {
+        return (StatusCode::UNAUTHORIZED).into_response();
+    };
+
+    let team_service = TeamService::new(database.clone());
+    let term_service = TermService::new(database.clone());
+
+    match team_service
+        .find_team_user_by_team_and_user_id(&body.team_id, &user.id)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::FORBIDDEN).into_response(),
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+
+    let term_list = match term_service.get_term_list_by_team_id(&body.team_id).await {
+        Ok(term_list) => term_list,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    };
+
+    let list = body
+        .logical_names
+        .into_iter()
+        .map(|logical_name| {
+            let suggestion = glossary::suggest_physical_name(&logical_name, &term_list);
+
+            SuggestPhysicalNameItem {
+                logical_name,
+                physical_name: suggestion.physical_name,
+                matched_terms: suggestion.matched_terms,
+                unmatched_words: suggestion.unmatched_words,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let response = SuggestPhysicalNameResponse { list };
+
+    Json(response).into_response()
+}
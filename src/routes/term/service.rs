@@ -0,0 +1,114 @@
+use std::{str::FromStr, sync::Arc};
+
+use aws_sdk_dynamodb::{model::AttributeValue, Client};
+use axum::Extension;
+use std::error::Error;
+
+use crate::{models::StandardTerm, utils::AllError};
+
+pub struct TermService {
+    client: Extension<Arc<Client>>,
+}
+
+impl TermService {
+    pub fn new(client: Extension<Arc<Client>>) -> Self {
+        Self { client }
+    }
+
+    pub async fn create_term(&self, data: StandardTerm) -> Result<String, AllError> {
+        let input = data.to_hashmap();
+
+        match self
+            .client
+            .put_item()
+            .table_name(StandardTerm::NAME)
+            .set_item(input)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(data.id),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_term_by_id(
+        &self,
+        term_id: impl Into<String>,
+    ) -> Result<StandardTerm, AllError> {
+        match self
+            .client
+            .scan()
+            .table_name(StandardTerm::NAME)
+            .filter_expression("id = :term_id")
+            .expression_attribute_values(":term_id", AttributeValue::S(term_id.into()))
+            .send()
+            .await
+        {
+            Ok(data) => data
+                .items()
+                .and_then(|items| {
+                    items
+                        .first()
+                        .and_then(|item| StandardTerm::from_hashmap(item.to_owned()))
+                })
+                .ok_or(AllError::NotFound),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn delete_term(&self, term_id: impl Into<String>) -> Result<(), AllError> {
+        match self
+            .client
+            .delete_item()
+            .table_name(StandardTerm::NAME)
+            .key("id", AttributeValue::S(term_id.into()))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+        }
+    }
+
+    pub async fn get_term_list_by_team_id(
+        &self,
+        team_id: impl Into<String>,
+    ) -> Result<Vec<StandardTerm>, AllError> {
+        let mut list = vec![];
+        let mut last_evaluated_key = None;
+
+        let team_id = team_id.into();
+
+        loop {
+            match self
+                .client
+                .scan()
+                .table_name(StandardTerm::NAME)
+                .filter_expression("team_id = :team_id")
+                .expression_attribute_values(":team_id", AttributeValue::S(team_id.clone()))
+                .set_exclusive_start_key(last_evaluated_key)
+                .send()
+                .await
+            {
+                Ok(data) => {
+                    if let Some(items) = data.items() {
+                        for item in items {
+                            if let Some(term) = StandardTerm::from_hashmap(item.to_owned()) {
+                                list.push(term);
+                            }
+                        }
+                    }
+
+                    match data.last_evaluated_key() {
+                        None => return Ok(list),
+                        Some(key) => {
+                            last_evaluated_key = Some(key.to_owned());
+                            continue;
+                        }
+                    }
+                }
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            }
+        }
+    }
+}
@@ -185,16 +185,16 @@ async fn get_email_duplicate(
             if exists {
                 let response = GetEmailDuplicateResponse { duplicate: true };
 
-                return (Json(response)).into_response();
+                (Json(response)).into_response()
             } else {
                 let response = GetEmailDuplicateResponse { duplicate: false };
 
-                return (Json(response)).into_response();
+                (Json(response)).into_response()
             }
         }
         Err(error) => {
             println!("error: {error:?}");
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
         }
     }
 }
@@ -44,7 +44,7 @@ impl UserService {
         let user_list = scan_result.items();
 
         match user_list {
-            Some(user_list) => Ok(User::from_hashmap(user_list.get(0))),
+            Some(user_list) => Ok(User::from_hashmap(user_list.first())),
             None => Ok(None),
         }
     }
@@ -65,7 +65,7 @@ impl UserService {
         let user_list = scan_result.items();
 
         match user_list {
-            Some(user_list) => Ok(User::from_hashmap(user_list.get(0))),
+            Some(user_list) => Ok(User::from_hashmap(user_list.first())),
             None => Ok(None),
         }
     }
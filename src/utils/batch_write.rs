@@ -0,0 +1,206 @@
+use std::{collections::HashMap, time::Duration};
+
+use aws_sdk_dynamodb::{
+    model::{
+        AttributeValue, Delete, DeleteRequest, Put, PutRequest, TransactWriteItem, WriteRequest,
+    },
+    Client,
+};
+use uuid::Uuid;
+
+use super::AllError;
+
+// BatchWriteItem 한 번에 보낼 수 있는 최대 항목 수
+const BATCH_WRITE_LIMIT: usize = 25;
+// 처리되지 않고 돌아온 항목을 다시 보내는 횟수
+const BATCH_WRITE_RETRIES: usize = 3;
+// 재시도 대기 시간 (시도할 때마다 두 배, 최대 BATCH_WRITE_MAX_DELAY_MS)
+const BATCH_WRITE_BASE_DELAY_MS: u64 = 50;
+const BATCH_WRITE_MAX_DELAY_MS: u64 = 2000;
+// TransactWriteItems 한 번에 묶을 수 있는 최대 항목 수
+const TRANSACT_WRITE_LIMIT: usize = 100;
+
+// 항목 저장 요청
+pub fn put_request(item: Option<HashMap<String, AttributeValue>>) -> WriteRequest {
+    WriteRequest::builder()
+        .put_request(PutRequest::builder().set_item(item).build())
+        .build()
+}
+
+// id로 항목 삭제 요청
+pub fn delete_request(id: impl Into<String>) -> WriteRequest {
+    WriteRequest::builder()
+        .delete_request(
+            DeleteRequest::builder()
+                .key("id", AttributeValue::S(id.into()))
+                .build(),
+        )
+        .build()
+}
+
+// 여러 번에 나눠 쓰다가 실패하면, 실패 전에 이미 반영된 요청을 함께 돌려줍니다.
+#[derive(Debug)]
+pub struct PartialWriteError {
+    pub error: AllError,
+    pub written: Vec<WriteRequest>,
+}
+
+// 요청 대상 항목의 id
+pub fn request_id(request: &WriteRequest) -> Option<String> {
+    let key = match (request.put_request(), request.delete_request()) {
+        (Some(put), _) => put.item(),
+        (None, Some(delete)) => delete.key(),
+        (None, None) => None,
+    };
+
+    key.and_then(|e| e.get("id"))
+        .and_then(|e| e.as_s().ok())
+        .map(|e| e.to_owned())
+}
+
+// 한 테이블에 여러 항목을 묶어서 쓰고 지웁니다. (25건씩 나눠 보냅니다)
+pub async fn batch_write(
+    client: &Client,
+    table: &str,
+    requests: Vec<WriteRequest>,
+) -> Result<(), AllError> {
+    batch_write_tracked(client, table, requests, &mut vec![]).await
+}
+
+// 반영된 요청을 written에 모으면서 씁니다.
+async fn batch_write_tracked(
+    client: &Client,
+    table: &str,
+    requests: Vec<WriteRequest>,
+    written: &mut Vec<WriteRequest>,
+) -> Result<(), AllError> {
+    for chunk in requests.chunks(BATCH_WRITE_LIMIT) {
+        let mut pending = chunk.to_vec();
+
+        for attempt in 0..=BATCH_WRITE_RETRIES {
+            if attempt > 0 {
+                tokio::time::sleep(backoff(attempt)).await;
+            }
+
+            let output = match client
+                .batch_write_item()
+                .request_items(table, pending.clone())
+                .send()
+                .await
+            {
+                Ok(output) => output,
+                Err(error) => return Err(AllError::AWSError(format!("{error:?}"))),
+            };
+
+            let unprocessed = output
+                .unprocessed_items()
+                .and_then(|e| e.get(table))
+                .cloned()
+                .unwrap_or_default();
+
+            written.extend(pending.into_iter().filter(|e| !unprocessed.contains(e)));
+            pending = unprocessed;
+
+            if pending.is_empty() {
+                break;
+            }
+        }
+
+        if !pending.is_empty() {
+            return Err(AllError::AWSError(format!(
+                "{table}: 처리되지 않은 항목 {}건",
+                pending.len()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// 지수 백오프에 지터를 더한 대기 시간 (0 ~ 상한 사이에서 무작위)
+fn backoff(attempt: usize) -> Duration {
+    let ceiling = BATCH_WRITE_BASE_DELAY_MS
+        .saturating_mul(1 << attempt.min(16))
+        .min(BATCH_WRITE_MAX_DELAY_MS);
+    let jitter = (Uuid::new_v4().as_u128() % (ceiling as u128 + 1)) as u64;
+
+    Duration::from_millis(jitter)
+}
+
+// 여러 테이블에 걸친 쓰기/삭제를 저장합니다. (테이블 이름, 요청)
+// 100건까지는 한 트랜잭션으로 보내 모두 반영되거나 모두 실패하고, 그보다 많으면 테이블별로 나눠 씁니다.
+// 나눠 쓰다 실패하면 일부만 반영될 수 있으므로, 반영된 요청을 에러에 담아 돌려줍니다.
+pub async fn write_all(
+    client: &Client,
+    requests: Vec<(&str, WriteRequest)>,
+) -> Result<(), PartialWriteError> {
+    if requests.is_empty() {
+        return Ok(());
+    }
+
+    if requests.len() <= TRANSACT_WRITE_LIMIT {
+        return transact_write(client, requests)
+            .await
+            .map_err(|error| PartialWriteError {
+                error,
+                written: vec![],
+            });
+    }
+
+    let mut tables: Vec<(&str, Vec<WriteRequest>)> = vec![];
+
+    for (table, request) in requests {
+        match tables.iter_mut().find(|e| e.0 == table) {
+            Some((_, list)) => list.push(request),
+            None => tables.push((table, vec![request])),
+        }
+    }
+
+    let mut written = vec![];
+
+    for (table, list) in tables {
+        if let Err(error) = batch_write_tracked(client, table, list, &mut written).await {
+            return Err(PartialWriteError { error, written });
+        }
+    }
+
+    Ok(())
+}
+
+async fn transact_write(
+    client: &Client,
+    requests: Vec<(&str, WriteRequest)>,
+) -> Result<(), AllError> {
+    let items = requests
+        .into_iter()
+        .filter_map(|(table, request)| {
+            if let Some(put) = request.put_request() {
+                let put = Put::builder()
+                    .table_name(table)
+                    .set_item(put.item().cloned())
+                    .build();
+
+                return Some(TransactWriteItem::builder().put(put).build());
+            }
+
+            request.delete_request().map(|delete| {
+                let delete = Delete::builder()
+                    .table_name(table)
+                    .set_key(delete.key().cloned())
+                    .build();
+
+                TransactWriteItem::builder().delete(delete).build()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    match client
+        .transact_write_items()
+        .set_transact_items(Some(items))
+        .send()
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AllError::AWSError(format!("{error:?}"))),
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Diagram, Entity, Note, Project};
+
+// 팀/인스턴스 간 이동과 백업에 쓰는 프로젝트 번들 형식
+// id는 번들 안의 참조(FK)용으로만 쓰이고, 가져올 때 모두 새로 발급합니다.
+// 형식이 바뀌면 BUNDLE_VERSION을 올리고 이전 버전을 읽을 수 있게 유지합니다.
+
+pub const BUNDLE_FORMAT: &str = "modeler-project-bundle";
+// 2: 다이어그램(주제 영역) 추가
+pub const BUNDLE_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleProject {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBundle {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: u64,
+    pub project: BundleProject,
+    pub entities: Vec<Entity>,
+    #[serde(default)]
+    pub notes: Vec<Note>,
+    // 버전 1 번들에는 없으므로 기본값으로 읽습니다.
+    #[serde(default)]
+    pub diagrams: Vec<Diagram>,
+}
+
+impl ProjectBundle {
+    pub fn new(
+        project: Project,
+        entities: Vec<Entity>,
+        notes: Vec<Note>,
+        diagrams: Vec<Diagram>,
+        exported_at: u64,
+    ) -> Self {
+        Self {
+            format: BUNDLE_FORMAT.to_owned(),
+            version: BUNDLE_VERSION,
+            exported_at,
+            project: BundleProject {
+                name: project.name,
+                description: project.description,
+                thumbnail_url: project.thumbnail_url,
+            },
+            entities,
+            notes,
+            diagrams,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.format != BUNDLE_FORMAT {
+            return Err(format!("지원하지 않는 번들 형식입니다: {}", self.format));
+        }
+
+        if self.version == 0 || self.version > BUNDLE_VERSION {
+            return Err(format!("지원하지 않는 번들 버전입니다: {}", self.version));
+        }
+
+        let mut entity_ids = std::collections::HashSet::new();
+
+        for entity in self.entities.iter() {
+            if !entity_ids.insert(entity.id.as_str()) {
+                return Err(format!("엔티티 id가 중복되었습니다: {}", entity.id));
+            }
+
+            entity.validate_indexes()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+
+use crate::{
+    models::{Column, Entity},
+    utils::{sql, Dialect},
+};
+
+use super::{
+    base_type, column_description, entity_description, escape_keyword, snake_case, type_kind,
+    Context, GeneratedFile, TypeKind, RUST_KEYWORDS,
+};
+
+// Diesel schema.rs (diesel print-schema와 같은 형식)
+// Rust 식별자로 쓸 수 없는 이름은 바꾸고 #[sql_name]으로 원래 이름을 남깁니다.
+
+fn sql_type(column: &Column, dialect: Dialect) -> String {
+    let sql_type = match type_kind(column) {
+        TypeKind::SmallInt => "SmallInt",
+        TypeKind::Int => "Integer",
+        TypeKind::BigInt => "BigInt",
+        TypeKind::Boolean => "Bool",
+        TypeKind::Decimal => "Numeric",
+        TypeKind::Float => "Float",
+        TypeKind::Double => "Double",
+        TypeKind::String => "Varchar",
+        TypeKind::Text => "Text",
+        TypeKind::Date => "Date",
+        TypeKind::Time => "Time",
+        TypeKind::DateTime if dialect == Dialect::MySql && base_type(column) == "DATETIME" => {
+            "Datetime"
+        }
+        TypeKind::DateTime => "Timestamp",
+        TypeKind::DateTimeTz if dialect == Dialect::PostgreSql => "Timestamptz",
+        TypeKind::DateTimeTz => "Timestamp",
+        TypeKind::Json if dialect == Dialect::PostgreSql && base_type(column) == "JSONB" => "Jsonb",
+        TypeKind::Json if dialect == Dialect::Sqlite => "Text",
+        TypeKind::Json => "Json",
+        TypeKind::Uuid if dialect == Dialect::PostgreSql => "Uuid",
+        TypeKind::Uuid => "Text",
+        TypeKind::Binary => "Binary",
+    };
+
+    // SQLite의 정수 PK는 Integer로 읽습니다.
+    let sql_type = match (dialect, sql_type) {
+        (Dialect::Sqlite, "SmallInt" | "BigInt") if column.is_primary_key => "Integer",
+        _ => sql_type,
+    };
+
+    let is_integer = matches!(sql_type, "SmallInt" | "Integer" | "BigInt");
+
+    let sql_type = if dialect == Dialect::MySql && column.is_unsigned && is_integer {
+        format!("Unsigned<{sql_type}>")
+    } else {
+        sql_type.to_owned()
+    };
+
+    if column.nullable && !column.is_primary_key {
+        format!("Nullable<{sql_type}>")
+    } else {
+        sql_type
+    }
+}
+
+fn rust_name(name: &str) -> String {
+    escape_keyword(snake_case(name), RUST_KEYWORDS)
+}
+
+fn render_table(entity: &Entity, dialect: Dialect) -> Vec<String> {
+    let table_name = rust_name(&entity.physical_name);
+    let primary_keys = sql::primary_key_columns(entity);
+
+    let mut lines = vec!["diesel::table! {".to_owned()];
+
+    let comment = entity_description(entity);
+    if !comment.is_empty() {
+        lines.push(format!("    /// {comment}"));
+    }
+
+    if table_name != entity.physical_name {
+        lines.push(format!("    #[sql_name = \"{}\"]", entity.physical_name));
+    }
+
+    lines.push(format!(
+        "    {table_name} ({}) {{",
+        primary_keys
+            .iter()
+            .map(|e| rust_name(&e.physical_name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+
+    for column in entity.columns.iter() {
+        let column_name = rust_name(&column.physical_name);
+
+        let comment = column_description(column);
+        if !comment.is_empty() {
+            lines.push(format!("        /// {comment}"));
+        }
+
+        if column_name != column.physical_name {
+            lines.push(format!(
+                "        #[sql_name = \"{}\"]",
+                column.physical_name
+            ));
+        }
+
+        if let (TypeKind::String, Some(length)) = (type_kind(column), column.length) {
+            lines.push(format!("        #[max_length = {length}]"));
+        }
+
+        lines.push(format!(
+            "        {column_name} -> {},",
+            sql_type(column, dialect)
+        ));
+    }
+
+    lines.push("    }".to_owned());
+    lines.push("}".to_owned());
+
+    lines
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    let dialect = context.options.dialect;
+
+    // Diesel은 기본 키가 없는 테이블을 선언할 수 없습니다.
+    let (tables, skipped): (Vec<&Entity>, Vec<&Entity>) = context
+        .entities
+        .iter()
+        .copied()
+        .partition(|e| !sql::primary_key_columns(e).is_empty());
+
+    let mut lines = vec![
+        "// @generated 모델러에서 생성한 코드입니다.".to_owned(),
+        String::new(),
+    ];
+
+    for entity in skipped.iter() {
+        lines.push(format!(
+            "// {}: 기본 키가 없어 생략했습니다.",
+            entity.physical_name
+        ));
+    }
+
+    if !skipped.is_empty() {
+        lines.push(String::new());
+    }
+
+    for entity in tables.iter() {
+        lines.extend(render_table(entity, dialect));
+        lines.push(String::new());
+    }
+
+    // joinable!은 단일 PK를 참조하는 관계만, 같은 테이블 쌍에는 하나만 선언할 수 있습니다.
+    let table_ids = tables.iter().map(|e| e.id.as_str()).collect::<HashSet<_>>();
+    let mut joined = HashSet::new();
+
+    for field in context.relations.iter() {
+        let relation = &field.relation;
+
+        let is_joinable = relation.parent.id != relation.child.id
+            && table_ids.contains(relation.parent.id.as_str())
+            && table_ids.contains(relation.child.id.as_str())
+            && relation.parent_column.is_primary_key
+            && sql::primary_key_columns(relation.parent).len() == 1;
+
+        if is_joinable && joined.insert((&relation.child.id, &relation.parent.id)) {
+            lines.push(format!(
+                "diesel::joinable!({} -> {} ({}));",
+                rust_name(&relation.child.physical_name),
+                rust_name(&relation.parent.physical_name),
+                rust_name(&relation.child_column.physical_name)
+            ));
+        }
+    }
+
+    if !joined.is_empty() {
+        lines.push(String::new());
+    }
+
+    if tables.len() > 1 {
+        lines.push("diesel::allow_tables_to_appear_in_same_query!(".to_owned());
+        lines.extend(
+            tables
+                .iter()
+                .map(|e| format!("    {},", rust_name(&e.physical_name))),
+        );
+        lines.push(");".to_owned());
+    }
+
+    vec![GeneratedFile {
+        path: "src/schema.rs".to_owned(),
+        content: lines.join("\n").trim_end().to_owned() + "\n",
+    }]
+}
@@ -0,0 +1,331 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    models::{Column, Entity},
+    utils::sql,
+};
+
+use super::{
+    camel_case, column_description, entity_description, escape_keyword, pascal_case, quoted,
+    type_kind, Context, GeneratedFile, TypeKind,
+};
+
+// JPA 엔티티 클래스 (jakarta.persistence, 엔티티별 .java 파일)
+// FK 컬럼은 값 필드로 두고, 연관 필드는 읽기 전용(insertable/updatable = false)으로 같은 컬럼에 매핑합니다.
+// 복합 키는 클래스 안의 static PrimaryKey 클래스를 @IdClass로 씁니다.
+
+pub const DEFAULT_PACKAGE: &str = "com.example.entity";
+
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "try",
+    "void",
+    "volatile",
+    "while",
+    "true",
+    "false",
+    "null",
+    "record",
+    "var",
+    "yield",
+];
+
+// 패키지 이름 형식 (com.example.entity)
+pub fn is_valid_package(package: &str) -> bool {
+    package.split('.').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !JAVA_KEYWORDS.contains(&part)
+    })
+}
+
+// (타입 이름, 필요한 import)
+fn java_type(column: &Column) -> (&'static str, Option<&'static str>) {
+    match type_kind(column) {
+        TypeKind::SmallInt => ("Short", None),
+        TypeKind::Int => ("Integer", None),
+        TypeKind::BigInt => ("Long", None),
+        TypeKind::Boolean => ("Boolean", None),
+        TypeKind::Decimal => ("BigDecimal", Some("java.math.BigDecimal")),
+        TypeKind::Float => ("Float", None),
+        TypeKind::Double => ("Double", None),
+        TypeKind::String | TypeKind::Text | TypeKind::Json => ("String", None),
+        TypeKind::Date => ("LocalDate", Some("java.time.LocalDate")),
+        TypeKind::Time => ("LocalTime", Some("java.time.LocalTime")),
+        TypeKind::DateTime => ("LocalDateTime", Some("java.time.LocalDateTime")),
+        TypeKind::DateTimeTz => ("OffsetDateTime", Some("java.time.OffsetDateTime")),
+        TypeKind::Uuid => ("UUID", Some("java.util.UUID")),
+        TypeKind::Binary => ("byte[]", None),
+    }
+}
+
+fn field_name(name: &str) -> String {
+    escape_keyword(camel_case(name), JAVA_KEYWORDS)
+}
+
+fn column_annotation(column: &Column) -> String {
+    let mut options = vec![format!("name = {}", quoted(&column.physical_name))];
+
+    if !column.nullable || column.is_primary_key {
+        options.push("nullable = false".to_owned());
+    }
+
+    if column.is_unique && !column.is_primary_key {
+        options.push("unique = true".to_owned());
+    }
+
+    match type_kind(column) {
+        TypeKind::String => options.extend(column.length.map(|e| format!("length = {e}"))),
+        TypeKind::Text => options.push("columnDefinition = \"TEXT\"".to_owned()),
+        TypeKind::Decimal => {
+            options.extend(column.precision.map(|e| format!("precision = {e}")));
+            options.extend(column.scale.map(|e| format!("scale = {e}")));
+        }
+        _ => {}
+    }
+
+    format!("@Column({})", options.join(", "))
+}
+
+fn javadoc(lines: &mut Vec<String>, indent: &str, comment: &str) {
+    if !comment.is_empty() {
+        lines.push(format!("{indent}/** {} */", comment.replace("*/", "* /")));
+    }
+}
+
+// getter/setter 한 쌍
+fn accessors(lines: &mut Vec<String>, field_type: &str, name: &str) {
+    let property = pascal_case(name.trim_end_matches('_'));
+
+    lines.push(String::new());
+    lines.push(format!("    public {field_type} get{property}() {{"));
+    lines.push(format!("        return {name};"));
+    lines.push("    }".to_owned());
+    lines.push(String::new());
+    lines.push(format!(
+        "    public void set{property}({field_type} {name}) {{"
+    ));
+    lines.push(format!("        this.{name} = {name};"));
+    lines.push("    }".to_owned());
+}
+
+fn render_primary_key_class(
+    primary_keys: &[&Column],
+    imports: &mut BTreeSet<String>,
+) -> Vec<String> {
+    imports.insert("java.io.Serializable".to_owned());
+    imports.insert("java.util.Objects".to_owned());
+
+    let names = primary_keys
+        .iter()
+        .map(|e| field_name(&e.physical_name))
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![
+        String::new(),
+        "    public static class PrimaryKey implements Serializable {".to_owned(),
+    ];
+
+    for (column, name) in primary_keys.iter().zip(names.iter()) {
+        lines.push(format!("        private {} {name};", java_type(column).0));
+    }
+
+    lines.push(String::new());
+    lines.push("        @Override".to_owned());
+    lines.push("        public boolean equals(Object o) {".to_owned());
+    lines.push("            if (this == o) return true;".to_owned());
+    lines.push("            if (!(o instanceof PrimaryKey that)) return false;".to_owned());
+    lines.push(format!(
+        "            return {};",
+        names
+            .iter()
+            .map(|e| format!("Objects.equals({e}, that.{e})"))
+            .collect::<Vec<_>>()
+            .join(" && ")
+    ));
+    lines.push("        }".to_owned());
+    lines.push(String::new());
+    lines.push("        @Override".to_owned());
+    lines.push("        public int hashCode() {".to_owned());
+    lines.push(format!(
+        "            return Objects.hash({});",
+        names.join(", ")
+    ));
+    lines.push("        }".to_owned());
+    lines.push("    }".to_owned());
+
+    lines
+}
+
+fn render_class(context: &Context, entity: &Entity) -> String {
+    let class_name = context.model_name(entity);
+    let primary_keys = sql::primary_key_columns(entity);
+
+    let mut imports = BTreeSet::from(["jakarta.persistence.*".to_owned()]);
+    let mut body = vec![];
+    // (타입, 필드 이름)
+    let mut properties = vec![];
+
+    for column in entity.columns.iter() {
+        let name = field_name(&column.physical_name);
+        let (field_type, import) = java_type(column);
+        imports.extend(import.map(|e| e.to_owned()));
+
+        body.push(String::new());
+        javadoc(&mut body, "    ", &column_description(column));
+
+        if column.is_primary_key {
+            body.push("    @Id".to_owned());
+        }
+
+        if column.is_auto_increment {
+            body.push("    @GeneratedValue(strategy = GenerationType.IDENTITY)".to_owned());
+        }
+
+        body.push(format!("    {}", column_annotation(column)));
+        body.push(format!("    private {field_type} {name};"));
+
+        properties.push((field_type.to_owned(), name));
+    }
+
+    for field in context.child_relations(entity) {
+        let relation = &field.relation;
+        let parent_name = context.model_name(relation.parent);
+        let name = field_name(&field.child_field);
+
+        body.push(String::new());
+        body.push(format!(
+            "    @{}(fetch = FetchType.LAZY)",
+            if relation.is_one_to_one() {
+                "OneToOne"
+            } else {
+                "ManyToOne"
+            }
+        ));
+        body.push(format!(
+            "    @JoinColumn(name = {}, referencedColumnName = {}, insertable = false, updatable = false)",
+            quoted(&relation.child_column.physical_name),
+            quoted(&relation.parent_column.physical_name)
+        ));
+        body.push(format!("    private {parent_name} {name};"));
+
+        properties.push((parent_name, name));
+    }
+
+    for field in context.parent_relations(entity) {
+        let relation = &field.relation;
+        let child_name = context.model_name(relation.child);
+        let name = field_name(&field.parent_field);
+        let mapped_by = quoted(&field_name(&field.child_field));
+
+        body.push(String::new());
+
+        if relation.is_one_to_one() {
+            body.push(format!("    @OneToOne(mappedBy = {mapped_by})"));
+            body.push(format!("    private {child_name} {name};"));
+
+            properties.push((child_name, name));
+        } else {
+            imports.insert("java.util.ArrayList".to_owned());
+            imports.insert("java.util.List".to_owned());
+
+            body.push(format!("    @OneToMany(mappedBy = {mapped_by})"));
+            body.push(format!(
+                "    private List<{child_name}> {name} = new ArrayList<>();"
+            ));
+
+            properties.push((format!("List<{child_name}>"), name));
+        }
+    }
+
+    for (field_type, name) in properties.iter() {
+        accessors(&mut body, field_type, name);
+    }
+
+    if primary_keys.len() > 1 {
+        body.extend(render_primary_key_class(&primary_keys, &mut imports));
+    }
+
+    let mut lines = vec![
+        format!("package {};", context.options.package),
+        String::new(),
+    ];
+
+    lines.extend(imports.iter().map(|e| format!("import {e};")));
+    lines.push(String::new());
+
+    javadoc(&mut lines, "", &entity_description(entity));
+    lines.push("@Entity".to_owned());
+    lines.push(format!("@Table(name = {})", quoted(&entity.physical_name)));
+
+    if primary_keys.len() > 1 {
+        lines.push(format!("@IdClass({class_name}.PrimaryKey.class)"));
+    }
+
+    lines.push(format!("public class {class_name} {{"));
+    lines.extend(body);
+    lines.push("}".to_owned());
+
+    lines.join("\n") + "\n"
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    let directory = context.options.package.replace('.', "/");
+
+    context
+        .entities
+        .iter()
+        .map(|entity| GeneratedFile {
+            path: format!(
+                "src/main/java/{directory}/{}.java",
+                context.model_name(entity)
+            ),
+            content: render_class(context, entity),
+        })
+        .collect()
+}
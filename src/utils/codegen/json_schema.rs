@@ -0,0 +1,151 @@
+use serde_json::{json, Map, Value};
+
+use crate::models::{Column, Entity};
+
+use super::{
+    camel_case, column_description, entity_description, type_kind, Context, GeneratedFile, TypeKind,
+};
+
+// JSON Schema (draft 2020-12, 엔티티별 .schema.json 파일)
+// 속성 이름과 값 표현은 TypeScript/Rust 타입과 같습니다. NULL 허용 컬럼은 required에서 빠지고 null도 받습니다.
+
+const SCHEMA: &str = "https://json-schema.org/draft/2020-12/schema";
+
+fn property(column: &Column) -> Map<String, Value> {
+    let mut property = Map::new();
+
+    let (json_type, format) = match type_kind(column) {
+        TypeKind::SmallInt | TypeKind::Int => (Some("integer"), Some("int32")),
+        TypeKind::BigInt => (Some("integer"), Some("int64")),
+        TypeKind::Boolean => (Some("boolean"), None),
+        TypeKind::Decimal => (Some("string"), Some("decimal")),
+        TypeKind::Float | TypeKind::Double => (Some("number"), None),
+        TypeKind::String | TypeKind::Text => (Some("string"), None),
+        TypeKind::Date => (Some("string"), Some("date")),
+        TypeKind::Time => (Some("string"), Some("time")),
+        TypeKind::DateTime | TypeKind::DateTimeTz => (Some("string"), Some("date-time")),
+        TypeKind::Uuid => (Some("string"), Some("uuid")),
+        TypeKind::Binary => (Some("string"), None),
+        // 어떤 JSON 값이든 받습니다.
+        TypeKind::Json => (None, None),
+    };
+
+    let is_optional = column.nullable && !column.is_primary_key;
+
+    match json_type {
+        Some(json_type) if is_optional => {
+            property.insert("type".into(), json!([json_type, "null"]));
+        }
+        Some(json_type) => {
+            property.insert("type".into(), json!(json_type));
+        }
+        None => {}
+    }
+
+    if let Some(format) = format {
+        property.insert("format".into(), json!(format));
+    }
+
+    match type_kind(column) {
+        TypeKind::String => {
+            if let Some(length) = column.length {
+                property.insert("maxLength".into(), json!(length));
+            }
+        }
+        TypeKind::Binary => {
+            property.insert("contentEncoding".into(), json!("base64"));
+        }
+        TypeKind::SmallInt | TypeKind::Int | TypeKind::BigInt if column.is_unsigned => {
+            property.insert("minimum".into(), json!(0));
+        }
+        _ => {}
+    }
+
+    let description = column_description(column);
+    if !description.is_empty() {
+        property.insert("description".into(), json!(description));
+    }
+
+    property
+}
+
+fn entity_schema(context: &Context, entity: &Entity) -> Map<String, Value> {
+    let mut properties = Map::new();
+    let mut required = vec![];
+
+    for column in entity.columns.iter() {
+        let name = camel_case(&column.physical_name);
+
+        if !column.nullable || column.is_primary_key {
+            required.push(name.clone());
+        }
+
+        properties.insert(name, Value::Object(property(column)));
+    }
+
+    let mut schema = Map::new();
+    schema.insert("title".into(), json!(context.model_name(entity)));
+
+    let description = entity_description(entity);
+    if !description.is_empty() {
+        schema.insert("description".into(), json!(description));
+    }
+
+    schema.insert("type".into(), json!("object"));
+    schema.insert("properties".into(), Value::Object(properties));
+    schema.insert("required".into(), json!(required));
+    schema.insert("additionalProperties".into(), json!(false));
+
+    schema
+}
+
+fn to_pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default() + "\n"
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    context
+        .entities
+        .iter()
+        .map(|entity| {
+            let model_name = context.model_name(entity);
+
+            let mut schema = Map::new();
+            schema.insert("$schema".into(), json!(SCHEMA));
+            schema.insert("$id".into(), json!(format!("{model_name}.schema.json")));
+            schema.extend(entity_schema(context, entity));
+
+            GeneratedFile {
+                path: format!("schemas/{model_name}.schema.json"),
+                content: to_pretty(&Value::Object(schema)),
+            }
+        })
+        .collect()
+}
+
+// 엔티티별 스키마를 $defs에 모은 문서 하나
+pub fn bundle(files: &[GeneratedFile]) -> String {
+    let mut definitions = Map::new();
+
+    for file in files.iter() {
+        let Ok(Value::Object(mut schema)) = serde_json::from_str::<Value>(&file.content) else {
+            continue;
+        };
+
+        schema.remove("$schema");
+        schema.remove("$id");
+
+        let name = schema
+            .get("title")
+            .and_then(|e| e.as_str())
+            .unwrap_or(&file.path)
+            .to_owned();
+
+        definitions.insert(name, Value::Object(schema));
+    }
+
+    to_pretty(&json!({
+        "$schema": SCHEMA,
+        "$defs": definitions,
+    }))
+}
@@ -0,0 +1,482 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CaseStyle, Column, Entity};
+
+use super::{
+    naming,
+    relation::{relations, Relation},
+    sql, Dialect,
+};
+
+pub mod diesel;
+pub mod jpa;
+pub mod json_schema;
+pub mod prisma;
+pub mod rust;
+pub mod seaorm;
+pub mod typeorm;
+pub mod typescript;
+
+// 프로젝트 모델로 ORM 코드와 애플리케이션 타입(DTO) 정의를 만듭니다.
+// 컬럼 타입은 먼저 TypeKind로 분류한 뒤 대상별 타입으로 바꾸고, 모델/관계 필드 이름은 모든 대상이 같은 규칙으로 짓습니다.
+// 엔티티는 물리명 순으로 정렬해서 같은 모델이면 항상 같은 코드가 나오게 합니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CodegenTarget {
+    Prisma,
+    Diesel,
+    SeaOrm,
+    Jpa,
+    TypeOrm,
+    // DTO 타입 정의
+    TypeScript,
+    Rust,
+    JsonSchema,
+}
+
+impl CodegenTarget {
+    // 파일 여러 개를 하나로 합칠 때 쓰는 파일 이름
+    fn single_file_name(&self) -> &'static str {
+        match self {
+            CodegenTarget::Prisma => "schema.prisma",
+            CodegenTarget::Diesel => "schema.rs",
+            CodegenTarget::SeaOrm => "entities.rs",
+            CodegenTarget::Jpa => "Entities.java",
+            CodegenTarget::TypeOrm => "entities.ts",
+            CodegenTarget::TypeScript => "types.ts",
+            CodegenTarget::Rust => "types.rs",
+            CodegenTarget::JsonSchema => "schema.json",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    pub dialect: Dialect,
+    // JPA 클래스 패키지
+    pub package: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeneratedFile {
+    pub path: String,
+    pub content: String,
+}
+
+// 대상 언어와 무관한 타입 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKind {
+    SmallInt,
+    Int,
+    BigInt,
+    Boolean,
+    Decimal,
+    Float,
+    Double,
+    String,
+    Text,
+    Date,
+    Time,
+    DateTime,
+    DateTimeTz,
+    Json,
+    Uuid,
+    Binary,
+}
+
+// 괄호 앞의 타입 이름 (대문자, UNSIGNED 제외)
+pub fn base_type(column: &Column) -> String {
+    let upper = column.data_type.trim().to_uppercase();
+
+    let base = match upper.find('(') {
+        Some(position) => upper[..position].trim(),
+        None => upper.as_str(),
+    };
+
+    base.trim_end_matches(" UNSIGNED").to_owned()
+}
+
+pub fn type_kind(column: &Column) -> TypeKind {
+    match base_type(column).as_str() {
+        // MySQL은 TINYINT(1)을 불리언으로 씁니다.
+        "TINYINT" if column.full_type().ends_with("(1)") => TypeKind::Boolean,
+        "TINYINT" | "SMALLINT" | "INT2" | "SMALLSERIAL" | "SERIAL2" => TypeKind::SmallInt,
+        "MEDIUMINT" | "INT" | "INTEGER" | "INT4" | "SERIAL" | "SERIAL4" => TypeKind::Int,
+        "BIGINT" | "INT8" | "BIGSERIAL" | "SERIAL8" => TypeKind::BigInt,
+        "BOOL" | "BOOLEAN" | "BIT" => TypeKind::Boolean,
+        "DECIMAL" | "NUMERIC" | "DEC" | "MONEY" => TypeKind::Decimal,
+        "FLOAT" | "REAL" | "FLOAT4" => TypeKind::Float,
+        "DOUBLE" | "DOUBLE PRECISION" | "FLOAT8" => TypeKind::Double,
+        "TEXT" | "TINYTEXT" | "MEDIUMTEXT" | "LONGTEXT" | "CLOB" | "CITEXT" => TypeKind::Text,
+        "DATE" => TypeKind::Date,
+        "TIME" | "TIMETZ" => TypeKind::Time,
+        "DATETIME" | "TIMESTAMP" => TypeKind::DateTime,
+        "TIMESTAMPTZ" | "TIMESTAMP WITH TIME ZONE" => TypeKind::DateTimeTz,
+        "JSON" | "JSONB" => TypeKind::Json,
+        "UUID" => TypeKind::Uuid,
+        "BLOB" | "TINYBLOB" | "MEDIUMBLOB" | "LONGBLOB" | "BINARY" | "VARBINARY" | "BYTEA" => {
+            TypeKind::Binary
+        }
+        // CHAR, VARCHAR, ENUM 등 나머지는 문자열로 봅니다.
+        _ => TypeKind::String,
+    }
+}
+
+// 기본값 표현
+pub enum DefaultValue {
+    Text(String),
+    Number(String),
+    Bool(bool),
+    CurrentTimestamp,
+    // 그 밖의 SQL 식
+    Expression(String),
+}
+
+pub fn default_value(column: &Column) -> Option<DefaultValue> {
+    let value = column.default_value.as_deref()?.trim();
+
+    if value.is_empty() || value.eq_ignore_ascii_case("NULL") {
+        return None;
+    }
+
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return Some(DefaultValue::Text(
+            value[1..value.len() - 1].replace("''", "'"),
+        ));
+    }
+
+    if matches!(
+        value.to_uppercase().as_str(),
+        "CURRENT_TIMESTAMP" | "CURRENT_TIMESTAMP()" | "NOW()" | "LOCALTIMESTAMP"
+    ) {
+        return Some(DefaultValue::CurrentTimestamp);
+    }
+
+    let kind = type_kind(column);
+
+    if kind == TypeKind::Boolean {
+        match value.to_uppercase().as_str() {
+            "TRUE" | "1" | "B'1'" => return Some(DefaultValue::Bool(true)),
+            "FALSE" | "0" | "B'0'" => return Some(DefaultValue::Bool(false)),
+            _ => {}
+        }
+    }
+
+    let is_number = matches!(
+        kind,
+        TypeKind::SmallInt
+            | TypeKind::Int
+            | TypeKind::BigInt
+            | TypeKind::Decimal
+            | TypeKind::Float
+            | TypeKind::Double
+    );
+
+    if is_number && value.parse::<f64>().is_ok() {
+        return Some(DefaultValue::Number(value.to_owned()));
+    }
+
+    Some(DefaultValue::Expression(value.to_owned()))
+}
+
+// 큰따옴표 문자열 리터럴 (Prisma, Java, TypeScript 공통)
+pub fn quoted(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| format!("\"{text}\""))
+}
+
+// 한 줄 주석으로 쓸 설명 (논리명이 물리명과 다르면 앞에 붙입니다)
+pub fn description(logical_name: &str, physical_name: &str, comment: &str) -> String {
+    let logical_name = logical_name.trim();
+    let comment = comment.trim().replace(['\r', '\n'], " ");
+
+    let has_logical_name = !logical_name.is_empty() && logical_name != physical_name;
+
+    match (has_logical_name, comment.is_empty()) {
+        (false, true) => String::new(),
+        (true, true) => logical_name.to_owned(),
+        (false, false) => comment,
+        (true, false) => format!("{logical_name} - {comment}"),
+    }
+}
+
+pub fn column_description(column: &Column) -> String {
+    description(&column.logical_name, &column.physical_name, &column.comment)
+}
+
+pub fn entity_description(entity: &Entity) -> String {
+    description(&entity.logical_name, &entity.physical_name, &entity.comment)
+}
+
+// 식별자로 쓸 수 없는 문자는 _로 바꾸고, 숫자로 시작하면 앞에 _를 붙입니다.
+fn identifier(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+fn convert_case(name: &str, case_style: CaseStyle) -> String {
+    identifier(&naming::apply_case(&naming::split_words(name), case_style))
+}
+
+pub fn pascal_case(name: &str) -> String {
+    convert_case(name, CaseStyle::Pascal)
+}
+
+pub fn camel_case(name: &str) -> String {
+    convert_case(name, CaseStyle::Camel)
+}
+
+pub fn snake_case(name: &str) -> String {
+    convert_case(name, CaseStyle::Snake)
+}
+
+pub const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+    "type", "unsafe", "use", "where", "while", "yield",
+];
+
+// 예약어와 겹치면 뒤에 _를 붙입니다.
+pub fn escape_keyword(name: String, keywords: &[&str]) -> String {
+    if keywords.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+// 이미 쓰인 이름이면 뒤에 번호를 붙입니다.
+fn unique_name(used: &mut HashSet<String>, base: String) -> String {
+    let mut name = base.clone();
+    let mut number = 2;
+
+    while used.contains(&name) {
+        name = format!("{base}{number}");
+        number += 1;
+    }
+
+    used.insert(name.clone());
+    name
+}
+
+// 관계 한 건과 양쪽 엔티티에 만들 관계 필드 이름 (snake_case, 대상별로 표기만 바꿉니다)
+pub struct RelationField<'a> {
+    pub relation: Relation<'a>,
+    // FK 제약 조건 이름 (관계 이름이 필요한 대상에서 사용)
+    pub name: String,
+    // 자식 쪽 필드 (부모 하나를 가리킴)
+    pub child_field: String,
+    // 부모 쪽 필드 (자식 목록 또는 1:1이면 자식 하나)
+    pub parent_field: String,
+    // 같은 두 엔티티 사이에 관계가 여러 개이거나 자기 참조라서 관계를 이름으로 구분해야 하는 경우
+    pub is_ambiguous: bool,
+}
+
+pub struct Context<'a> {
+    pub entities: Vec<&'a Entity>,
+    pub relations: Vec<RelationField<'a>>,
+    pub options: &'a CodegenOptions,
+    // 엔티티 id -> 모델(클래스) 이름
+    model_names: HashMap<String, String>,
+}
+
+impl<'a> Context<'a> {
+    fn new(entities: &'a [Entity], options: &'a CodegenOptions) -> Self {
+        let mut entities = entities.iter().collect::<Vec<_>>();
+        entities.sort_by(|a, b| a.physical_name.cmp(&b.physical_name));
+
+        // 모델 이름: 테이블명의 마지막 단어를 단수로 바꾼 PascalCase (order_items -> OrderItem)
+        let mut used = HashSet::new();
+        let model_names = entities
+            .iter()
+            .map(|entity| {
+                let mut words = naming::split_words(&entity.physical_name);
+
+                if let Some(last) = words.last_mut() {
+                    *last = naming::singularize(last);
+                }
+
+                let name = identifier(&naming::apply_case(&words, CaseStyle::Pascal));
+                (entity.id.clone(), unique_name(&mut used, name))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut context = Context {
+            entities,
+            relations: vec![],
+            options,
+            model_names,
+        };
+
+        context.relations = context.relation_fields();
+        context
+    }
+
+    pub fn model_name(&self, entity: &Entity) -> String {
+        self.model_names
+            .get(&entity.id)
+            .cloned()
+            .unwrap_or_else(|| pascal_case(&entity.physical_name))
+    }
+
+    // 엔티티가 자식(FK 보유) 쪽인 관계
+    pub fn child_relations(&self, entity: &Entity) -> Vec<&RelationField<'a>> {
+        self.relations
+            .iter()
+            .filter(|e| e.relation.child.id == entity.id)
+            .collect()
+    }
+
+    // 엔티티가 부모(참조 대상) 쪽인 관계
+    pub fn parent_relations(&self, entity: &Entity) -> Vec<&RelationField<'a>> {
+        self.relations
+            .iter()
+            .filter(|e| e.relation.parent.id == entity.id)
+            .collect()
+    }
+
+    fn relation_fields(&self) -> Vec<RelationField<'a>> {
+        let relations = relations(&self.entities);
+
+        // 엔티티별로 이미 쓰인 필드 이름 (컬럼 이름 포함)
+        let mut used_names = self
+            .entities
+            .iter()
+            .map(|entity| {
+                let names = entity
+                    .columns
+                    .iter()
+                    .map(|e| snake_case(&e.physical_name))
+                    .collect::<HashSet<_>>();
+
+                (entity.id.clone(), names)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let is_ambiguous = relations
+            .iter()
+            .map(|relation| {
+                relation.parent.id == relation.child.id
+                    || relations
+                        .iter()
+                        .filter(|e| {
+                            e.parent.id == relation.parent.id && e.child.id == relation.child.id
+                        })
+                        .count()
+                        > 1
+            })
+            .collect::<Vec<_>>();
+
+        relations
+            .into_iter()
+            .zip(is_ambiguous)
+            .map(|(relation, is_ambiguous)| {
+                let column_name = snake_case(&relation.child_column.physical_name);
+
+                // user_id -> user
+                let child_base = match column_name.strip_suffix("_id") {
+                    Some(base) if !base.is_empty() => base.to_owned(),
+                    _ => snake_case(&self.model_name(relation.parent)),
+                };
+
+                let child_name = snake_case(&self.model_name(relation.child));
+                let parent_base = if relation.is_one_to_one() {
+                    child_name
+                } else {
+                    naming::pluralize(&child_name)
+                };
+
+                // 여러 관계가 같은 자식 목록 이름을 갖지 않도록 FK 컬럼 이름을 붙입니다.
+                let parent_base = if is_ambiguous {
+                    format!("{parent_base}_by_{column_name}")
+                } else {
+                    parent_base
+                };
+
+                let child_used = used_names.entry(relation.child.id.clone()).or_default();
+                let child_field = unique_name(child_used, child_base);
+
+                let parent_used = used_names.entry(relation.parent.id.clone()).or_default();
+                let parent_field = unique_name(parent_used, parent_base);
+
+                RelationField {
+                    name: sql::foreign_key_name(relation.child, relation.child_column),
+                    relation,
+                    child_field,
+                    parent_field,
+                    is_ambiguous,
+                }
+            })
+            .collect()
+    }
+}
+
+pub fn generate(
+    target: CodegenTarget,
+    entities: &[Entity],
+    options: &CodegenOptions,
+) -> Vec<GeneratedFile> {
+    let context = Context::new(entities, options);
+
+    match target {
+        CodegenTarget::Prisma => prisma::generate(&context),
+        CodegenTarget::Diesel => diesel::generate(&context),
+        CodegenTarget::SeaOrm => seaorm::generate(&context),
+        CodegenTarget::Jpa => jpa::generate(&context),
+        CodegenTarget::TypeOrm => typeorm::generate(&context),
+        CodegenTarget::TypeScript => typescript::generate(&context),
+        CodegenTarget::Rust => rust::generate(&context),
+        CodegenTarget::JsonSchema => json_schema::generate(&context),
+    }
+}
+
+// 파일이 여러 개면 경로 주석으로 구분해서 하나로 합칩니다. (파일 이름, 내용)
+// JSON Schema는 주석을 쓸 수 없으므로 $defs로 묶은 문서 하나로 만듭니다.
+pub fn to_single_file(target: CodegenTarget, files: &[GeneratedFile]) -> (String, String) {
+    if target == CodegenTarget::JsonSchema {
+        return (
+            target.single_file_name().to_owned(),
+            json_schema::bundle(files),
+        );
+    }
+
+    if let [file] = files {
+        let file_name = file.path.rsplit('/').next().unwrap_or(&file.path);
+        return (file_name.to_owned(), file.content.clone());
+    }
+
+    let content = files
+        .iter()
+        .map(|e| format!("// ===== {} =====\n\n{}", e.path, e.content))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    (target.single_file_name().to_owned(), content)
+}
+
+pub fn to_zip(files: &[GeneratedFile]) -> zip::result::ZipResult<Vec<u8>> {
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    for file in files.iter() {
+        writer.start_file(file.path.as_str(), options)?;
+        writer.write_all(file.content.as_bytes())?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
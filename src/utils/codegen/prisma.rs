@@ -0,0 +1,297 @@
+use crate::{
+    models::{Column, Entity},
+    utils::Dialect,
+};
+
+use super::{
+    camel_case, column_description, default_value, entity_description, quoted, type_kind, Context,
+    DefaultValue, GeneratedFile, TypeKind,
+};
+
+// Prisma schema (schema.prisma 한 파일)
+// 필드/모델 이름이 물리명과 다르면 @map/@@map으로 원래 이름을 남깁니다.
+
+fn provider(dialect: Dialect) -> &'static str {
+    match dialect {
+        Dialect::MySql => "mysql",
+        Dialect::PostgreSql => "postgresql",
+        Dialect::Sqlite => "sqlite",
+    }
+}
+
+fn scalar_type(column: &Column, dialect: Dialect) -> &'static str {
+    match type_kind(column) {
+        TypeKind::SmallInt | TypeKind::Int => "Int",
+        TypeKind::BigInt => "BigInt",
+        TypeKind::Boolean => "Boolean",
+        TypeKind::Decimal => "Decimal",
+        TypeKind::Float | TypeKind::Double => "Float",
+        TypeKind::String | TypeKind::Text | TypeKind::Uuid => "String",
+        TypeKind::Date | TypeKind::Time | TypeKind::DateTime | TypeKind::DateTimeTz => "DateTime",
+        // SQLite 커넥터는 Json 타입을 지원하지 않습니다.
+        TypeKind::Json if dialect == Dialect::Sqlite => "String",
+        TypeKind::Json => "Json",
+        TypeKind::Binary => "Bytes",
+    }
+}
+
+// 기본 매핑과 다른 DB 타입이면 @db.* 속성을 붙입니다. (SQLite는 네이티브 타입 속성이 없습니다)
+fn native_type(column: &Column, dialect: Dialect) -> Option<String> {
+    if dialect == Dialect::Sqlite {
+        return None;
+    }
+
+    let is_char = super::base_type(column).ends_with("CHAR")
+        && !super::base_type(column).ends_with("VARCHAR");
+
+    match (type_kind(column), dialect) {
+        (TypeKind::String, _) => match column.length {
+            Some(length) if is_char => Some(format!("Char({length})")),
+            Some(length) => Some(format!("VarChar({length})")),
+            None => None,
+        },
+        (TypeKind::Text, _) => Some("Text".to_owned()),
+        (TypeKind::SmallInt, _) => Some("SmallInt".to_owned()),
+        (TypeKind::Decimal, _) => match (column.precision, column.scale) {
+            (Some(precision), scale) => Some(format!(
+                "Decimal({precision}, {})",
+                scale.unwrap_or_default()
+            )),
+            _ => None,
+        },
+        (TypeKind::Float, Dialect::PostgreSql) => Some("Real".to_owned()),
+        (TypeKind::Float, _) => Some("Float".to_owned()),
+        (TypeKind::Date, _) => Some("Date".to_owned()),
+        (TypeKind::Time, _) => Some("Time".to_owned()),
+        (TypeKind::DateTimeTz, Dialect::PostgreSql) => Some("Timestamptz".to_owned()),
+        (TypeKind::Uuid, Dialect::PostgreSql) => Some("Uuid".to_owned()),
+        _ => None,
+    }
+}
+
+fn default_attribute(column: &Column) -> Option<String> {
+    if column.is_auto_increment {
+        return Some("@default(autoincrement())".to_owned());
+    }
+
+    let value = match default_value(column)? {
+        DefaultValue::Text(text) => quoted(&text),
+        DefaultValue::Number(number) => number,
+        DefaultValue::Bool(value) => value.to_string(),
+        DefaultValue::CurrentTimestamp => "now()".to_owned(),
+        DefaultValue::Expression(expression) => format!("dbgenerated({})", quoted(&expression)),
+    };
+
+    Some(format!("@default({value})"))
+}
+
+// 필드 이름 (column_id 등 같은 엔티티 안에서 겹치지 않는다고 봅니다)
+fn field_name(column: &Column) -> String {
+    camel_case(&column.physical_name)
+}
+
+// (이름, 타입, 속성) 행을 prisma format처럼 열을 맞춰 씁니다.
+fn aligned(rows: &[(String, String, String)]) -> Vec<String> {
+    let name_width = rows.iter().map(|e| e.0.len()).max().unwrap_or_default();
+    let type_width = rows.iter().map(|e| e.1.len()).max().unwrap_or_default();
+
+    rows.iter()
+        .map(|(name, field_type, attributes)| {
+            if attributes.is_empty() {
+                format!("  {name:name_width$} {field_type}")
+            } else {
+                format!("  {name:name_width$} {field_type:type_width$} {attributes}")
+            }
+        })
+        .collect()
+}
+
+fn render_model(context: &Context, entity: &Entity) -> Vec<String> {
+    let dialect = context.options.dialect;
+    let model_name = context.model_name(entity);
+    let primary_keys = crate::utils::sql::primary_key_columns(entity);
+
+    // 설명 주석은 정렬에 넣지 않으려고 필드 행과 따로 모읍니다.
+    let mut fields: Vec<(Option<String>, (String, String, String))> = vec![];
+
+    for column in entity.columns.iter() {
+        let name = field_name(column);
+        let optional = if column.nullable && !column.is_primary_key {
+            "?"
+        } else {
+            ""
+        };
+
+        let mut attributes = vec![];
+
+        if column.is_primary_key && primary_keys.len() == 1 {
+            attributes.push("@id".to_owned());
+        }
+
+        if column.is_unique && !(column.is_primary_key && primary_keys.len() == 1) {
+            attributes.push("@unique".to_owned());
+        }
+
+        attributes.extend(default_attribute(column));
+
+        if name != column.physical_name {
+            attributes.push(format!("@map({})", quoted(&column.physical_name)));
+        }
+
+        attributes.extend(native_type(column, dialect).map(|e| format!("@db.{e}")));
+
+        let comment = column_description(column);
+
+        fields.push((
+            (!comment.is_empty()).then_some(comment),
+            (
+                name,
+                format!("{}{optional}", scalar_type(column, dialect)),
+                attributes.join(" "),
+            ),
+        ));
+    }
+
+    for field in context.child_relations(entity) {
+        let relation = &field.relation;
+        let parent_name = context.model_name(relation.parent);
+
+        let name = if field.is_ambiguous {
+            format!("{}, ", quoted(&field.name))
+        } else {
+            String::new()
+        };
+
+        fields.push((
+            None,
+            (
+                camel_case(&field.child_field),
+                format!(
+                    "{parent_name}{}",
+                    if relation.is_optional() { "?" } else { "" }
+                ),
+                format!(
+                    "@relation({name}fields: [{}], references: [{}])",
+                    field_name(relation.child_column),
+                    field_name(relation.parent_column)
+                ),
+            ),
+        ));
+    }
+
+    for field in context.parent_relations(entity) {
+        let relation = &field.relation;
+        let child_name = context.model_name(relation.child);
+
+        fields.push((
+            None,
+            (
+                camel_case(&field.parent_field),
+                format!(
+                    "{child_name}{}",
+                    if relation.is_one_to_one() { "?" } else { "[]" }
+                ),
+                if field.is_ambiguous {
+                    format!("@relation({})", quoted(&field.name))
+                } else {
+                    String::new()
+                },
+            ),
+        ));
+    }
+
+    let mut lines = vec![];
+
+    let comment = entity_description(entity);
+    if !comment.is_empty() {
+        lines.push(format!("/// {comment}"));
+    }
+
+    lines.push(format!("model {model_name} {{"));
+
+    let rows = fields.iter().map(|e| e.1.clone()).collect::<Vec<_>>();
+    for ((comment, _), line) in fields.iter().zip(aligned(&rows)) {
+        if let Some(comment) = comment {
+            lines.push(format!("  /// {comment}"));
+        }
+        lines.push(line);
+    }
+
+    let mut block_attributes = vec![];
+
+    if primary_keys.len() > 1 {
+        block_attributes.push(format!(
+            "@@id([{}])",
+            primary_keys
+                .iter()
+                .map(|e| field_name(e))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    for index in entity.indexes.iter() {
+        let columns = index
+            .columns
+            .iter()
+            .filter_map(|index_column| {
+                entity
+                    .columns
+                    .iter()
+                    .find(|e| e.id == index_column.column_id)
+            })
+            .map(field_name)
+            .collect::<Vec<_>>();
+
+        if columns.is_empty() {
+            continue;
+        }
+
+        block_attributes.push(format!(
+            "{}([{}], map: {})",
+            if index.is_unique {
+                "@@unique"
+            } else {
+                "@@index"
+            },
+            columns.join(", "),
+            quoted(&index.name)
+        ));
+    }
+
+    if model_name != entity.physical_name {
+        block_attributes.push(format!("@@map({})", quoted(&entity.physical_name)));
+    }
+
+    if !block_attributes.is_empty() {
+        lines.push(String::new());
+        lines.extend(block_attributes.into_iter().map(|e| format!("  {e}")));
+    }
+
+    lines.push("}".to_owned());
+
+    lines
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    let mut lines = vec![
+        "generator client {".to_owned(),
+        "  provider = \"prisma-client-js\"".to_owned(),
+        "}".to_owned(),
+        String::new(),
+        "datasource db {".to_owned(),
+        format!("  provider = {}", quoted(provider(context.options.dialect))),
+        "  url      = env(\"DATABASE_URL\")".to_owned(),
+        "}".to_owned(),
+    ];
+
+    for entity in context.entities.iter() {
+        lines.push(String::new());
+        lines.extend(render_model(context, entity));
+    }
+
+    vec![GeneratedFile {
+        path: "schema.prisma".to_owned(),
+        content: lines.join("\n") + "\n",
+    }]
+}
@@ -0,0 +1,119 @@
+use crate::models::{Column, Entity};
+
+use super::{
+    camel_case, column_description, entity_description, escape_keyword, quoted, snake_case,
+    type_kind, Context, GeneratedFile, TypeKind, RUST_KEYWORDS,
+};
+
+// serde 구조체 (types.rs 한 파일)
+// JSON 이름은 TypeScript/JSON Schema와 같은 camelCase이고, NULL 허용 컬럼은 Option 필드로 빠져도 되게 합니다.
+// 날짜/시간은 chrono, UUID는 uuid, DECIMAL은 rust_decimal 타입을 씁니다.
+
+fn rust_type(column: &Column) -> &'static str {
+    let unsigned = column.is_unsigned;
+
+    match type_kind(column) {
+        TypeKind::SmallInt if unsigned => "u16",
+        TypeKind::SmallInt => "i16",
+        TypeKind::Int if unsigned => "u32",
+        TypeKind::Int => "i32",
+        TypeKind::BigInt if unsigned => "u64",
+        TypeKind::BigInt => "i64",
+        TypeKind::Boolean => "bool",
+        TypeKind::Decimal => "rust_decimal::Decimal",
+        TypeKind::Float => "f32",
+        TypeKind::Double => "f64",
+        TypeKind::String | TypeKind::Text => "String",
+        TypeKind::Date => "chrono::NaiveDate",
+        TypeKind::Time => "chrono::NaiveTime",
+        TypeKind::DateTime => "chrono::NaiveDateTime",
+        TypeKind::DateTimeTz => "chrono::DateTime<chrono::Utc>",
+        TypeKind::Json => "serde_json::Value",
+        TypeKind::Uuid => "uuid::Uuid",
+        TypeKind::Binary => "Vec<u8>",
+    }
+}
+
+// #[serde(rename_all = "camelCase")]가 필드 이름에서 만드는 이름
+fn serde_camel_case(field: &str) -> String {
+    let mut name = String::new();
+    let mut capitalize = false;
+
+    for c in field.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            name.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            name.push(c);
+        }
+    }
+
+    name
+}
+
+fn doc_comment(lines: &mut Vec<String>, indent: &str, comment: &str) {
+    if !comment.is_empty() {
+        lines.push(format!("{indent}/// {comment}"));
+    }
+}
+
+fn render_struct(context: &Context, entity: &Entity) -> Vec<String> {
+    let mut lines = vec![];
+
+    doc_comment(&mut lines, "", &entity_description(entity));
+    lines.push("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]".to_owned());
+    lines.push("#[serde(rename_all = \"camelCase\")]".to_owned());
+    lines.push(format!("pub struct {} {{", context.model_name(entity)));
+
+    for column in entity.columns.iter() {
+        doc_comment(&mut lines, "    ", &column_description(column));
+
+        let field = escape_keyword(snake_case(&column.physical_name), RUST_KEYWORDS);
+        let json_name = camel_case(&column.physical_name);
+
+        let mut attributes = vec![];
+
+        if serde_camel_case(&field) != json_name {
+            attributes.push(format!("rename = {}", quoted(&json_name)));
+        }
+
+        let is_optional = column.nullable && !column.is_primary_key;
+
+        if is_optional {
+            attributes.push("default".to_owned());
+            attributes.push("skip_serializing_if = \"Option::is_none\"".to_owned());
+        }
+
+        if !attributes.is_empty() {
+            lines.push(format!("    #[serde({})]", attributes.join(", ")));
+        }
+
+        if is_optional {
+            lines.push(format!("    pub {field}: Option<{}>,", rust_type(column)));
+        } else {
+            lines.push(format!("    pub {field}: {},", rust_type(column)));
+        }
+    }
+
+    lines.push("}".to_owned());
+
+    lines
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    let mut blocks = vec!["use serde::{Deserialize, Serialize};".to_owned()];
+
+    blocks.extend(
+        context
+            .entities
+            .iter()
+            .map(|entity| render_struct(context, entity).join("\n")),
+    );
+
+    vec![GeneratedFile {
+        path: "src/types.rs".to_owned(),
+        content: blocks.join("\n\n") + "\n",
+    }]
+}
@@ -0,0 +1,269 @@
+use crate::{
+    models::{Column, Entity},
+    utils::sql,
+};
+
+use super::{
+    column_description, entity_description, escape_keyword, pascal_case, quoted, snake_case,
+    type_kind, Context, GeneratedFile, TypeKind, RUST_KEYWORDS,
+};
+
+// SeaORM 엔티티 (sea-orm-cli generate entity와 같은 구성: 엔티티별 파일 + mod.rs + prelude.rs)
+// 관계가 모호한 경우(자기 참조, 같은 테이블 쌍의 여러 FK)에는 belongs_to만 만들고 Related 구현은 생략합니다.
+
+fn rust_type(column: &Column) -> String {
+    let unsigned = column.is_unsigned;
+
+    let rust_type = match type_kind(column) {
+        TypeKind::SmallInt if unsigned => "u16",
+        TypeKind::SmallInt => "i16",
+        TypeKind::Int if unsigned => "u32",
+        TypeKind::Int => "i32",
+        TypeKind::BigInt if unsigned => "u64",
+        TypeKind::BigInt => "i64",
+        TypeKind::Boolean => "bool",
+        TypeKind::Decimal => "Decimal",
+        TypeKind::Float => "f32",
+        TypeKind::Double => "f64",
+        TypeKind::String | TypeKind::Text => "String",
+        TypeKind::Date => "Date",
+        TypeKind::Time => "Time",
+        TypeKind::DateTime => "DateTime",
+        TypeKind::DateTimeTz => "DateTimeWithTimeZone",
+        TypeKind::Json => "Json",
+        TypeKind::Uuid => "Uuid",
+        TypeKind::Binary => "Vec<u8>",
+    };
+
+    if column.nullable && !column.is_primary_key {
+        format!("Option<{rust_type}>")
+    } else {
+        rust_type.to_owned()
+    }
+}
+
+// 기본 매핑으로 충분하지 않은 컬럼 타입
+fn column_type(column: &Column) -> Option<String> {
+    match type_kind(column) {
+        TypeKind::Text => Some("Text".to_owned()),
+        TypeKind::Decimal => column.precision.map(|precision| {
+            format!(
+                "Decimal(Some(({precision}, {})))",
+                column.scale.unwrap_or_default()
+            )
+        }),
+        _ => None,
+    }
+}
+
+fn field_name(column: &Column) -> String {
+    escape_keyword(snake_case(&column.physical_name), RUST_KEYWORDS)
+}
+
+fn module_name(entity: &Entity) -> String {
+    escape_keyword(snake_case(&entity.physical_name), RUST_KEYWORDS)
+}
+
+// Column enum의 variant 이름
+fn column_variant(column: &Column) -> String {
+    pascal_case(&column.physical_name)
+}
+
+fn render_entity(context: &Context, entity: &Entity) -> String {
+    let primary_keys = sql::primary_key_columns(entity);
+
+    let mut lines = vec!["use sea_orm::entity::prelude::*;".to_owned(), String::new()];
+
+    let comment = entity_description(entity);
+    if !comment.is_empty() {
+        lines.push(format!("/// {comment}"));
+    }
+
+    lines.push("#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]".to_owned());
+    lines.push(format!(
+        "#[sea_orm(table_name = {})]",
+        quoted(&entity.physical_name)
+    ));
+    lines.push("pub struct Model {".to_owned());
+
+    for column in entity.columns.iter() {
+        let name = field_name(column);
+        let mut attributes = vec![];
+
+        if column.is_primary_key {
+            attributes.push("primary_key".to_owned());
+
+            let is_integer = matches!(
+                type_kind(column),
+                TypeKind::SmallInt | TypeKind::Int | TypeKind::BigInt
+            );
+
+            // 정수 PK는 기본으로 자동 증가로 봅니다.
+            if !column.is_auto_increment && (is_integer || primary_keys.len() > 1) {
+                attributes.push("auto_increment = false".to_owned());
+            }
+        }
+
+        if name != column.physical_name {
+            attributes.push(format!("column_name = {}", quoted(&column.physical_name)));
+        }
+
+        if let Some(column_type) = column_type(column) {
+            attributes.push(format!("column_type = {}", quoted(&column_type)));
+        }
+
+        if column.nullable && column.is_primary_key {
+            attributes.push("nullable".to_owned());
+        }
+
+        if column.is_unique && !column.is_primary_key {
+            attributes.push("unique".to_owned());
+        }
+
+        let comment = column_description(column);
+        if !comment.is_empty() {
+            lines.push(format!("    /// {comment}"));
+        }
+
+        if !attributes.is_empty() {
+            lines.push(format!("    #[sea_orm({})]", attributes.join(", ")));
+        }
+
+        lines.push(format!("    pub {name}: {},", rust_type(column)));
+    }
+
+    lines.push("}".to_owned());
+    lines.push(String::new());
+
+    let child_relations = context.child_relations(entity);
+    let parent_relations = context
+        .parent_relations(entity)
+        .into_iter()
+        .filter(|e| !e.is_ambiguous)
+        .collect::<Vec<_>>();
+
+    lines.push("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]".to_owned());
+
+    if child_relations.is_empty() && parent_relations.is_empty() {
+        lines.push("pub enum Relation {}".to_owned());
+    } else {
+        lines.push("pub enum Relation {".to_owned());
+
+        for field in child_relations.iter() {
+            let relation = &field.relation;
+            let parent_module = module_name(relation.parent);
+
+            lines.push("    #[sea_orm(".to_owned());
+            lines.push(format!(
+                "        belongs_to = \"super::{parent_module}::Entity\","
+            ));
+            lines.push(format!(
+                "        from = \"Column::{}\",",
+                column_variant(relation.child_column)
+            ));
+            lines.push(format!(
+                "        to = \"super::{parent_module}::Column::{}\"",
+                column_variant(relation.parent_column)
+            ));
+            lines.push("    )]".to_owned());
+            lines.push(format!("    {},", pascal_case(&field.child_field)));
+        }
+
+        for field in parent_relations.iter() {
+            let relation = &field.relation;
+
+            lines.push(format!(
+                "    #[sea_orm({} = \"super::{}::Entity\")]",
+                if relation.is_one_to_one() {
+                    "has_one"
+                } else {
+                    "has_many"
+                },
+                module_name(relation.child)
+            ));
+            lines.push(format!("    {},", pascal_case(&field.parent_field)));
+        }
+
+        lines.push("}".to_owned());
+    }
+
+    // Related는 상대 엔티티마다 하나만 구현할 수 있습니다.
+    let mut related = vec![];
+
+    for (module, variant) in child_relations
+        .iter()
+        .filter(|e| !e.is_ambiguous)
+        .map(|e| (module_name(e.relation.parent), pascal_case(&e.child_field)))
+        .chain(
+            parent_relations
+                .iter()
+                .map(|e| (module_name(e.relation.child), pascal_case(&e.parent_field))),
+        )
+    {
+        if related.iter().any(|(e, _)| e == &module) {
+            continue;
+        }
+
+        related.push((module, variant));
+    }
+
+    for (module, variant) in related.iter() {
+        lines.push(String::new());
+        lines.push(format!(
+            "impl Related<super::{module}::Entity> for Entity {{"
+        ));
+        lines.push("    fn to() -> RelationDef {".to_owned());
+        lines.push(format!("        Relation::{variant}.def()"));
+        lines.push("    }".to_owned());
+        lines.push("}".to_owned());
+    }
+
+    lines.push(String::new());
+    lines.push("impl ActiveModelBehavior for ActiveModel {}".to_owned());
+
+    lines.join("\n") + "\n"
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    let modules = context
+        .entities
+        .iter()
+        .map(|e| module_name(e))
+        .collect::<Vec<_>>();
+
+    let mut files = vec![];
+
+    let mut mod_lines = vec!["pub mod prelude;".to_owned(), String::new()];
+    mod_lines.extend(modules.iter().map(|e| format!("pub mod {e};")));
+
+    files.push(GeneratedFile {
+        path: "src/entities/mod.rs".to_owned(),
+        content: mod_lines.join("\n") + "\n",
+    });
+
+    let prelude_lines = context
+        .entities
+        .iter()
+        .zip(modules.iter())
+        .map(|(entity, module)| {
+            format!(
+                "pub use super::{module}::Entity as {};",
+                context.model_name(entity)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    files.push(GeneratedFile {
+        path: "src/entities/prelude.rs".to_owned(),
+        content: prelude_lines.join("\n") + "\n",
+    });
+
+    for (entity, module) in context.entities.iter().zip(modules.iter()) {
+        files.push(GeneratedFile {
+            path: format!("src/entities/{module}.rs"),
+            content: render_entity(context, entity),
+        });
+    }
+
+    files
+}
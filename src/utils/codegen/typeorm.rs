@@ -0,0 +1,220 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    models::{Column, Entity},
+    utils::Dialect,
+};
+
+use super::{
+    base_type, camel_case, column_description, default_value, entity_description, quoted,
+    type_kind, Context, DefaultValue, GeneratedFile, TypeKind,
+};
+
+// TypeORM 엔티티 (데코레이터 방식, 엔티티별 .ts 파일)
+// 컬럼 type에는 모델의 DB 타입을 그대로 넘기고, 속성 타입은 드라이버가 돌려주는 JS 타입에 맞춥니다.
+
+fn ts_type(column: &Column) -> &'static str {
+    match type_kind(column) {
+        TypeKind::SmallInt | TypeKind::Int | TypeKind::Float | TypeKind::Double => "number",
+        // BIGINT/DECIMAL은 정밀도를 잃지 않도록 문자열로 받습니다.
+        TypeKind::BigInt | TypeKind::Decimal => "string",
+        TypeKind::Boolean => "boolean",
+        TypeKind::String | TypeKind::Text | TypeKind::Uuid => "string",
+        TypeKind::Date | TypeKind::Time => "string",
+        TypeKind::DateTime | TypeKind::DateTimeTz => "Date",
+        TypeKind::Json => "unknown",
+        TypeKind::Binary => "Buffer",
+    }
+}
+
+fn column_options(column: &Column, dialect: Dialect) -> Vec<String> {
+    let mut options = vec![
+        format!("name: {}", quoted(&column.physical_name)),
+        format!("type: {}", quoted(&base_type(column).to_lowercase())),
+    ];
+
+    match type_kind(column) {
+        TypeKind::String => options.extend(column.length.map(|e| format!("length: {e}"))),
+        TypeKind::Decimal => {
+            options.extend(column.precision.map(|e| format!("precision: {e}")));
+            options.extend(column.scale.map(|e| format!("scale: {e}")));
+        }
+        _ => {}
+    }
+
+    if column.is_unsigned && dialect == Dialect::MySql {
+        options.push("unsigned: true".to_owned());
+    }
+
+    if column.is_primary_key {
+        return options;
+    }
+
+    if column.nullable {
+        options.push("nullable: true".to_owned());
+    }
+
+    if column.is_unique {
+        options.push("unique: true".to_owned());
+    }
+
+    let default = default_value(column).map(|e| match e {
+        DefaultValue::Text(text) => quoted(&text),
+        DefaultValue::Number(number) => number,
+        DefaultValue::Bool(value) => value.to_string(),
+        DefaultValue::CurrentTimestamp => "() => \"CURRENT_TIMESTAMP\"".to_owned(),
+        DefaultValue::Expression(expression) => format!("() => {}", quoted(&expression)),
+    });
+
+    options.extend(default.map(|e| format!("default: {e}")));
+
+    // DB 컬럼 코멘트 (SQLite는 지원하지 않습니다)
+    let comment = column.comment.trim();
+    if !comment.is_empty() && dialect != Dialect::Sqlite {
+        options.push(format!("comment: {}", quoted(comment)));
+    }
+
+    options
+}
+
+fn render_class(context: &Context, entity: &Entity) -> String {
+    let dialect = context.options.dialect;
+    let class_name = context.model_name(entity);
+
+    let mut decorators = BTreeSet::from(["Entity"]);
+    // 다른 엔티티 클래스 import
+    let mut entity_imports = BTreeSet::new();
+    let mut body = vec![];
+
+    for column in entity.columns.iter() {
+        let name = camel_case(&column.physical_name);
+
+        let decorator = match (column.is_primary_key, column.is_auto_increment) {
+            (true, true) => "PrimaryGeneratedColumn",
+            (true, false) => "PrimaryColumn",
+            (false, _) => "Column",
+        };
+        decorators.insert(decorator);
+
+        let nullable = if column.nullable && !column.is_primary_key {
+            " | null"
+        } else {
+            ""
+        };
+
+        body.push(String::new());
+
+        let comment = column_description(column);
+        if !comment.is_empty() {
+            body.push(format!("  /** {} */", comment.replace("*/", "* /")));
+        }
+
+        body.push(format!(
+            "  @{decorator}({{ {} }})",
+            column_options(column, dialect).join(", ")
+        ));
+        body.push(format!("  {name}!: {}{nullable};", ts_type(column)));
+    }
+
+    for field in context.child_relations(entity) {
+        let relation = &field.relation;
+        let parent_name = context.model_name(relation.parent);
+        let name = camel_case(&field.child_field);
+        let inverse = camel_case(&field.parent_field);
+
+        let decorator = if relation.is_one_to_one() {
+            "OneToOne"
+        } else {
+            "ManyToOne"
+        };
+        decorators.insert(decorator);
+        decorators.insert("JoinColumn");
+
+        if relation.parent.id != entity.id {
+            entity_imports.insert(parent_name.clone());
+        }
+
+        let nullable = if relation.is_optional() {
+            " | null"
+        } else {
+            ""
+        };
+
+        body.push(String::new());
+        body.push(format!(
+            "  @{decorator}(() => {parent_name}, (e) => e.{inverse}, {{ nullable: {} }})",
+            relation.is_optional()
+        ));
+        body.push(format!(
+            "  @JoinColumn({{ name: {}, referencedColumnName: {} }})",
+            quoted(&relation.child_column.physical_name),
+            quoted(&camel_case(&relation.parent_column.physical_name))
+        ));
+        body.push(format!("  {name}!: {parent_name}{nullable};"));
+    }
+
+    for field in context.parent_relations(entity) {
+        let relation = &field.relation;
+        let child_name = context.model_name(relation.child);
+        let name = camel_case(&field.parent_field);
+        let inverse = camel_case(&field.child_field);
+
+        if relation.child.id != entity.id {
+            entity_imports.insert(child_name.clone());
+        }
+
+        body.push(String::new());
+
+        if relation.is_one_to_one() {
+            decorators.insert("OneToOne");
+            body.push(format!(
+                "  @OneToOne(() => {child_name}, (e) => e.{inverse})"
+            ));
+            body.push(format!("  {name}?: {child_name} | null;"));
+        } else {
+            decorators.insert("OneToMany");
+            body.push(format!(
+                "  @OneToMany(() => {child_name}, (e) => e.{inverse})"
+            ));
+            body.push(format!("  {name}?: {child_name}[];"));
+        }
+    }
+
+    let mut lines = vec![format!(
+        "import {{ {} }} from \"typeorm\";",
+        decorators.into_iter().collect::<Vec<_>>().join(", ")
+    )];
+
+    lines.extend(
+        entity_imports
+            .iter()
+            .map(|e| format!("import {{ {e} }} from \"./{e}\";")),
+    );
+    lines.push(String::new());
+
+    let comment = entity_description(entity);
+    if !comment.is_empty() {
+        lines.push(format!("/** {} */", comment.replace("*/", "* /")));
+    }
+
+    lines.push(format!(
+        "@Entity({{ name: {} }})",
+        quoted(&entity.physical_name)
+    ));
+    lines.push(format!("export class {class_name} {{"));
+    lines.extend(body.into_iter().skip(1));
+    lines.push("}".to_owned());
+
+    lines.join("\n") + "\n"
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    context
+        .entities
+        .iter()
+        .map(|entity| GeneratedFile {
+            path: format!("src/entities/{}.ts", context.model_name(entity)),
+            content: render_class(context, entity),
+        })
+        .collect()
+}
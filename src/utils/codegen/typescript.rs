@@ -0,0 +1,75 @@
+use crate::models::{Column, Entity};
+
+use super::{
+    camel_case, column_description, entity_description, type_kind, Context, GeneratedFile, TypeKind,
+};
+
+// TypeScript 인터페이스 (types.ts 한 파일)
+// API에서 주고받는 JSON 기준이라 속성 이름은 camelCase, NULL 허용 컬럼은 선택 속성입니다.
+
+fn ts_type(column: &Column) -> &'static str {
+    match type_kind(column) {
+        TypeKind::SmallInt
+        | TypeKind::Int
+        | TypeKind::BigInt
+        | TypeKind::Float
+        | TypeKind::Double => "number",
+        TypeKind::Boolean => "boolean",
+        // DECIMAL은 정밀도를 잃지 않도록 문자열, 날짜/시간은 ISO 8601 문자열, 바이너리는 Base64 문자열로 주고받습니다.
+        TypeKind::Decimal
+        | TypeKind::String
+        | TypeKind::Text
+        | TypeKind::Uuid
+        | TypeKind::Date
+        | TypeKind::Time
+        | TypeKind::DateTime
+        | TypeKind::DateTimeTz
+        | TypeKind::Binary => "string",
+        TypeKind::Json => "unknown",
+    }
+}
+
+fn doc_comment(lines: &mut Vec<String>, indent: &str, comment: &str) {
+    if !comment.is_empty() {
+        lines.push(format!("{indent}/** {} */", comment.replace("*/", "* /")));
+    }
+}
+
+fn render_interface(context: &Context, entity: &Entity) -> Vec<String> {
+    let mut lines = vec![];
+
+    doc_comment(&mut lines, "", &entity_description(entity));
+    lines.push(format!(
+        "export interface {} {{",
+        context.model_name(entity)
+    ));
+
+    for column in entity.columns.iter() {
+        doc_comment(&mut lines, "  ", &column_description(column));
+
+        let name = camel_case(&column.physical_name);
+
+        if column.nullable && !column.is_primary_key {
+            lines.push(format!("  {name}?: {} | null;", ts_type(column)));
+        } else {
+            lines.push(format!("  {name}: {};", ts_type(column)));
+        }
+    }
+
+    lines.push("}".to_owned());
+
+    lines
+}
+
+pub fn generate(context: &Context) -> Vec<GeneratedFile> {
+    let interfaces = context
+        .entities
+        .iter()
+        .map(|entity| render_interface(context, entity).join("\n"))
+        .collect::<Vec<_>>();
+
+    vec![GeneratedFile {
+        path: "src/types.ts".to_owned(),
+        content: interfaces.join("\n\n") + "\n",
+    }]
+}
@@ -0,0 +1,369 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Column, Entity};
+
+use super::relation::relations;
+
+// 프로젝트 전체 컬럼 목록(컬럼 카탈로그)의 CSV 내보내기/가져오기
+// 가져올 때는 헤더 이름으로 필드를 찾으므로 스프레드시트에서 열 순서를 바꾸거나 일부 열을 지워도 됩니다.
+// entity_id/column_id로 기존 컬럼을 찾아 값이 달라진 필드만 반영합니다. (새 컬럼 추가/삭제는 하지 않습니다)
+
+const ENTITY_ID: &str = "entity_id";
+const COLUMN_ID: &str = "column_id";
+// 참고용 열 (가져올 때 무시)
+const FOREIGN_KEY: &str = "foreign_key";
+
+pub const HEADERS: [&str; 19] = [
+    ENTITY_ID,
+    "entity_logical_name",
+    "entity_physical_name",
+    COLUMN_ID,
+    "logical_name",
+    "physical_name",
+    "data_type",
+    "length",
+    "precision",
+    "scale",
+    "nullable",
+    "is_primary_key",
+    "is_unique",
+    "is_auto_increment",
+    "is_unsigned",
+    "default_value",
+    "check",
+    "comment",
+    FOREIGN_KEY,
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogChange {
+    pub line: usize,
+    pub entity_id: String,
+    pub column_id: Option<String>,
+    pub table: String,
+    pub column: Option<String>,
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogError {
+    pub line: usize,
+    pub message: String,
+}
+
+fn format_bool(value: bool) -> String {
+    if value { "Y" } else { "N" }.to_owned()
+}
+
+fn format_number(value: Option<u32>) -> String {
+    value.map(|e| e.to_string()).unwrap_or_default()
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "y" | "yes" | "true" | "1" => Ok(true),
+        "n" | "no" | "false" | "0" | "" => Ok(false),
+        _ => Err(format!("Y/N 값이 아닙니다: {value}")),
+    }
+}
+
+fn parse_number(value: &str) -> Result<Option<u32>, String> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    value
+        .parse::<u32>()
+        .map(Some)
+        .map_err(|_| format!("숫자가 아닙니다: {value}"))
+}
+
+fn parse_text(value: &str) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
+
+pub fn export_rows(entities: &[Entity]) -> Vec<Vec<String>> {
+    let mut entities = entities.iter().collect::<Vec<_>>();
+    entities.sort_by(|a, b| a.physical_name.cmp(&b.physical_name));
+
+    let relations = relations(&entities);
+
+    let mut rows = vec![HEADERS.iter().map(|e| e.to_string()).collect::<Vec<_>>()];
+
+    for entity in entities.iter() {
+        for column in entity.columns.iter() {
+            let foreign_key = relations
+                .iter()
+                .find(|e| e.child_column.id == column.id)
+                .map(|e| {
+                    format!(
+                        "{}.{}",
+                        e.parent.physical_name, e.parent_column.physical_name
+                    )
+                })
+                .unwrap_or_default();
+
+            rows.push(vec![
+                entity.id.clone(),
+                entity.logical_name.clone(),
+                entity.physical_name.clone(),
+                column.id.clone(),
+                column.logical_name.clone(),
+                column.physical_name.clone(),
+                column.data_type.clone(),
+                format_number(column.length),
+                format_number(column.precision),
+                format_number(column.scale),
+                format_bool(column.nullable),
+                format_bool(column.is_primary_key),
+                format_bool(column.is_unique),
+                format_bool(column.is_auto_increment),
+                format_bool(column.is_unsigned),
+                column.default_value.clone().unwrap_or_default(),
+                column.check.clone().unwrap_or_default(),
+                column.comment.clone(),
+                foreign_key,
+            ]);
+        }
+    }
+
+    rows
+}
+
+// 바뀐 경우 (변경 전, 변경 후) 표시값을 돌려줍니다.
+fn set_text(target: &mut String, value: &str) -> Option<(String, String)> {
+    if target == value {
+        return None;
+    }
+
+    let before = std::mem::replace(target, value.to_owned());
+    Some((before, value.to_owned()))
+}
+
+fn set_optional_text(target: &mut Option<String>, value: &str) -> Option<(String, String)> {
+    let value = parse_text(value);
+
+    if *target == value {
+        return None;
+    }
+
+    let before = std::mem::replace(target, value.clone());
+    Some((before.unwrap_or_default(), value.unwrap_or_default()))
+}
+
+fn set_number(target: &mut Option<u32>, value: &str) -> Result<Option<(String, String)>, String> {
+    let value = parse_number(value)?;
+
+    if *target == value {
+        return Ok(None);
+    }
+
+    let before = std::mem::replace(target, value);
+    Ok(Some((format_number(before), format_number(value))))
+}
+
+fn set_bool(target: &mut bool, value: &str) -> Result<Option<(String, String)>, String> {
+    let value = parse_bool(value)?;
+
+    if *target == value {
+        return Ok(None);
+    }
+
+    let before = std::mem::replace(target, value);
+    Ok(Some((format_bool(before), format_bool(value))))
+}
+
+// 엔티티 이름은 컬럼 행마다 반복되므로 원래 값과 같은 행은 무시하고, 행끼리 다른 새 값을 주면 오류로 봅니다.
+fn set_entity_text(
+    target: &mut String,
+    original: &str,
+    value: &str,
+) -> Result<Option<(String, String)>, String> {
+    if value == original {
+        return Ok(None);
+    }
+
+    if target != original && target != value {
+        return Err(format!("다른 행에서 이미 {target}(으)로 바꿨습니다."));
+    }
+
+    Ok(set_text(target, value))
+}
+
+fn apply_column_field(
+    column: &mut Column,
+    field: &str,
+    value: &str,
+) -> Result<Option<(String, String)>, String> {
+    Ok(match field {
+        "logical_name" => set_text(&mut column.logical_name, value),
+        "physical_name" => {
+            if value.is_empty() {
+                return Err("컬럼 물리명은 비워둘 수 없습니다.".into());
+            }
+
+            set_text(&mut column.physical_name, value)
+        }
+        "data_type" => {
+            if value.is_empty() {
+                return Err("데이터 타입은 비워둘 수 없습니다.".into());
+            }
+
+            set_text(&mut column.data_type, value)
+        }
+        "length" => set_number(&mut column.length, value)?,
+        "precision" => set_number(&mut column.precision, value)?,
+        "scale" => set_number(&mut column.scale, value)?,
+        "nullable" => set_bool(&mut column.nullable, value)?,
+        "is_primary_key" => set_bool(&mut column.is_primary_key, value)?,
+        "is_unique" => set_bool(&mut column.is_unique, value)?,
+        "is_auto_increment" => set_bool(&mut column.is_auto_increment, value)?,
+        "is_unsigned" => set_bool(&mut column.is_unsigned, value)?,
+        "default_value" => set_optional_text(&mut column.default_value, value),
+        "check" => set_optional_text(&mut column.check, value),
+        "comment" => set_text(&mut column.comment, value),
+        _ => None,
+    })
+}
+
+// 첫 행은 헤더여야 합니다. 오류가 있는 행은 건너뛰고 나머지 행은 반영합니다.
+// 엔티티 목록을 직접 고치고, 바뀐 내역과 오류를 돌려줍니다.
+pub fn apply_rows(
+    rows: &[Vec<String>],
+    entities: &mut [Entity],
+) -> Result<(Vec<CatalogChange>, Vec<CatalogError>), String> {
+    let headers = rows
+        .first()
+        .ok_or("CSV가 비어 있습니다.")?
+        .iter()
+        .map(|e| e.trim().to_ascii_lowercase())
+        .collect::<Vec<_>>();
+
+    let position = |name: &str| headers.iter().position(|e| e == name);
+
+    let (Some(entity_index), Some(column_index)) = (position(ENTITY_ID), position(COLUMN_ID))
+    else {
+        return Err(format!("{ENTITY_ID}, {COLUMN_ID} 열이 필요합니다."));
+    };
+
+    let original = entities.to_vec();
+
+    let mut changes = vec![];
+    let mut errors = vec![];
+
+    for (index, row) in rows.iter().enumerate().skip(1) {
+        let line = index + 1;
+        let value = |i: usize| row.get(i).map(|e| e.trim()).unwrap_or_default();
+
+        let entity_id = value(entity_index);
+        let column_id = value(column_index);
+
+        let Some(entity) = entities.iter_mut().find(|e| e.id == entity_id) else {
+            errors.push(CatalogError {
+                line,
+                message: format!("엔티티를 찾을 수 없습니다: {entity_id}"),
+            });
+            continue;
+        };
+
+        if !entity.columns.iter().any(|e| e.id == column_id) {
+            errors.push(CatalogError {
+                line,
+                message: format!("컬럼을 찾을 수 없습니다: {column_id}"),
+            });
+            continue;
+        }
+
+        // 한 행의 값은 모두 확인한 뒤에 반영합니다.
+        let Some(original) = original.iter().find(|e| e.id == entity_id) else {
+            continue;
+        };
+
+        let mut candidate = entity.clone();
+        let mut row_changes = vec![];
+        let mut row_error = None;
+
+        for (i, header) in headers.iter().enumerate() {
+            let value = value(i);
+
+            let changed = match header.as_str() {
+                "entity_logical_name" => {
+                    set_entity_text(&mut candidate.logical_name, &original.logical_name, value)
+                }
+                "entity_physical_name" if value.is_empty() => {
+                    Err("엔티티 물리명은 비워둘 수 없습니다.".to_owned())
+                }
+                "entity_physical_name" => {
+                    set_entity_text(&mut candidate.physical_name, &original.physical_name, value)
+                }
+                field => match candidate.columns.iter_mut().find(|e| e.id == column_id) {
+                    Some(column) => apply_column_field(column, field, value),
+                    None => Ok(None),
+                },
+            };
+
+            match changed {
+                Ok(Some((before, after))) => {
+                    let is_entity_field = header.starts_with("entity_");
+
+                    row_changes.push(CatalogChange {
+                        line,
+                        entity_id: entity_id.to_owned(),
+                        column_id: (!is_entity_field).then(|| column_id.to_owned()),
+                        table: candidate.physical_name.clone(),
+                        column: (!is_entity_field).then(|| {
+                            candidate
+                                .columns
+                                .iter()
+                                .find(|e| e.id == column_id)
+                                .map(|e| e.physical_name.clone())
+                                .unwrap_or_default()
+                        }),
+                        field: header.trim_start_matches("entity_").to_owned(),
+                        before,
+                        after,
+                    });
+                }
+                Ok(None) => {}
+                Err(message) => {
+                    row_error = Some(format!("{header}: {message}"));
+                    break;
+                }
+            }
+        }
+
+        if let Some(message) = row_error {
+            errors.push(CatalogError { line, message });
+            continue;
+        }
+
+        if let Some(column) = candidate.columns.iter_mut().find(|e| e.id == column_id) {
+            // PK 컬럼은 NULL을 허용하지 않습니다.
+            if column.is_primary_key {
+                column.nullable = false;
+            }
+
+            // 도메인에서 받은 타입을 직접 바꾸면 도메인 연결을 끊습니다.
+            if row_changes.iter().any(|e| {
+                e.column_id.is_some()
+                    && matches!(
+                        e.field.as_str(),
+                        "data_type" | "length" | "precision" | "scale"
+                    )
+            }) {
+                column.domain_id = None;
+            }
+        }
+
+        *entity = candidate;
+        changes.extend(row_changes);
+    }
+
+    Ok((changes, errors))
+}
@@ -0,0 +1,76 @@
+use super::AllError;
+
+// RFC 4180 형식의 간단한 CSV 읽기/쓰기 (엑셀에서 저장한 UTF-8 BOM 포함 파일도 처리)
+
+pub fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, AllError> {
+    let text = text.trim_start_matches('\u{feff}');
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                c => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(AllError::InvalidInput(
+            "CSV에 닫히지 않은 따옴표가 있습니다.".into(),
+        ));
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    Ok(rows
+        .into_iter()
+        .filter(|row| row.iter().any(|e| !e.trim().is_empty()))
+        .collect())
+}
+
+pub fn write_csv(rows: &[Vec<String>]) -> String {
+    let mut text = String::from('\u{feff}');
+
+    for row in rows {
+        let line = row
+            .iter()
+            .map(|field| {
+                if field.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.to_owned()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        text.push_str(&line);
+        text.push_str("\r\n");
+    }
+
+    text
+}
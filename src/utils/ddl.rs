@@ -0,0 +1,909 @@
+use crate::models::{IndexType, SortOrder};
+
+use super::{
+    schema_import::{SchemaColumn, SchemaForeignKey, SchemaIndex, SchemaTable},
+    AllError, Dialect,
+};
+
+// CREATE TABLE / ALTER TABLE / COMMENT ON 구문만 해석하는 간이 DDL 파서입니다.
+// 그 외의 구문(INSERT, DROP 등)은 무시합니다.
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Str(String),
+    Number(String),
+    Symbol(char),
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        matches!(self, Token::Symbol(c) if *c == symbol)
+    }
+
+    fn identifier(&self) -> Option<String> {
+        match self {
+            Token::Word(word) | Token::Quoted(word) => Some(word.to_owned()),
+            _ => None,
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            Token::Word(word) | Token::Number(word) => word.to_owned(),
+            Token::Quoted(word) => format!("\"{word}\""),
+            Token::Str(text) => format!("'{}'", text.replace('\'', "''")),
+            Token::Symbol(c) => c.to_string(),
+        }
+    }
+}
+
+fn tokenize(sql: &str, dialect: Dialect) -> Result<Vec<Token>, AllError> {
+    let chars = sql.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    let read_until = |start: usize, quote: char| -> Result<(String, usize), AllError> {
+        let mut text = String::new();
+        let mut i = start;
+
+        loop {
+            match chars.get(i) {
+                None => {
+                    return Err(AllError::InvalidInput(format!(
+                        "닫히지 않은 따옴표({quote})가 있습니다."
+                    )))
+                }
+                Some('\\') if quote == '\'' && dialect == Dialect::MySql => {
+                    if let Some(c) = chars.get(i + 1) {
+                        text.push(match c {
+                            'n' => '\n',
+                            't' => '\t',
+                            'r' => '\r',
+                            '0' => '\0',
+                            c => *c,
+                        });
+                    }
+                    i += 2;
+                }
+                Some(c) if *c == quote => {
+                    if chars.get(i + 1) == Some(&quote) {
+                        text.push(quote);
+                        i += 2;
+                    } else {
+                        return Ok((text, i + 1));
+                    }
+                }
+                Some(c) => {
+                    text.push(*c);
+                    i += 1;
+                }
+            }
+        }
+    };
+
+    while let Some(&c) = chars.get(i) {
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if (c == '-' && next == Some('-')) || (c == '#' && dialect == Dialect::MySql) {
+            while let Some(&c) = chars.get(i) {
+                if c == '\n' {
+                    break;
+                }
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            loop {
+                match (chars.get(i), chars.get(i + 1)) {
+                    (Some('*'), Some('/')) => {
+                        i += 2;
+                        break;
+                    }
+                    (Some(_), _) => i += 1,
+                    (None, _) => {
+                        return Err(AllError::InvalidInput(
+                            "닫히지 않은 주석이 있습니다.".into(),
+                        ))
+                    }
+                }
+            }
+        } else if c == '\'' {
+            let (text, end) = read_until(i + 1, '\'')?;
+            tokens.push(Token::Str(text));
+            i = end;
+        } else if c == '"' {
+            let (text, end) = read_until(i + 1, '"')?;
+            // MySQL은 기본 설정에서 큰따옴표를 문자열로 취급합니다.
+            if dialect == Dialect::MySql {
+                tokens.push(Token::Str(text));
+            } else {
+                tokens.push(Token::Quoted(text));
+            }
+            i = end;
+        } else if c == '`' {
+            let (text, end) = read_until(i + 1, '`')?;
+            tokens.push(Token::Quoted(text));
+            i = end;
+        } else if c == '[' && dialect == Dialect::Sqlite {
+            let (text, end) = read_until(i + 1, ']')?;
+            tokens.push(Token::Quoted(text));
+            i = end;
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while let Some(&c) = chars.get(i) {
+                if c.is_ascii_alphanumeric() || c == '.' {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while let Some(&c) = chars.get(i) {
+                if c.is_alphanumeric() || c == '_' || c == '$' {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(Token::Symbol(c));
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+// 괄호 깊이를 고려해 구분자 기준으로 토큰 목록을 나눕니다.
+fn split_top_level(tokens: &[Token], separator: char) -> Vec<&[Token]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            Token::Symbol(c) if *c == separator && depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&tokens[start..]);
+
+    parts.into_iter().filter(|e| !e.is_empty()).collect()
+}
+
+// 컬럼 정의에서 타입 이름이 끝나는 지점을 판단하기 위한 키워드
+const COLUMN_CONSTRAINT_KEYWORDS: &[&str] = &[
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "PRIMARY",
+    "UNIQUE",
+    "KEY",
+    "AUTO_INCREMENT",
+    "AUTOINCREMENT",
+    "COMMENT",
+    "REFERENCES",
+    "CHECK",
+    "CONSTRAINT",
+    "GENERATED",
+    "COLLATE",
+    "CHARSET",
+    "ON",
+    "IDENTITY",
+    "AS",
+    "STORED",
+    "VIRTUAL",
+    "INVISIBLE",
+    "VISIBLE",
+];
+
+struct Cursor<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(tokens: &'a [Token]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&'a Token> {
+        self.tokens.get(self.position + offset)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek().map(|e| e.is_keyword(keyword)).unwrap_or(false) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keywords(&mut self, keywords: &[&str]) -> bool {
+        let matched = keywords.iter().enumerate().all(|(i, keyword)| {
+            self.peek_at(i)
+                .map(|e| e.is_keyword(keyword))
+                .unwrap_or(false)
+        });
+
+        if matched {
+            self.position += keywords.len();
+        }
+
+        matched
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        if self.peek().map(|e| e.is_symbol(symbol)).unwrap_or(false) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // schema.table 형태면 마지막 이름만 사용합니다.
+    fn qualified_name(&mut self) -> Option<String> {
+        let mut name = self.next()?.identifier()?;
+
+        while self.peek().map(|e| e.is_symbol('.')).unwrap_or(false) {
+            self.position += 1;
+            name = self.next()?.identifier()?;
+        }
+
+        Some(name)
+    }
+
+    // 괄호로 묶인 구간을 통째로 읽습니다. (여는 괄호 위치에서 호출)
+    fn parenthesized(&mut self) -> &'a [Token] {
+        if !self.eat_symbol('(') {
+            return &[];
+        }
+
+        let start = self.position;
+        let mut depth = 1;
+
+        while let Some(token) = self.next() {
+            match token {
+                Token::Symbol('(') => depth += 1,
+                Token::Symbol(')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return &self.tokens[start..self.position - 1];
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        &self.tokens[start..]
+    }
+
+    // DEFAULT, ON UPDATE 등에 오는 단일 식을 읽습니다.
+    fn expression(&mut self) -> String {
+        let mut parts = vec![];
+
+        if self.eat_symbol('-') {
+            parts.push("-".to_string());
+        }
+
+        match self.peek() {
+            Some(Token::Symbol('(')) => {
+                let inner = self.parenthesized();
+                parts.push(format!("({})", render_tokens(inner)));
+            }
+            Some(token) => {
+                self.position += 1;
+                parts.push(token.render());
+
+                if matches!(token, Token::Word(_))
+                    && self.peek().map(|e| e.is_symbol('(')) == Some(true)
+                {
+                    let inner = self.parenthesized();
+                    parts.push(format!("({})", render_tokens(inner)));
+                }
+            }
+            None => {}
+        }
+
+        // PostgreSQL 캐스팅 ('a'::text)
+        while self.peek().map(|e| e.is_symbol(':')) == Some(true)
+            && self.peek_at(1).map(|e| e.is_symbol(':')) == Some(true)
+        {
+            self.position += 2;
+            if let Some(token) = self.next() {
+                parts.push(format!("::{}", token.render()));
+            }
+        }
+
+        parts.concat()
+    }
+}
+
+fn render_tokens(tokens: &[Token]) -> String {
+    let mut text = String::new();
+    let mut previous: Option<&Token> = None;
+
+    // 괄호, 쉼표 주변과 연속된 기호(>=, ::) 사이에는 공백을 넣지 않습니다.
+    let is_tight = |token: &Token| {
+        matches!(
+            token,
+            Token::Symbol('(' | ')' | ',' | '.' | ':' | '[' | ']')
+        )
+    };
+
+    for token in tokens {
+        let needs_space = match previous {
+            Some(previous) => {
+                let both_symbols =
+                    matches!(previous, Token::Symbol(_)) && matches!(token, Token::Symbol(_));
+                !(is_tight(previous) || is_tight(token) || both_symbols)
+            }
+            None => false,
+        };
+
+        if needs_space {
+            text.push(' ');
+        }
+        text.push_str(&token.render());
+        previous = Some(token);
+    }
+
+    text
+}
+
+// (a, b(10) DESC, c) 형태의 컬럼 목록에서 이름만 추립니다.
+fn column_names(tokens: &[Token]) -> Vec<String> {
+    split_top_level(tokens, ',')
+        .into_iter()
+        .filter_map(|part| part.first().and_then(|e| e.identifier()))
+        .collect()
+}
+
+pub fn parse_ddl(sql: &str, dialect: Dialect) -> Result<Vec<SchemaTable>, AllError> {
+    let tokens = tokenize(sql, dialect)?;
+    let mut tables: Vec<SchemaTable> = vec![];
+
+    for statement in split_top_level(&tokens, ';') {
+        let mut cursor = Cursor::new(statement);
+
+        if cursor.eat_keyword("CREATE") {
+            cursor.eat_keywords(&["OR", "REPLACE"]);
+            let _ = cursor.eat_keyword("TEMPORARY") || cursor.eat_keyword("TEMP");
+
+            if cursor.eat_keyword("TABLE") {
+                if let Some(table) = parse_create_table(&mut cursor) {
+                    tables.retain(|e| !e.name.eq_ignore_ascii_case(&table.name));
+                    tables.push(table);
+                }
+            } else {
+                parse_create_index(&mut cursor, &mut tables);
+            }
+        } else if cursor.eat_keywords(&["ALTER", "TABLE"]) {
+            cursor.eat_keywords(&["IF", "EXISTS"]);
+            cursor.eat_keyword("ONLY");
+
+            let name = match cursor.qualified_name() {
+                Some(name) => name,
+                None => continue,
+            };
+
+            if let Some(table) = tables
+                .iter_mut()
+                .find(|e| e.name.eq_ignore_ascii_case(&name))
+            {
+                parse_alter_table(&mut cursor, table);
+            }
+        } else if cursor.eat_keywords(&["COMMENT", "ON"]) {
+            parse_comment_on(&mut cursor, &mut tables);
+        }
+    }
+
+    Ok(tables)
+}
+
+fn parse_create_table(cursor: &mut Cursor) -> Option<SchemaTable> {
+    cursor.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+    let name = cursor.qualified_name()?;
+
+    if !cursor.peek()?.is_symbol('(') {
+        // CREATE TABLE ... AS SELECT, LIKE 등은 지원하지 않습니다.
+        return None;
+    }
+
+    let mut table = SchemaTable {
+        name,
+        ..Default::default()
+    };
+
+    let body = cursor.parenthesized();
+
+    for definition in split_top_level(body, ',') {
+        let mut element = Cursor::new(definition);
+
+        if is_table_constraint(definition) {
+            parse_table_constraint(&mut element, &mut table);
+        } else if let Some(column) = parse_column(&mut element, &mut table) {
+            table.columns.push(column);
+        }
+    }
+
+    // 테이블 옵션 (MySQL: COMMENT='...')
+    while let Some(token) = cursor.next() {
+        if token.is_keyword("COMMENT") {
+            cursor.eat_symbol('=');
+            if let Some(Token::Str(comment)) = cursor.peek() {
+                table.comment = comment.to_owned();
+                cursor.position += 1;
+            }
+        }
+    }
+
+    Some(table)
+}
+
+fn is_table_constraint(definition: &[Token]) -> bool {
+    let first = match definition.first() {
+        Some(Token::Word(word)) => word.to_uppercase(),
+        _ => return false,
+    };
+
+    match first.as_str() {
+        "CONSTRAINT" | "PRIMARY" | "FOREIGN" | "CHECK" | "FULLTEXT" | "SPATIAL" | "EXCLUDE" => true,
+        "UNIQUE" => true,
+        // KEY, INDEX는 컬럼명으로도 쓰일 수 있으므로 "KEY varchar(10)" 같은 형태는 컬럼으로 봅니다.
+        "KEY" | "INDEX" => match (definition.get(1), definition.get(2)) {
+            (Some(Token::Symbol('(')), _) => true,
+            (Some(token), _) if token.is_keyword("USING") => true,
+            (Some(Token::Word(_) | Token::Quoted(_)), Some(token)) if token.is_keyword("USING") => {
+                true
+            }
+            (Some(Token::Word(_) | Token::Quoted(_)), Some(Token::Symbol('('))) => {
+                !matches!(definition.get(3), Some(Token::Number(_)))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn parse_table_constraint(cursor: &mut Cursor, table: &mut SchemaTable) {
+    let is_unnamed_constraint = |token: &Token| {
+        ["PRIMARY", "FOREIGN", "UNIQUE", "CHECK"]
+            .iter()
+            .any(|keyword| token.is_keyword(keyword))
+    };
+
+    let mut constraint_name = None;
+
+    if cursor.eat_keyword("CONSTRAINT") && !cursor.peek().map(is_unnamed_constraint).unwrap_or(true)
+    {
+        constraint_name = cursor.next().and_then(|e| e.identifier());
+    }
+
+    if cursor.eat_keywords(&["PRIMARY", "KEY"]) {
+        let name = index_name(cursor);
+        table.primary_key = parse_index_body(cursor, name, true, IndexType::Btree)
+            .columns
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+    } else if cursor.eat_keywords(&["FOREIGN", "KEY"]) {
+        index_name(cursor);
+        let columns = column_names(cursor.parenthesized());
+
+        if let Some(foreign_key) = parse_references(cursor, columns) {
+            table.foreign_keys.push(foreign_key);
+        }
+    } else if cursor.eat_keyword("UNIQUE") {
+        let _ = cursor.eat_keyword("KEY") || cursor.eat_keyword("INDEX");
+        let name = index_name(cursor).or(constraint_name);
+        let index = parse_index_body(cursor, name, true, IndexType::Btree);
+
+        // 단일 컬럼 유니크 제약은 컬럼 속성으로 옮기고, 복합 유니크만 인덱스로 남깁니다.
+        if let [(name, _)] = index.columns.as_slice() {
+            if let Some(column) = table.find_column_mut(name) {
+                column.is_unique = true;
+            }
+        } else {
+            table.push_index(index);
+        }
+    } else {
+        let index_type = if cursor.eat_keyword("FULLTEXT") {
+            IndexType::Fulltext
+        } else if cursor.eat_keyword("SPATIAL") {
+            IndexType::Spatial
+        } else {
+            IndexType::Btree
+        };
+
+        if cursor.eat_keyword("KEY")
+            || cursor.eat_keyword("INDEX")
+            || index_type != IndexType::Btree
+        {
+            let name = index_name(cursor);
+            let index = parse_index_body(cursor, name, false, index_type);
+            table.push_index(index);
+        }
+    }
+}
+
+fn index_name(cursor: &mut Cursor) -> Option<String> {
+    let token = cursor.peek()?;
+
+    if token.is_keyword("USING") || token.is_keyword("ON") {
+        return None;
+    }
+
+    let name = token.identifier()?;
+    cursor.next();
+    Some(name)
+}
+
+// [USING method] (a, b DESC) [USING method]
+fn parse_index_body(
+    cursor: &mut Cursor,
+    name: Option<String>,
+    is_unique: bool,
+    mut index_type: IndexType,
+) -> SchemaIndex {
+    let mut read_method = |cursor: &mut Cursor| {
+        if cursor.eat_keyword("USING") {
+            match cursor.next() {
+                Some(token) if token.is_keyword("HASH") => index_type = IndexType::Hash,
+                Some(token) if token.is_keyword("GIST") || token.is_keyword("RTREE") => {
+                    index_type = IndexType::Spatial
+                }
+                Some(token) if token.is_keyword("GIN") => index_type = IndexType::Fulltext,
+                _ => {}
+            }
+        }
+    };
+
+    read_method(cursor);
+
+    let columns = split_top_level(cursor.parenthesized(), ',')
+        .into_iter()
+        .filter_map(|part| {
+            let name = part.first()?.identifier()?;
+            let order = if part.iter().any(|e| e.is_keyword("DESC")) {
+                SortOrder::Desc
+            } else {
+                SortOrder::Asc
+            };
+            Some((name, order))
+        })
+        .collect();
+
+    read_method(cursor);
+
+    SchemaIndex {
+        name: name.unwrap_or_default(),
+        columns,
+        is_unique,
+        index_type,
+    }
+}
+
+// CREATE [UNIQUE|FULLTEXT|SPATIAL] INDEX [CONCURRENTLY] [IF NOT EXISTS] name ON table ...
+fn parse_create_index(cursor: &mut Cursor, tables: &mut [SchemaTable]) {
+    let is_unique = cursor.eat_keyword("UNIQUE");
+    let index_type = if cursor.eat_keyword("FULLTEXT") {
+        IndexType::Fulltext
+    } else if cursor.eat_keyword("SPATIAL") {
+        IndexType::Spatial
+    } else {
+        IndexType::Btree
+    };
+
+    if !cursor.eat_keyword("INDEX") {
+        return;
+    }
+
+    cursor.eat_keyword("CONCURRENTLY");
+    cursor.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+    let name = index_name(cursor);
+
+    if !cursor.eat_keyword("ON") {
+        return;
+    }
+    cursor.eat_keyword("ONLY");
+
+    let table_name = match cursor.qualified_name() {
+        Some(table_name) => table_name,
+        None => return,
+    };
+
+    if let Some(table) = tables
+        .iter_mut()
+        .find(|e| e.name.eq_ignore_ascii_case(&table_name))
+    {
+        let index = parse_index_body(cursor, name, is_unique, index_type);
+        table.push_index(index);
+    }
+}
+
+fn parse_references(cursor: &mut Cursor, columns: Vec<String>) -> Option<SchemaForeignKey> {
+    if !cursor.eat_keyword("REFERENCES") {
+        return None;
+    }
+
+    let referenced_table = cursor.qualified_name()?;
+    let referenced_columns = if cursor.peek().map(|e| e.is_symbol('(')) == Some(true) {
+        column_names(cursor.parenthesized())
+    } else {
+        vec![]
+    };
+
+    // ON DELETE / ON UPDATE / MATCH / DEFERRABLE 등 참조 옵션은 건너뜁니다.
+    while let Some(token) = cursor.peek() {
+        if token.is_keyword("ON") {
+            cursor.next();
+            cursor.next();
+            if !(cursor.eat_keyword("CASCADE") || cursor.eat_keyword("RESTRICT")) {
+                let _ = cursor.eat_keywords(&["SET", "NULL"])
+                    || cursor.eat_keywords(&["SET", "DEFAULT"])
+                    || cursor.eat_keywords(&["NO", "ACTION"]);
+            }
+        } else if token.is_keyword("MATCH") {
+            cursor.next();
+            cursor.next();
+        } else if token.is_keyword("DEFERRABLE") || token.is_keyword("INITIALLY") {
+            cursor.next();
+            if cursor
+                .peek()
+                .map(|e| e.is_keyword("DEFERRED") || e.is_keyword("IMMEDIATE"))
+                == Some(true)
+            {
+                cursor.next();
+            }
+        } else {
+            break;
+        }
+    }
+
+    Some(SchemaForeignKey {
+        columns,
+        referenced_table,
+        referenced_columns,
+    })
+}
+
+fn parse_column(cursor: &mut Cursor, table: &mut SchemaTable) -> Option<SchemaColumn> {
+    let name = cursor.next()?.identifier()?;
+    let data_type = parse_data_type(cursor);
+
+    let mut column = SchemaColumn {
+        name,
+        nullable: true,
+        ..Default::default()
+    };
+    column.set_data_type(&data_type);
+
+    while let Some(token) = cursor.next() {
+        let keyword = match token {
+            Token::Word(word) => word.to_uppercase(),
+            _ => continue,
+        };
+
+        match keyword.as_str() {
+            "NOT" if cursor.eat_keyword("NULL") => column.nullable = false,
+            "NULL" => column.nullable = true,
+            "DEFAULT" => column.default_value = Some(cursor.expression()),
+            "UNIQUE" => {
+                let _ = cursor.eat_keyword("KEY") || cursor.eat_keyword("INDEX");
+                column.is_unique = true;
+            }
+            "AUTO_INCREMENT" | "AUTOINCREMENT" => column.is_auto_increment = true,
+            "PRIMARY" => {
+                cursor.eat_keyword("KEY");
+                let _ = cursor.eat_keyword("ASC") || cursor.eat_keyword("DESC");
+                column.is_primary_key = true;
+                column.nullable = false;
+            }
+            "COMMENT" => {
+                if let Some(Token::Str(comment)) = cursor.peek() {
+                    column.comment = comment.to_owned();
+                    cursor.next();
+                }
+            }
+            "REFERENCES" => {
+                cursor.position -= 1;
+                if let Some(foreign_key) = parse_references(cursor, vec![column.name.clone()]) {
+                    table.foreign_keys.push(foreign_key);
+                }
+            }
+            "CHECK" => column.check = Some(render_tokens(cursor.parenthesized())),
+            "CONSTRAINT" | "COLLATE" | "CHARSET" => {
+                cursor.next();
+            }
+            "CHARACTER" => {
+                cursor.eat_keyword("SET");
+                cursor.next();
+            }
+            "ON" => {
+                // ON UPDATE CURRENT_TIMESTAMP
+                cursor.next();
+                cursor.expression();
+            }
+            "GENERATED" => {
+                // GENERATED ALWAYS AS IDENTITY / GENERATED ALWAYS AS (expr) STORED
+                let _ = cursor.eat_keyword("ALWAYS") || cursor.eat_keywords(&["BY", "DEFAULT"]);
+                cursor.eat_keyword("AS");
+                if cursor.eat_keyword("IDENTITY") {
+                    column.nullable = false;
+                    column.is_auto_increment = true;
+                }
+                if cursor.peek().map(|e| e.is_symbol('(')) == Some(true) {
+                    cursor.parenthesized();
+                }
+            }
+            "AS" => {
+                cursor.parenthesized();
+            }
+            _ => {}
+        }
+    }
+
+    Some(column)
+}
+
+fn parse_data_type(cursor: &mut Cursor) -> String {
+    let mut parts: Vec<String> = vec![];
+
+    while let Some(token) = cursor.peek() {
+        match token {
+            Token::Word(word) => {
+                let upper = word.to_uppercase();
+
+                if COLUMN_CONSTRAINT_KEYWORDS.contains(&upper.as_str())
+                    || (upper == "CHARACTER"
+                        && cursor.peek_at(1).map(|e| e.is_keyword("SET")) == Some(true))
+                {
+                    break;
+                }
+
+                parts.push(upper);
+                cursor.next();
+            }
+            Token::Quoted(name) if parts.is_empty() => {
+                parts.push(name.to_owned());
+                cursor.next();
+            }
+            Token::Symbol('(') if !parts.is_empty() => {
+                let arguments = split_top_level(cursor.parenthesized(), ',')
+                    .into_iter()
+                    .map(render_tokens)
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                if let Some(last) = parts.last_mut() {
+                    last.push_str(&format!("({arguments})"));
+                }
+            }
+            Token::Symbol('[') if !parts.is_empty() => {
+                // PostgreSQL 배열 타입 (int[], text[3])
+                cursor.next();
+                let mut suffix = "[".to_string();
+                while let Some(token) = cursor.next() {
+                    suffix.push_str(&token.render());
+                    if token.is_symbol(']') {
+                        break;
+                    }
+                }
+                if let Some(last) = parts.last_mut() {
+                    last.push_str(&suffix);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    parts.join(" ")
+}
+
+fn parse_alter_table(cursor: &mut Cursor, table: &mut SchemaTable) {
+    let actions = split_top_level(&cursor.tokens[cursor.position..], ',');
+
+    for action in actions {
+        let mut action = Cursor::new(action);
+
+        if !action.eat_keyword("ADD") {
+            continue;
+        }
+
+        if action.eat_keyword("COLUMN") {
+            action.eat_keywords(&["IF", "NOT", "EXISTS"]);
+        } else if is_table_constraint(&action.tokens[action.position..]) {
+            parse_table_constraint(&mut action, table);
+            continue;
+        }
+
+        if let Some(column) = parse_column(&mut action, table) {
+            table
+                .columns
+                .retain(|e| !e.name.eq_ignore_ascii_case(&column.name));
+            table.columns.push(column);
+        }
+    }
+}
+
+fn parse_comment_on(cursor: &mut Cursor, tables: &mut [SchemaTable]) {
+    let is_table = cursor.eat_keyword("TABLE");
+    let is_column = !is_table && cursor.eat_keyword("COLUMN");
+
+    if !is_table && !is_column {
+        return;
+    }
+
+    let mut names = vec![];
+    while let Some(name) = cursor.peek().and_then(|e| e.identifier()) {
+        cursor.next();
+        names.push(name);
+        if !cursor.eat_symbol('.') {
+            break;
+        }
+    }
+
+    if !cursor.eat_keyword("IS") {
+        return;
+    }
+
+    let comment = match cursor.next() {
+        Some(Token::Str(comment)) => comment.to_owned(),
+        _ => return,
+    };
+
+    if is_table {
+        if let Some(table) = names.last().and_then(|name| {
+            tables
+                .iter_mut()
+                .find(|e| e.name.eq_ignore_ascii_case(name))
+        }) {
+            table.comment = comment;
+        }
+    } else if names.len() >= 2 {
+        let column_name = &names[names.len() - 1];
+        let table_name = &names[names.len() - 2];
+
+        if let Some(column) = tables
+            .iter_mut()
+            .find(|e| e.name.eq_ignore_ascii_case(table_name))
+            .and_then(|table| table.find_column_mut(column_name))
+        {
+            column.comment = comment;
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use serde::{Deserialize, Serialize};
+
+// SQL 방언
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Dialect {
+    #[default]
+    MySql,
+    PostgreSql,
+    Sqlite,
+}
+
+// 방언 공통 예약어 (SQL 표준 중 자주 충돌하는 것 위주)
+const COMMON_RESERVED_WORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CHECK",
+    "COLUMN",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DROP",
+    "ELSE",
+    "EXISTS",
+    "FOREIGN",
+    "FROM",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NOT",
+    "NULL",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "PRIMARY",
+    "REFERENCES",
+    "RIGHT",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TO",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+    "WITH",
+];
+
+const MYSQL_RESERVED_WORDS: &[&str] = &[
+    "ACCESSIBLE",
+    "CHANGE",
+    "CONDITION",
+    "DATABASE",
+    "DATABASES",
+    "DELAYED",
+    "DESCRIBE",
+    "DIV",
+    "DUAL",
+    "ENCLOSED",
+    "ESCAPED",
+    "EXPLAIN",
+    "FULLTEXT",
+    "GENERATED",
+    "GRANT",
+    "GROUPS",
+    "IGNORE",
+    "INTERVAL",
+    "KEYS",
+    "KILL",
+    "LEAD",
+    "LINES",
+    "LOAD",
+    "LOCK",
+    "LONG",
+    "MATCH",
+    "MOD",
+    "OPTION",
+    "OUT",
+    "PARTITION",
+    "RANGE",
+    "RANK",
+    "READ",
+    "REGEXP",
+    "RELEASE",
+    "RENAME",
+    "REPEAT",
+    "REPLACE",
+    "REQUIRE",
+    "RETURN",
+    "REVOKE",
+    "RLIKE",
+    "ROW",
+    "ROWS",
+    "SCHEMA",
+    "SEPARATOR",
+    "SHOW",
+    "SIGNAL",
+    "SPATIAL",
+    "SYSTEM",
+    "TRIGGER",
+    "UNLOCK",
+    "UNSIGNED",
+    "USAGE",
+    "USE",
+    "WINDOW",
+    "WRITE",
+    "XOR",
+    "ZEROFILL",
+];
+
+const POSTGRESQL_RESERVED_WORDS: &[&str] = &[
+    "ANALYSE",
+    "ANALYZE",
+    "ANY",
+    "ARRAY",
+    "ASYMMETRIC",
+    "AUTHORIZATION",
+    "BINARY",
+    "BOTH",
+    "CAST",
+    "COLLATE",
+    "COLLATION",
+    "CONCURRENTLY",
+    "CURRENT_CATALOG",
+    "CURRENT_ROLE",
+    "CURRENT_SCHEMA",
+    "CURRENT_USER",
+    "DEFERRABLE",
+    "DO",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FREEZE",
+    "FULL",
+    "GRANT",
+    "ILIKE",
+    "INITIALLY",
+    "INTERSECT",
+    "ISNULL",
+    "LATERAL",
+    "LEADING",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NATURAL",
+    "NOTNULL",
+    "OFFSET",
+    "ONLY",
+    "OVERLAPS",
+    "PLACING",
+    "RETURNING",
+    "SESSION_USER",
+    "SIMILAR",
+    "SOME",
+    "SYMMETRIC",
+    "TABLESAMPLE",
+    "TRAILING",
+    "TRUE",
+    "USER",
+    "VARIADIC",
+    "VERBOSE",
+    "WINDOW",
+];
+
+const SQLITE_RESERVED_WORDS: &[&str] = &[
+    "ABORT",
+    "ACTION",
+    "AFTER",
+    "ATTACH",
+    "AUTOINCREMENT",
+    "BEFORE",
+    "BEGIN",
+    "CASCADE",
+    "COMMIT",
+    "CONFLICT",
+    "DATABASE",
+    "DEFERRABLE",
+    "DEFERRED",
+    "DETACH",
+    "EACH",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXCLUSIVE",
+    "EXPLAIN",
+    "FAIL",
+    "FOR",
+    "GLOB",
+    "IF",
+    "IGNORE",
+    "IMMEDIATE",
+    "INDEXED",
+    "INITIALLY",
+    "INSTEAD",
+    "INTERSECT",
+    "ISNULL",
+    "MATCH",
+    "NATURAL",
+    "NO",
+    "NOTNULL",
+    "OF",
+    "OFFSET",
+    "PLAN",
+    "PRAGMA",
+    "QUERY",
+    "RAISE",
+    "RECURSIVE",
+    "REGEXP",
+    "REINDEX",
+    "RELEASE",
+    "RENAME",
+    "REPLACE",
+    "RESTRICT",
+    "ROLLBACK",
+    "ROW",
+    "SAVEPOINT",
+    "TEMP",
+    "TEMPORARY",
+    "TRANSACTION",
+    "TRIGGER",
+    "VACUUM",
+    "VIEW",
+    "VIRTUAL",
+];
+
+impl Dialect {
+    pub fn is_reserved_word(&self, word: &str) -> bool {
+        let word = word.to_uppercase();
+        let dialect_words = match self {
+            Dialect::MySql => MYSQL_RESERVED_WORDS,
+            Dialect::PostgreSql => POSTGRESQL_RESERVED_WORDS,
+            Dialect::Sqlite => SQLITE_RESERVED_WORDS,
+        };
+
+        COMMON_RESERVED_WORDS.contains(&word.as_str()) || dialect_words.contains(&word.as_str())
+    }
+
+    // SQLite는 식별자 길이 제한이 없습니다.
+    pub fn max_identifier_length(&self) -> Option<usize> {
+        match self {
+            Dialect::MySql => Some(64),
+            Dialect::PostgreSql => Some(63),
+            Dialect::Sqlite => None,
+        }
+    }
+}
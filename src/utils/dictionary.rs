@@ -0,0 +1,324 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Column, Entity, Project};
+
+use super::relation::{relations, Relation};
+
+// 테이블 정의서 (데이터 사전) 문서 생성
+// 엔티티 목차와 엔티티별 컬럼/인덱스 표를 만듭니다. 엔티티는 물리명 순으로 정렬합니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DictionaryFormat {
+    Markdown,
+    Html,
+}
+
+impl DictionaryFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DictionaryFormat::Markdown => "md",
+            DictionaryFormat::Html => "html",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            DictionaryFormat::Markdown => "text/markdown; charset=utf-8",
+            DictionaryFormat::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+const COLUMN_HEADERS: [&str; 9] = [
+    "No",
+    "물리명",
+    "논리명",
+    "타입",
+    "NULL",
+    "PK",
+    "FK",
+    "기본값",
+    "설명",
+];
+
+const INDEX_HEADERS: [&str; 4] = ["이름", "컬럼", "유니크", "종류"];
+
+fn anchor(index: usize) -> String {
+    format!("entity-{}", index + 1)
+}
+
+fn entity_title(entity: &Entity) -> String {
+    if entity.logical_name.is_empty() || entity.logical_name == entity.physical_name {
+        entity.physical_name.clone()
+    } else {
+        format!("{} ({})", entity.physical_name, entity.logical_name)
+    }
+}
+
+fn column_cells(number: usize, column: &Column, relations: &[Relation]) -> Vec<String> {
+    let foreign_key = relations
+        .iter()
+        .find(|e| e.child_column.id == column.id)
+        .map(|e| {
+            format!(
+                "{}.{}",
+                e.parent.physical_name, e.parent_column.physical_name
+            )
+        })
+        .unwrap_or_default();
+
+    vec![
+        number.to_string(),
+        column.physical_name.clone(),
+        column.logical_name.clone(),
+        column.full_type(),
+        if column.nullable { "Y" } else { "N" }.to_owned(),
+        if column.is_primary_key { "Y" } else { "" }.to_owned(),
+        foreign_key,
+        column.default_value.clone().unwrap_or_default(),
+        column.comment.clone(),
+    ]
+}
+
+fn index_cells(entity: &Entity) -> Vec<Vec<String>> {
+    entity
+        .indexes
+        .iter()
+        .map(|index| {
+            let columns = index
+                .columns
+                .iter()
+                .filter_map(|index_column| {
+                    entity
+                        .columns
+                        .iter()
+                        .find(|e| e.id == index_column.column_id)
+                })
+                .map(|e| e.physical_name.clone())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            vec![
+                index.name.clone(),
+                columns,
+                if index.is_unique { "Y" } else { "" }.to_owned(),
+                format!("{:?}", index.index_type),
+            ]
+        })
+        .collect()
+}
+
+pub fn render(format: DictionaryFormat, project: &Project, entities: &[Entity]) -> String {
+    let mut entities = entities.iter().collect::<Vec<_>>();
+    entities.sort_by(|a, b| a.physical_name.cmp(&b.physical_name));
+
+    match format {
+        DictionaryFormat::Markdown => to_markdown(project, &entities),
+        DictionaryFormat::Html => to_html(project, &entities),
+    }
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+    let mut lines = vec![
+        format!("| {} |", headers.join(" | ")),
+        format!("|{}", "---|".repeat(headers.len())),
+    ];
+
+    for row in rows.iter() {
+        let cells = row.iter().map(|e| markdown_cell(e)).collect::<Vec<_>>();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+
+    lines
+}
+
+fn to_markdown(project: &Project, entities: &[&Entity]) -> String {
+    let relations = relations(entities);
+
+    let mut lines = vec![format!("# {} 테이블 정의서", project.name), String::new()];
+
+    if !project.description.trim().is_empty() {
+        lines.push(project.description.trim().to_owned());
+        lines.push(String::new());
+    }
+
+    lines.push("## 목차".to_owned());
+    lines.push(String::new());
+
+    for (index, entity) in entities.iter().enumerate() {
+        lines.push(format!(
+            "{}. [{}](#{})",
+            index + 1,
+            markdown_cell(&entity_title(entity)),
+            anchor(index)
+        ));
+    }
+
+    for (index, entity) in entities.iter().enumerate() {
+        lines.push(String::new());
+        lines.push(format!("<a id=\"{}\"></a>", anchor(index)));
+        lines.push(String::new());
+        lines.push(format!("## {}. {}", index + 1, entity_title(entity)));
+        lines.push(String::new());
+        lines.push(format!("- 물리명: `{}`", entity.physical_name));
+        lines.push(format!("- 논리명: {}", entity.logical_name));
+
+        if !entity.comment.trim().is_empty() {
+            lines.push(format!("- 설명: {}", markdown_cell(entity.comment.trim())));
+        }
+
+        lines.push(String::new());
+
+        let rows = entity
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| column_cells(i + 1, column, &relations))
+            .collect::<Vec<_>>();
+
+        lines.extend(markdown_table(&COLUMN_HEADERS, &rows));
+
+        let indexes = index_cells(entity);
+
+        if !indexes.is_empty() {
+            lines.push(String::new());
+            lines.push("**인덱스**".to_owned());
+            lines.push(String::new());
+            lines.extend(markdown_table(&INDEX_HEADERS, &indexes));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn html_table(headers: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+    let mut lines = vec!["<table>".to_owned(), "<thead><tr>".to_owned()];
+
+    lines.extend(
+        headers
+            .iter()
+            .map(|e| format!("<th>{}</th>", html_escape(e))),
+    );
+    lines.push("</tr></thead>".to_owned());
+    lines.push("<tbody>".to_owned());
+
+    for row in rows.iter() {
+        let cells = row
+            .iter()
+            .map(|e| format!("<td>{}</td>", html_escape(e).replace('\n', "<br>")))
+            .collect::<String>();
+
+        lines.push(format!("<tr>{cells}</tr>"));
+    }
+
+    lines.push("</tbody>".to_owned());
+    lines.push("</table>".to_owned());
+
+    lines
+}
+
+const HTML_STYLE: &str = "body { font-family: sans-serif; margin: 2em; color: #1a202c; }
+table { border-collapse: collapse; width: 100%; margin: 0.5em 0 1.5em; }
+th, td { border: 1px solid #cbd5e0; padding: 4px 8px; text-align: left; font-size: 14px; }
+th { background: #edf2f7; }
+code { background: #edf2f7; padding: 0 4px; }
+@media print { section { page-break-inside: avoid; } }";
+
+fn to_html(project: &Project, entities: &[&Entity]) -> String {
+    let relations = relations(entities);
+    let title = html_escape(&format!("{} 테이블 정의서", project.name));
+
+    let mut lines = vec![
+        "<!DOCTYPE html>".to_owned(),
+        "<html lang=\"ko\">".to_owned(),
+        "<head>".to_owned(),
+        "<meta charset=\"utf-8\">".to_owned(),
+        format!("<title>{title}</title>"),
+        format!("<style>\n{HTML_STYLE}\n</style>"),
+        "</head>".to_owned(),
+        "<body>".to_owned(),
+        format!("<h1>{title}</h1>"),
+    ];
+
+    if !project.description.trim().is_empty() {
+        lines.push(format!(
+            "<p>{}</p>",
+            html_escape(project.description.trim())
+        ));
+    }
+
+    lines.push("<h2>목차</h2>".to_owned());
+    lines.push("<ol>".to_owned());
+
+    for (index, entity) in entities.iter().enumerate() {
+        lines.push(format!(
+            "<li><a href=\"#{}\">{}</a></li>",
+            anchor(index),
+            html_escape(&entity_title(entity))
+        ));
+    }
+
+    lines.push("</ol>".to_owned());
+
+    for (index, entity) in entities.iter().enumerate() {
+        lines.push(format!("<section id=\"{}\">", anchor(index)));
+        lines.push(format!(
+            "<h2>{}. {}</h2>",
+            index + 1,
+            html_escape(&entity_title(entity))
+        ));
+        lines.push("<ul>".to_owned());
+        lines.push(format!(
+            "<li>물리명: <code>{}</code></li>",
+            html_escape(&entity.physical_name)
+        ));
+        lines.push(format!(
+            "<li>논리명: {}</li>",
+            html_escape(&entity.logical_name)
+        ));
+
+        if !entity.comment.trim().is_empty() {
+            lines.push(format!(
+                "<li>설명: {}</li>",
+                html_escape(entity.comment.trim())
+            ));
+        }
+
+        lines.push("</ul>".to_owned());
+
+        let rows = entity
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| column_cells(i + 1, column, &relations))
+            .collect::<Vec<_>>();
+
+        lines.extend(html_table(&COLUMN_HEADERS, &rows));
+
+        let indexes = index_cells(entity);
+
+        if !indexes.is_empty() {
+            lines.push("<h3>인덱스</h3>".to_owned());
+            lines.extend(html_table(&INDEX_HEADERS, &indexes));
+        }
+
+        lines.push("</section>".to_owned());
+    }
+
+    lines.push("</body>".to_owned());
+    lines.push("</html>".to_owned());
+
+    lines.join("\n") + "\n"
+}
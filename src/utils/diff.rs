@@ -0,0 +1,761 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Column, Entity, Index};
+
+use super::{
+    sql::{self, ConstraintKind},
+    Dialect,
+};
+
+// 두 모델 상태(엔티티 목록)를 비교하고 마이그레이션 SQL을 만듭니다.
+// 엔티티/컬럼은 id로 먼저 맞추고, id가 다르면 물리명으로 맞춥니다. id가 같고 이름이 다르면 이름 변경으로 봅니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueChange<T> {
+    pub before: T,
+    pub after: T,
+}
+
+impl<T: PartialEq> ValueChange<T> {
+    fn of(before: T, after: T) -> Option<Self> {
+        if before == after {
+            None
+        } else {
+            Some(Self { before, after })
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnDiff {
+    pub kind: DiffKind,
+    pub column_id: String,
+    pub name: String,
+    pub previous_name: Option<String>,
+    pub data_type: Option<ValueChange<String>>,
+    pub nullable: Option<ValueChange<bool>>,
+    pub is_primary_key: Option<ValueChange<bool>>,
+    pub default_value: Option<ValueChange<Option<String>>>,
+    pub is_unique: Option<ValueChange<bool>>,
+    pub is_auto_increment: Option<ValueChange<bool>>,
+    pub foreign_key: Option<ValueChange<Option<String>>>,
+    pub comment: Option<ValueChange<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableDiff {
+    pub kind: DiffKind,
+    pub entity_id: String,
+    pub name: String,
+    pub previous_name: Option<String>,
+    pub comment: Option<ValueChange<String>>,
+    pub columns: Vec<ColumnDiff>,
+    pub indexes_added: Vec<String>,
+    pub indexes_removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Migration {
+    pub statements: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl Migration {
+    pub fn to_sql(&self) -> String {
+        let mut sql = self
+            .warnings
+            .iter()
+            .map(|e| format!("-- {e}\n"))
+            .collect::<String>();
+
+        if !sql.is_empty() {
+            sql.push('\n');
+        }
+
+        sql.push_str(&self.statements.join("\n"));
+        sql
+    }
+}
+
+trait Named {
+    fn id(&self) -> &str;
+    fn name(&self) -> &str;
+}
+
+impl Named for Entity {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.physical_name
+    }
+}
+
+impl Named for Column {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.physical_name
+    }
+}
+
+impl Named for Index {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+type Pair<'a, T> = (Option<&'a T>, Option<&'a T>);
+
+fn pair<'a, T: Named>(before: &'a [T], after: &'a [T]) -> Vec<Pair<'a, T>> {
+    let mut matched = vec![None; after.len()];
+
+    for (i, a) in after.iter().enumerate() {
+        matched[i] = before.iter().position(|b| b.id() == a.id());
+    }
+
+    for (i, a) in after.iter().enumerate() {
+        if matched[i].is_some() {
+            continue;
+        }
+
+        matched[i] = before.iter().enumerate().position(|(j, b)| {
+            !matched.contains(&Some(j)) && b.name().eq_ignore_ascii_case(a.name())
+        });
+    }
+
+    let mut pairs = after
+        .iter()
+        .enumerate()
+        .map(|(i, a)| (matched[i].map(|j| &before[j]), Some(a)))
+        .collect::<Vec<_>>();
+
+    pairs.extend(
+        before
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| !matched.contains(&Some(*j)))
+            .map(|(_, b)| (Some(b), None)),
+    );
+
+    pairs
+}
+
+fn renamed<T: Named>(before: &T, after: &T) -> Option<String> {
+    if before.name() != after.name() {
+        Some(before.name().to_owned())
+    } else {
+        None
+    }
+}
+
+fn display_type(column: &Column) -> String {
+    if column.is_unsigned {
+        format!("{} UNSIGNED", column.full_type())
+    } else {
+        column.full_type()
+    }
+}
+
+// 참조 대상의 표시 이름 (table.column). 대상을 찾을 수 없으면 None
+fn foreign_key_target(column: &Column, entities: &[Entity]) -> Option<String> {
+    let foreign_key = column.foreign_key.as_ref()?;
+    let target = entities.iter().find(|e| e.id == foreign_key.entity_id)?;
+    let target_column = target
+        .columns
+        .iter()
+        .find(|e| e.id == foreign_key.column_id)?;
+
+    Some(format!(
+        "{}.{}",
+        target.physical_name, target_column.physical_name
+    ))
+}
+
+fn is_same_index(before: &Index, after: &Index) -> bool {
+    before.name == after.name
+        && before.is_unique == after.is_unique
+        && before.index_type == after.index_type
+        && before.columns.len() == after.columns.len()
+        && before
+            .columns
+            .iter()
+            .zip(after.columns.iter())
+            .all(|(b, a)| b.column_id == a.column_id && b.order == a.order)
+}
+
+fn column_diff(
+    kind: DiffKind,
+    before: Option<&Column>,
+    after: Option<&Column>,
+    before_entities: &[Entity],
+    after_entities: &[Entity],
+) -> Option<ColumnDiff> {
+    let column = after.or(before)?;
+
+    let mut diff = ColumnDiff {
+        kind,
+        column_id: column.id.clone(),
+        name: column.physical_name.clone(),
+        previous_name: None,
+        data_type: None,
+        nullable: None,
+        is_primary_key: None,
+        default_value: None,
+        is_unique: None,
+        is_auto_increment: None,
+        foreign_key: None,
+        comment: None,
+    };
+
+    if let (Some(before), Some(after)) = (before, after) {
+        diff.previous_name = renamed(before, after);
+        diff.data_type = ValueChange::of(display_type(before), display_type(after));
+        diff.nullable = ValueChange::of(before.nullable, after.nullable);
+        diff.is_primary_key = ValueChange::of(before.is_primary_key, after.is_primary_key);
+        diff.default_value =
+            ValueChange::of(before.default_value.clone(), after.default_value.clone());
+        diff.is_unique = ValueChange::of(before.is_unique, after.is_unique);
+        diff.is_auto_increment = ValueChange::of(before.is_auto_increment, after.is_auto_increment);
+        diff.foreign_key = ValueChange::of(
+            foreign_key_target(before, before_entities),
+            foreign_key_target(after, after_entities),
+        );
+        diff.comment = ValueChange::of(before.comment.clone(), after.comment.clone());
+
+        let is_changed = diff.previous_name.is_some()
+            || diff.data_type.is_some()
+            || diff.nullable.is_some()
+            || diff.is_primary_key.is_some()
+            || diff.default_value.is_some()
+            || diff.is_unique.is_some()
+            || diff.is_auto_increment.is_some()
+            || diff.foreign_key.is_some()
+            || diff.comment.is_some();
+
+        if !is_changed {
+            return None;
+        }
+    }
+
+    Some(diff)
+}
+
+pub fn diff_entities(before: &[Entity], after: &[Entity]) -> Vec<TableDiff> {
+    let mut tables = vec![];
+
+    for (before_entity, after_entity) in pair(before, after) {
+        let (kind, entity) = match (before_entity, after_entity) {
+            (None, Some(entity)) => (DiffKind::Added, entity),
+            (Some(entity), None) => (DiffKind::Removed, entity),
+            (Some(_), Some(entity)) => (DiffKind::Modified, entity),
+            (None, None) => continue,
+        };
+
+        let mut table = TableDiff {
+            kind,
+            entity_id: entity.id.clone(),
+            name: entity.physical_name.clone(),
+            previous_name: None,
+            comment: None,
+            columns: vec![],
+            indexes_added: vec![],
+            indexes_removed: vec![],
+        };
+
+        let (Some(before_entity), Some(after_entity)) = (before_entity, after_entity) else {
+            tables.push(table);
+            continue;
+        };
+
+        table.previous_name = renamed(before_entity, after_entity);
+        table.comment =
+            ValueChange::of(before_entity.comment.clone(), after_entity.comment.clone());
+
+        for (before_column, after_column) in pair(&before_entity.columns, &after_entity.columns) {
+            let kind = match (before_column, after_column) {
+                (None, _) => DiffKind::Added,
+                (_, None) => DiffKind::Removed,
+                _ => DiffKind::Modified,
+            };
+
+            table.columns.extend(column_diff(
+                kind,
+                before_column,
+                after_column,
+                before,
+                after,
+            ));
+        }
+
+        for (before_index, after_index) in pair(&before_entity.indexes, &after_entity.indexes) {
+            match (before_index, after_index) {
+                (Some(b), Some(a)) if is_same_index(b, a) => {}
+                (b, a) => {
+                    table.indexes_removed.extend(b.map(|e| e.name.clone()));
+                    table.indexes_added.extend(a.map(|e| e.name.clone()));
+                }
+            }
+        }
+
+        let is_changed = table.previous_name.is_some()
+            || table.comment.is_some()
+            || !table.columns.is_empty()
+            || !table.indexes_added.is_empty()
+            || !table.indexes_removed.is_empty();
+
+        if is_changed {
+            tables.push(table);
+        }
+    }
+
+    tables
+}
+
+fn primary_key_ids(entity: &Entity) -> Vec<&str> {
+    sql::primary_key_columns(entity)
+        .iter()
+        .map(|e| e.id.as_str())
+        .collect()
+}
+
+// FK가 그대로 유지되는지 (대상, 제약 이름 모두 같아야 함)
+fn is_same_foreign_key(
+    before: (&Entity, &Column),
+    after: (&Entity, &Column),
+    after_entities: &[Entity],
+) -> bool {
+    let (before_entity, before_column) = before;
+    let (after_entity, after_column) = after;
+
+    match (&before_column.foreign_key, &after_column.foreign_key) {
+        (Some(b), Some(a)) => {
+            b.entity_id == a.entity_id
+                && b.column_id == a.column_id
+                && sql::foreign_key_name(before_entity, before_column)
+                    == sql::foreign_key_name(after_entity, after_column)
+                && foreign_key_target(after_column, after_entities).is_some()
+        }
+        _ => false,
+    }
+}
+
+fn is_column_definition_changed(before: &Column, after: &Column) -> bool {
+    display_type(before) != display_type(after)
+        || before.nullable != after.nullable
+        || before.default_value != after.default_value
+        || before.is_auto_increment != after.is_auto_increment
+        || before.check != after.check
+        || before.comment != after.comment
+}
+
+fn modify_column(
+    entity: &Entity,
+    before: &Column,
+    after: &Column,
+    dialect: Dialect,
+    migration: &mut Migration,
+) {
+    let table = sql::quote(&entity.physical_name, dialect);
+    let column = sql::quote(&after.physical_name, dialect);
+
+    match dialect {
+        Dialect::MySql => migration.statements.push(format!(
+            "ALTER TABLE {table} MODIFY COLUMN {};",
+            sql::column_definition(entity, after, dialect)
+        )),
+        Dialect::PostgreSql => {
+            let statements = &mut migration.statements;
+
+            if display_type(before) != display_type(after) {
+                statements.push(format!(
+                    "ALTER TABLE {table} ALTER COLUMN {column} TYPE {};",
+                    sql::column_type(after, dialect)
+                ));
+            }
+
+            if before.nullable != after.nullable {
+                let action = if after.nullable { "DROP" } else { "SET" };
+                statements.push(format!(
+                    "ALTER TABLE {table} ALTER COLUMN {column} {action} NOT NULL;"
+                ));
+            }
+
+            if before.default_value != after.default_value {
+                statements.push(match after.default_value.as_ref() {
+                    Some(default_value) => format!(
+                        "ALTER TABLE {table} ALTER COLUMN {column} SET DEFAULT {default_value};"
+                    ),
+                    None => format!("ALTER TABLE {table} ALTER COLUMN {column} DROP DEFAULT;"),
+                });
+            }
+
+            if before.is_auto_increment != after.is_auto_increment {
+                statements.push(if after.is_auto_increment {
+                    format!(
+                        "ALTER TABLE {table} ALTER COLUMN {column} ADD GENERATED BY DEFAULT AS IDENTITY;"
+                    )
+                } else {
+                    format!("ALTER TABLE {table} ALTER COLUMN {column} DROP IDENTITY;")
+                });
+            }
+
+            if before.check != after.check {
+                migration.warnings.push(format!(
+                    "{}.{}: CHECK 제약 변경은 직접 반영해야 합니다.",
+                    entity.physical_name, after.physical_name
+                ));
+            }
+
+            if before.comment != after.comment {
+                statements.push(format!(
+                    "COMMENT ON COLUMN {table}.{column} IS {};",
+                    if after.comment.is_empty() {
+                        "NULL".to_owned()
+                    } else {
+                        sql::string_literal(&after.comment)
+                    }
+                ));
+            }
+        }
+        Dialect::Sqlite => {
+            // SQLite는 설명(comment)을 저장하지 않으므로 그 외 변경만 경고합니다.
+            let before = Column {
+                comment: after.comment.clone(),
+                ..before.clone()
+            };
+
+            if is_column_definition_changed(&before, after) {
+                migration.warnings.push(format!(
+                    "{}.{}: SQLite는 컬럼 정의 변경을 지원하지 않습니다. 테이블을 다시 만들어야 합니다.",
+                    entity.physical_name, after.physical_name
+                ));
+            }
+        }
+    }
+}
+
+pub fn generate_migration(before: &[Entity], after: &[Entity], dialect: Dialect) -> Migration {
+    let mut migration = Migration::default();
+    let table_pairs = pair(before, after);
+
+    let mut drop_foreign_keys = vec![];
+    let mut drop_constraints = vec![];
+    let mut drop_indexes = vec![];
+    let mut drop_primary_keys = vec![];
+    let mut drop_tables = vec![];
+    let mut rename_tables = vec![];
+    let mut create_tables = vec![];
+    let mut alter_columns = vec![];
+    let mut add_primary_keys = vec![];
+    let mut add_constraints = vec![];
+    let mut create_indexes = vec![];
+    let mut add_foreign_keys = vec![];
+    let mut comments = vec![];
+
+    for (before_entity, after_entity) in table_pairs.iter().copied() {
+        // 삭제/변경되는 FK와 유니크 제약은 먼저 제거합니다.
+        if let Some(before_entity) = before_entity {
+            for before_column in before_entity.columns.iter() {
+                let after_column = after_entity.and_then(|entity| {
+                    pair(&before_entity.columns, &entity.columns)
+                        .into_iter()
+                        .find(|(b, _)| b.map(|e| e.id == before_column.id).unwrap_or(false))
+                        .and_then(|(_, a)| a)
+                        .map(|column| (entity, column))
+                });
+
+                if before_column.foreign_key.is_some()
+                    && foreign_key_target(before_column, before).is_some()
+                {
+                    let is_kept = after_column
+                        .map(|column| {
+                            is_same_foreign_key((before_entity, before_column), column, after)
+                        })
+                        .unwrap_or(false);
+
+                    // 삭제되는 테이블의 FK도 먼저 지워야 참조 대상 테이블을 삭제할 수 있습니다.
+                    // SQLite는 테이블과 함께 FK가 사라지므로 따로 지우지 않습니다.
+                    let is_dropped_with_table =
+                        after_entity.is_none() && dialect == Dialect::Sqlite;
+
+                    if !is_kept && !is_dropped_with_table {
+                        drop_foreign_keys.push(sql::drop_constraint(
+                            &before_entity.physical_name,
+                            ConstraintKind::ForeignKey,
+                            &sql::foreign_key_name(before_entity, before_column),
+                            dialect,
+                        ));
+                    }
+                }
+
+                if before_column.is_unique && after_entity.is_some() {
+                    let is_kept = after_column
+                        .map(|(entity, column)| {
+                            column.is_unique
+                                && sql::unique_name(entity, column)
+                                    == sql::unique_name(before_entity, before_column)
+                        })
+                        .unwrap_or(false);
+
+                    if !is_kept {
+                        drop_constraints.push(sql::drop_constraint(
+                            &before_entity.physical_name,
+                            ConstraintKind::Unique,
+                            &sql::unique_name(before_entity, before_column),
+                            dialect,
+                        ));
+                    }
+                }
+            }
+        }
+
+        match (before_entity, after_entity) {
+            (Some(before_entity), None) => {
+                drop_tables.push(format!(
+                    "DROP TABLE {};",
+                    sql::quote(&before_entity.physical_name, dialect)
+                ));
+            }
+            (None, Some(after_entity)) => {
+                create_tables.push(sql::create_table(after_entity, after, dialect));
+
+                create_indexes.extend(
+                    after_entity
+                        .indexes
+                        .iter()
+                        .filter_map(|e| sql::create_index(after_entity, e, dialect)),
+                );
+
+                if dialect != Dialect::Sqlite {
+                    add_foreign_keys.extend(after_entity.columns.iter().filter_map(|e| {
+                        sql::foreign_key_clause(after_entity, e, after, dialect).map(|clause| {
+                            format!(
+                                "ALTER TABLE {} ADD {clause};",
+                                sql::quote(&after_entity.physical_name, dialect)
+                            )
+                        })
+                    }));
+                }
+            }
+            (Some(before_entity), Some(after_entity)) => {
+                let table = sql::quote(&after_entity.physical_name, dialect);
+
+                if before_entity.physical_name != after_entity.physical_name {
+                    rename_tables.push(format!(
+                        "ALTER TABLE {} RENAME TO {table};",
+                        sql::quote(&before_entity.physical_name, dialect)
+                    ));
+                }
+
+                for (before_index, after_index) in
+                    pair(&before_entity.indexes, &after_entity.indexes)
+                {
+                    match (before_index, after_index) {
+                        (Some(b), Some(a)) if is_same_index(b, a) => {}
+                        (b, a) => {
+                            drop_indexes
+                                .extend(b.map(|e| sql::drop_index(before_entity, e, dialect)));
+                            create_indexes.extend(
+                                a.and_then(|e| sql::create_index(after_entity, e, dialect)),
+                            );
+                        }
+                    }
+                }
+
+                if primary_key_ids(before_entity) != primary_key_ids(after_entity) {
+                    if dialect == Dialect::Sqlite {
+                        migration.warnings.push(format!(
+                            "{}: SQLite는 기본 키 변경을 지원하지 않습니다. 테이블을 다시 만들어야 합니다.",
+                            after_entity.physical_name
+                        ));
+                    } else {
+                        if !primary_key_ids(before_entity).is_empty() {
+                            drop_primary_keys.push(sql::drop_constraint(
+                                &before_entity.physical_name,
+                                ConstraintKind::PrimaryKey,
+                                &sql::primary_key_name(before_entity),
+                                dialect,
+                            ));
+                        }
+
+                        if let Some(clause) = sql::primary_key_clause(after_entity, dialect) {
+                            add_primary_keys.push(format!("ALTER TABLE {table} ADD {clause};"));
+                        }
+                    }
+                }
+
+                for (before_column, after_column) in
+                    pair(&before_entity.columns, &after_entity.columns)
+                {
+                    match (before_column, after_column) {
+                        (Some(before_column), None) => {
+                            alter_columns.push(format!(
+                                "ALTER TABLE {table} DROP COLUMN {};",
+                                sql::quote(&before_column.physical_name, dialect)
+                            ));
+                        }
+                        (None, Some(after_column)) => {
+                            alter_columns.push(format!(
+                                "ALTER TABLE {table} ADD COLUMN {};",
+                                sql::column_definition(after_entity, after_column, dialect)
+                            ));
+
+                            if dialect == Dialect::PostgreSql && !after_column.comment.is_empty() {
+                                comments.push(format!(
+                                    "COMMENT ON COLUMN {table}.{} IS {};",
+                                    sql::quote(&after_column.physical_name, dialect),
+                                    sql::string_literal(&after_column.comment)
+                                ));
+                            }
+                        }
+                        (Some(before_column), Some(after_column)) => {
+                            if before_column.physical_name != after_column.physical_name {
+                                alter_columns.push(format!(
+                                    "ALTER TABLE {table} RENAME COLUMN {} TO {};",
+                                    sql::quote(&before_column.physical_name, dialect),
+                                    sql::quote(&after_column.physical_name, dialect)
+                                ));
+                            }
+
+                            if is_column_definition_changed(before_column, after_column) {
+                                let mut statements = Migration::default();
+                                modify_column(
+                                    after_entity,
+                                    before_column,
+                                    after_column,
+                                    dialect,
+                                    &mut statements,
+                                );
+                                alter_columns.extend(statements.statements);
+                                migration.warnings.extend(statements.warnings);
+                            }
+                        }
+                        (None, None) => {}
+                    }
+                }
+
+                if before_entity.comment != after_entity.comment {
+                    match dialect {
+                        Dialect::MySql => comments.push(format!(
+                            "ALTER TABLE {table} COMMENT={};",
+                            sql::string_literal(&after_entity.comment)
+                        )),
+                        Dialect::PostgreSql => comments.push(format!(
+                            "COMMENT ON TABLE {table} IS {};",
+                            if after_entity.comment.is_empty() {
+                                "NULL".to_owned()
+                            } else {
+                                sql::string_literal(&after_entity.comment)
+                            }
+                        )),
+                        Dialect::Sqlite => {}
+                    }
+                }
+
+                // 새로 생기거나 이름/대상이 바뀐 FK, 유니크 제약을 추가합니다.
+                for after_column in after_entity.columns.iter() {
+                    let before_column = pair(&before_entity.columns, &after_entity.columns)
+                        .into_iter()
+                        .find(|(_, a)| a.map(|e| e.id == after_column.id).unwrap_or(false))
+                        .and_then(|(b, _)| b);
+
+                    let is_unique_kept = before_column
+                        .map(|before_column| {
+                            before_column.is_unique
+                                && sql::unique_name(before_entity, before_column)
+                                    == sql::unique_name(after_entity, after_column)
+                        })
+                        .unwrap_or(false);
+
+                    if after_column.is_unique && !is_unique_kept {
+                        if dialect == Dialect::Sqlite {
+                            add_constraints.push(format!(
+                                "CREATE UNIQUE INDEX {} ON {table} ({});",
+                                sql::quote(&sql::unique_name(after_entity, after_column), dialect),
+                                sql::quote(&after_column.physical_name, dialect)
+                            ));
+                        } else {
+                            add_constraints.push(format!(
+                                "ALTER TABLE {table} ADD {};",
+                                sql::unique_clause(after_entity, after_column, dialect)
+                            ));
+                        }
+                    }
+
+                    let is_foreign_key_kept = before_column
+                        .map(|before_column| {
+                            is_same_foreign_key(
+                                (before_entity, before_column),
+                                (after_entity, after_column),
+                                after,
+                            )
+                        })
+                        .unwrap_or(false);
+
+                    if is_foreign_key_kept {
+                        continue;
+                    }
+
+                    if let Some(clause) =
+                        sql::foreign_key_clause(after_entity, after_column, after, dialect)
+                    {
+                        if dialect == Dialect::Sqlite {
+                            migration.warnings.push(format!(
+                                "{}.{}: SQLite는 FK 추가를 지원하지 않습니다. 테이블을 다시 만들어야 합니다.",
+                                after_entity.physical_name, after_column.physical_name
+                            ));
+                        } else {
+                            add_foreign_keys.push(format!("ALTER TABLE {table} ADD {clause};"));
+                        }
+                    }
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    if dialect == Dialect::Sqlite {
+        for statement in drop_foreign_keys
+            .drain(..)
+            .chain(drop_constraints.drain(..))
+        {
+            migration.warnings.push(format!(
+                "SQLite는 제약 조건 삭제를 지원하지 않습니다: {statement}"
+            ));
+        }
+    }
+
+    migration.statements = [
+        drop_foreign_keys,
+        drop_constraints,
+        drop_indexes,
+        drop_primary_keys,
+        drop_tables,
+        rename_tables,
+        create_tables,
+        alter_columns,
+        add_primary_keys,
+        add_constraints,
+        create_indexes,
+        add_foreign_keys,
+        comments,
+    ]
+    .concat();
+
+    migration
+}
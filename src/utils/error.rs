@@ -4,4 +4,8 @@ pub enum AllError {
     AWSError(String),
     #[error("resource not found")]
     NotFound,
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+    #[error("io error: {0}")]
+    IoError(String),
 }
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Entity, StandardTerm};
+
+// 표준 단어 사전을 이용한 물리명 생성
+
+#[derive(Debug, Clone, Default)]
+pub struct NameSuggestion {
+    pub physical_name: String,
+    pub matched_terms: Vec<String>,
+    pub unmatched_words: Vec<String>,
+}
+
+// 논리명을 가장 긴 단어부터 사전과 맞춰보고 물리 약어를 '_'로 이어 붙입니다.
+// 예) "사용자 번호", "사용자번호" -> USR_NO
+pub fn suggest_physical_name(logical_name: &str, terms: &[StandardTerm]) -> NameSuggestion {
+    let mut suggestion = NameSuggestion::default();
+    let mut parts: Vec<String> = vec![];
+
+    let mut sorted_terms = terms
+        .iter()
+        .filter(|e| !e.logical_name.trim().is_empty())
+        .collect::<Vec<_>>();
+    sorted_terms.sort_by_key(|e| std::cmp::Reverse(e.logical_name.chars().count()));
+
+    for word in logical_name.split(|c: char| c.is_whitespace() || c == '_') {
+        let chars = word.chars().collect::<Vec<_>>();
+        let mut position = 0;
+        let mut unmatched = String::new();
+
+        while position < chars.len() {
+            let rest = chars[position..].iter().collect::<String>().to_lowercase();
+
+            let term = sorted_terms
+                .iter()
+                .find(|e| rest.starts_with(&e.logical_name.trim().to_lowercase()));
+
+            match term {
+                Some(term) => {
+                    if !unmatched.is_empty() {
+                        parts.push(unmatched.clone());
+                        suggestion
+                            .unmatched_words
+                            .push(std::mem::take(&mut unmatched));
+                    }
+
+                    parts.push(term.physical_name.trim().to_owned());
+                    suggestion.matched_terms.push(term.logical_name.to_owned());
+                    position += term.logical_name.trim().chars().count();
+                }
+                None => {
+                    unmatched.push(chars[position]);
+                    position += 1;
+                }
+            }
+        }
+
+        if !unmatched.is_empty() {
+            parts.push(unmatched.clone());
+            suggestion.unmatched_words.push(unmatched);
+        }
+    }
+
+    suggestion.physical_name = parts.join("_");
+    suggestion
+}
+
+// 같은 팀 사전 안에서 논리명이 같거나(중복) 물리 약어가 같은(동음이의) 단어를 찾습니다.
+pub fn find_conflicts<'a>(term: &StandardTerm, terms: &'a [StandardTerm]) -> Vec<&'a StandardTerm> {
+    terms
+        .iter()
+        .filter(|e| e.id != term.id)
+        .filter(|e| {
+            is_same_name(&e.logical_name, &term.logical_name)
+                || is_same_name(&e.physical_name, &term.physical_name)
+        })
+        .collect()
+}
+
+// 사전에서 같은 단어로 보는 기준 (앞뒤 공백, 영문 대소문자 무시)
+pub fn is_same_name(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhysicalNameChange {
+    pub entity_id: String,
+    pub column_id: Option<String>,
+    pub logical_name: String,
+    pub before: String,
+    pub after: String,
+    pub unmatched_words: Vec<String>,
+}
+
+// 엔티티/컬럼의 물리명을 사전 기반으로 채웁니다. overwrite가 아니면 비어 있는 것만 바꿉니다.
+pub fn generate_physical_names(
+    entities: &mut [Entity],
+    terms: &[StandardTerm],
+    overwrite: bool,
+) -> Vec<PhysicalNameChange> {
+    let mut changes = vec![];
+
+    for entity in entities.iter_mut() {
+        let entity_id = entity.id.clone();
+
+        let mut targets = vec![(None, &entity.logical_name, &mut entity.physical_name)];
+        targets.extend(
+            entity
+                .columns
+                .iter_mut()
+                .map(|e| (Some(e.id.clone()), &e.logical_name, &mut e.physical_name)),
+        );
+
+        for (column_id, logical_name, physical_name) in targets {
+            if logical_name.trim().is_empty() || (!overwrite && !physical_name.trim().is_empty()) {
+                continue;
+            }
+
+            let suggestion = suggest_physical_name(logical_name, terms);
+
+            if suggestion.physical_name.is_empty() || suggestion.physical_name == *physical_name {
+                continue;
+            }
+
+            changes.push(PhysicalNameChange {
+                entity_id: entity_id.clone(),
+                column_id,
+                logical_name: logical_name.to_owned(),
+                before: std::mem::replace(physical_name, suggestion.physical_name.clone()),
+                after: suggestion.physical_name,
+                unmatched_words: suggestion.unmatched_words,
+            });
+        }
+    }
+
+    changes
+}
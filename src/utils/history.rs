@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Entity;
+
+use super::diff::{self, ColumnDiff};
+
+// 엔티티 이력 한 건의 변경 전/후 차이
+// 컬럼/인덱스 비교는 diff 모듈을 그대로 사용합니다. (다른 엔티티를 가리키는 FK 대상 변경은 비교하지 않습니다)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntityChanges {
+    pub fields: Vec<FieldChange>,
+    pub columns: Vec<ColumnDiff>,
+    pub indexes_added: Vec<String>,
+    pub indexes_removed: Vec<String>,
+}
+
+pub fn entity_changes(before: &Entity, after: &Entity) -> EntityChanges {
+    let mut changes = EntityChanges::default();
+
+    // 숫자/스타일 항목은 문자열로 바꿔 비교합니다. (값이 없으면 빈 문자열)
+    let size = |value: Option<f64>| value.map(|e| e.to_string()).unwrap_or_default();
+
+    let fields = [
+        (
+            "logical_name",
+            before.logical_name.clone(),
+            after.logical_name.clone(),
+        ),
+        (
+            "physical_name",
+            before.physical_name.clone(),
+            after.physical_name.clone(),
+        ),
+        ("comment", before.comment.clone(), after.comment.clone()),
+        ("x", before.x.to_string(), after.x.to_string()),
+        ("y", before.y.to_string(), after.y.to_string()),
+        ("width", size(before.width), size(after.width)),
+        ("height", size(before.height), size(after.height)),
+        (
+            "color",
+            before.color.clone().unwrap_or_default(),
+            after.color.clone().unwrap_or_default(),
+        ),
+        (
+            "z_index",
+            before.z_index.to_string(),
+            after.z_index.to_string(),
+        ),
+        (
+            "collapsed",
+            before.collapsed.to_string(),
+            after.collapsed.to_string(),
+        ),
+    ];
+
+    for (field, before, after) in fields {
+        if before != after {
+            changes.fields.push(FieldChange {
+                field: field.to_owned(),
+                before,
+                after,
+            });
+        }
+    }
+
+    let tables = diff::diff_entities(std::slice::from_ref(before), std::slice::from_ref(after));
+
+    if let Some(table) = tables.into_iter().next() {
+        changes.columns = table.columns;
+        changes.indexes_added = table.indexes_added;
+        changes.indexes_removed = table.indexes_removed;
+    }
+
+    changes
+}
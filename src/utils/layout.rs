@@ -0,0 +1,381 @@
+use crate::models::{Entity, Note};
+
+use super::{relation, svg};
+
+// 다이어그램 배치 관련 상수 (프론트엔드 렌더링 크기 기준)
+
+pub const ENTITY_WIDTH: f64 = 240.0;
+pub const ENTITY_HEADER_HEIGHT: f64 = 40.0;
+pub const COLUMN_HEIGHT: f64 = 24.0;
+
+const GAP: f64 = 60.0;
+const ENTITIES_PER_ROW: usize = 4;
+// 자동 배치에서 관계선이 지나갈 열 사이 간격
+const LAYER_GAP: f64 = 160.0;
+// 열 안의 순서를 다듬는 반복 횟수
+const ORDER_SWEEPS: usize = 4;
+
+// 크기를 지정하지 않은 엔티티는 컬럼 수에 맞춘 기본 크기로, 접힌 엔티티는 헤더 높이로 봅니다.
+pub fn entity_width(entity: &Entity) -> f64 {
+    entity.width.unwrap_or(ENTITY_WIDTH)
+}
+
+pub fn entity_height(entity: &Entity) -> f64 {
+    if entity.collapsed {
+        return ENTITY_HEADER_HEIGHT;
+    }
+
+    entity
+        .height
+        .unwrap_or(ENTITY_HEADER_HEIGHT + COLUMN_HEIGHT * entity.columns.len().max(1) as f64)
+}
+
+// 기존 엔티티들 아래쪽 빈 공간에 새 엔티티들을 격자 형태로 배치합니다.
+pub fn place_below(existing: &[Entity], new_entities: &mut [Entity]) {
+    let (left, bottom) = existing
+        .iter()
+        .map(|e| (e.x, e.y + entity_height(e)))
+        .fold(None, |acc: Option<(f64, f64)>, (x, y)| match acc {
+            None => Some((x, y)),
+            Some((left, bottom)) => Some((left.min(x), bottom.max(y))),
+        })
+        .map(|(left, bottom)| (left, bottom + GAP))
+        .unwrap_or((0.0, 0.0));
+
+    let mut y = bottom;
+
+    for row in new_entities.chunks_mut(ENTITIES_PER_ROW) {
+        let mut x = left;
+        let mut row_height: f64 = 0.0;
+
+        for entity in row.iter_mut() {
+            entity.x = x;
+            entity.y = y;
+
+            x += entity_width(entity) + GAP;
+            row_height = row_height.max(entity_height(entity));
+        }
+
+        y += row_height + GAP;
+    }
+}
+
+// 자동 배치 결과 좌표 한 건
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub id: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AutoLayout {
+    pub entities: Vec<Position>,
+    pub notes: Vec<Position>,
+}
+
+// 관계를 따라 부모는 왼쪽 열, 자식은 오른쪽 열에 두는 계층형 배치입니다.
+// 관계로 이어진 묶음마다 따로 배치해 위에서 아래로 쌓고, 관계가 없는 엔티티는 그 아래 격자로,
+// 노트는 전체 오른쪽에 세로로 둡니다. 좌표는 크기 어림값 기준이라 서로 겹치지 않습니다.
+// 같은 입력이면 항상 같은 결과가 나오도록 엔티티는 이름(id) 순으로 다룹니다.
+pub fn auto_layout(entities: &[Entity], notes: &[Note]) -> AutoLayout {
+    let mut order = (0..entities.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        (&entities[a].physical_name, &entities[a].id)
+            .cmp(&(&entities[b].physical_name, &entities[b].id))
+    });
+
+    let refs = order.iter().map(|&i| &entities[i]).collect::<Vec<_>>();
+
+    // 부모 -> 자식 (refs 인덱스). 자기 참조와 중복 관계는 한 번만 봅니다.
+    let mut edges = vec![];
+
+    for relation in relation::relations(&refs) {
+        let parent = refs.iter().position(|e| e.id == relation.parent.id);
+        let child = refs.iter().position(|e| e.id == relation.child.id);
+
+        if let (Some(parent), Some(child)) = (parent, child) {
+            if parent != child && !edges.contains(&(parent, child)) {
+                edges.push((parent, child));
+            }
+        }
+    }
+
+    let mut points = vec![(0.0, 0.0); refs.len()];
+    let mut singles = vec![];
+    let mut top = 0.0;
+    let mut right: f64 = 0.0;
+
+    for component in components(refs.len(), &edges) {
+        if component.len() == 1 {
+            singles.push(component[0]);
+            continue;
+        }
+
+        let (width, height) = place_component(&refs, &component, &edges, top, &mut points);
+
+        top += height + LAYER_GAP;
+        right = right.max(width);
+    }
+
+    // 관계 없는 엔티티는 위 묶음들의 폭만큼 한 줄에 채웁니다.
+    let per_row = ENTITIES_PER_ROW.max(((right + GAP) / (ENTITY_WIDTH + GAP)) as usize);
+
+    for row in singles.chunks(per_row) {
+        let mut x = 0.0;
+        let mut row_height: f64 = 0.0;
+
+        for &index in row.iter() {
+            points[index] = (x, top);
+            x += entity_width(refs[index]) + GAP;
+            row_height = row_height.max(entity_height(refs[index]));
+        }
+
+        right = right.max(x - GAP);
+        top += row_height + GAP;
+    }
+
+    let mut layout = AutoLayout::default();
+
+    for (index, entity) in refs.iter().enumerate() {
+        layout.entities.push(Position {
+            id: entity.id.clone(),
+            x: points[index].0.round(),
+            y: points[index].1.round(),
+        });
+    }
+
+    let mut notes = notes.iter().collect::<Vec<_>>();
+    notes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let left = if refs.is_empty() {
+        0.0
+    } else {
+        right + LAYER_GAP
+    };
+    let mut y: f64 = 0.0;
+
+    for note in notes {
+        layout.notes.push(Position {
+            id: note.id.clone(),
+            x: left.round(),
+            y: y.round(),
+        });
+
+        y += svg::note_height(note) + GAP;
+    }
+
+    layout
+}
+
+// 관계로 이어진 엔티티 묶음 (방향 무시)
+fn components(count: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut group = (0..count).collect::<Vec<_>>();
+
+    fn find(group: &mut [usize], index: usize) -> usize {
+        let mut root = index;
+        while group[root] != root {
+            root = group[root];
+        }
+        group[index] = root;
+        root
+    }
+
+    for &(a, b) in edges.iter() {
+        let (a, b) = (find(&mut group, a), find(&mut group, b));
+        group[a.max(b)] = a.min(b);
+    }
+
+    let mut components: Vec<Vec<usize>> = vec![];
+    let mut roots = vec![];
+
+    for index in 0..count {
+        let root = find(&mut group, index);
+
+        match roots.iter().position(|&e| e == root) {
+            Some(position) => components[position].push(index),
+            None => {
+                roots.push(root);
+                components.push(vec![index]);
+            }
+        }
+    }
+
+    components
+}
+
+// 묶음 하나를 열 단위로 배치하고 (폭, 높이)를 돌려줍니다.
+fn place_component(
+    entities: &[&Entity],
+    component: &[usize],
+    edges: &[(usize, usize)],
+    top: f64,
+    points: &mut [(f64, f64)],
+) -> (f64, f64) {
+    let edges = acyclic_edges(component, edges);
+
+    // 가장 긴 경로 기준 열 번호 (부모보다 항상 오른쪽)
+    let mut layer = vec![0; entities.len()];
+
+    for _ in 0..component.len() {
+        let mut changed = false;
+
+        for &(parent, child) in edges.iter() {
+            if layer[child] < layer[parent] + 1 {
+                layer[child] = layer[parent] + 1;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let layer_count = component
+        .iter()
+        .map(|&e| layer[e])
+        .max()
+        .unwrap_or_default()
+        + 1;
+    let mut layers = vec![vec![]; layer_count];
+
+    for &index in component.iter() {
+        layers[layer[index]].push(index);
+    }
+
+    // 이웃 열에서 연결된 엔티티의 평균 순번으로 정렬해 관계선 교차를 줄입니다.
+    let barycenter =
+        |layers: &[Vec<usize>], index: usize, neighbor_layer: usize, current: usize| {
+            let neighbors = edges
+                .iter()
+                .filter_map(|&(a, b)| {
+                    if a == index {
+                        Some(b)
+                    } else if b == index {
+                        Some(a)
+                    } else {
+                        None
+                    }
+                })
+                .filter_map(|e| layers[neighbor_layer].iter().position(|&n| n == e))
+                .collect::<Vec<_>>();
+
+            if neighbors.is_empty() {
+                current as f64
+            } else {
+                neighbors.iter().sum::<usize>() as f64 / neighbors.len() as f64
+            }
+        };
+
+    for _ in 0..ORDER_SWEEPS {
+        let down = (1..layer_count).map(|e| (e, e - 1)).collect::<Vec<_>>();
+        let up = (0..layer_count.saturating_sub(1))
+            .rev()
+            .map(|e| (e, e + 1))
+            .collect::<Vec<_>>();
+
+        for (current, neighbor) in down.into_iter().chain(up) {
+            let mut keyed = layers[current]
+                .iter()
+                .enumerate()
+                .map(|(position, &index)| (barycenter(&layers, index, neighbor, position), index))
+                .collect::<Vec<_>>();
+
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            layers[current] = keyed.into_iter().map(|(_, index)| index).collect();
+        }
+    }
+
+    let heights = layers
+        .iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .map(|&e| entity_height(entities[e]))
+                .sum::<f64>()
+                + GAP * layer.len().saturating_sub(1) as f64
+        })
+        .collect::<Vec<_>>();
+    let height = heights.iter().copied().fold(0.0, f64::max);
+
+    // 열마다 세로 가운데 정렬, 열 폭은 가장 넓은 엔티티 기준
+    let mut x = 0.0;
+
+    for (column, layer) in layers.iter().enumerate() {
+        let mut y = top + (height - heights[column]) / 2.0;
+
+        for &index in layer.iter() {
+            points[index] = (x, y);
+            y += entity_height(entities[index]) + GAP;
+        }
+
+        let width = layer
+            .iter()
+            .map(|&e| entity_width(entities[e]))
+            .fold(0.0, f64::max);
+
+        x += width + LAYER_GAP;
+    }
+
+    let width = x - LAYER_GAP;
+
+    (width, height)
+}
+
+// 순환 참조는 깊이 우선 탐색에서 되돌아가는 관계를 빼서 끊습니다.
+fn acyclic_edges(component: &[usize], edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        Visiting,
+        Done,
+    }
+
+    fn visit(
+        index: usize,
+        edges: &[(usize, usize)],
+        state: &mut [State],
+        kept: &mut Vec<(usize, usize)>,
+    ) {
+        state[index] = State::Visiting;
+
+        for &(parent, child) in edges.iter().filter(|e| e.0 == index) {
+            match state[child] {
+                State::New => {
+                    kept.push((parent, child));
+                    visit(child, edges, state, kept);
+                }
+                State::Done => kept.push((parent, child)),
+                State::Visiting => {}
+            }
+        }
+
+        state[index] = State::Done;
+    }
+
+    let count = edges
+        .iter()
+        .map(|e| e.0.max(e.1) + 1)
+        .chain(component.iter().map(|e| e + 1))
+        .max()
+        .unwrap_or_default();
+    let mut state = vec![State::New; count];
+    let mut kept = vec![];
+
+    // 부모가 없는 엔티티부터 시작해야 원래 방향이 최대한 유지됩니다.
+    let mut starts = component
+        .iter()
+        .copied()
+        .filter(|&e| !edges.iter().any(|edge| edge.1 == e))
+        .collect::<Vec<_>>();
+    starts.extend(component.iter().copied());
+
+    for index in starts {
+        if state[index] == State::New {
+            visit(index, edges, &mut state, &mut kept);
+        }
+    }
+
+    kept
+}
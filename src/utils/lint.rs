@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Column, Entity};
+
+use super::Dialect;
+
+// 모델 검증 규칙 엔진
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintRule {
+    DuplicateTableName,
+    DuplicateColumnName,
+    MissingPrimaryKey,
+    EmptyPhysicalName,
+    ReservedWord,
+    NullablePrimaryKey,
+    BrokenRelationship,
+    RelationshipTypeMismatch,
+    NamingConvention,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    pub severity: LintSeverity,
+    pub entity_id: String,
+    pub column_id: Option<String>,
+    pub message: String,
+}
+
+impl LintFinding {
+    pub fn new(
+        rule: LintRule,
+        severity: LintSeverity,
+        entity: &Entity,
+        column: Option<&Column>,
+        message: String,
+    ) -> Self {
+        Self {
+            rule,
+            severity,
+            entity_id: entity.id.to_owned(),
+            column_id: column.map(|e| e.id.to_owned()),
+            message,
+        }
+    }
+}
+
+pub fn lint_entities(entities: &[Entity], dialect: Dialect) -> Vec<LintFinding> {
+    let mut findings = vec![];
+
+    let mut table_names: HashMap<String, &Entity> = HashMap::new();
+
+    for entity in entities {
+        let table_name = entity.physical_name.trim();
+
+        if table_name.is_empty() {
+            findings.push(LintFinding::new(
+                LintRule::EmptyPhysicalName,
+                LintSeverity::Error,
+                entity,
+                None,
+                format!("{}: 테이블 물리명이 비어 있습니다.", entity.logical_name),
+            ));
+        } else {
+            match table_names.get(&table_name.to_lowercase()) {
+                Some(other) => findings.push(LintFinding::new(
+                    LintRule::DuplicateTableName,
+                    LintSeverity::Error,
+                    entity,
+                    None,
+                    format!(
+                        "{table_name}: 테이블 물리명이 다른 엔티티({})와 중복됩니다.",
+                        other.logical_name
+                    ),
+                )),
+                None => {
+                    table_names.insert(table_name.to_lowercase(), entity);
+                }
+            }
+
+            if dialect.is_reserved_word(table_name) {
+                findings.push(LintFinding::new(
+                    LintRule::ReservedWord,
+                    LintSeverity::Warning,
+                    entity,
+                    None,
+                    format!("{table_name}: {dialect:?} 예약어를 테이블명으로 사용했습니다."),
+                ));
+            }
+        }
+
+        if !entity.columns.iter().any(|e| e.is_primary_key) {
+            findings.push(LintFinding::new(
+                LintRule::MissingPrimaryKey,
+                LintSeverity::Warning,
+                entity,
+                None,
+                format!("{}: 기본 키가 없습니다.", entity.physical_name),
+            ));
+        }
+
+        lint_columns(entity, entities, dialect, &mut findings);
+    }
+
+    findings
+}
+
+fn lint_columns(
+    entity: &Entity,
+    entities: &[Entity],
+    dialect: Dialect,
+    findings: &mut Vec<LintFinding>,
+) {
+    let mut column_names: Vec<String> = vec![];
+
+    for column in entity.columns.iter() {
+        let column_name = column.physical_name.trim();
+        let display_name = format!("{}.{}", entity.physical_name, column_name);
+
+        if column_name.is_empty() {
+            findings.push(LintFinding::new(
+                LintRule::EmptyPhysicalName,
+                LintSeverity::Error,
+                entity,
+                Some(column),
+                format!(
+                    "{}.{}: 컬럼 물리명이 비어 있습니다.",
+                    entity.physical_name, column.logical_name
+                ),
+            ));
+        } else {
+            if column_names.contains(&column_name.to_lowercase()) {
+                findings.push(LintFinding::new(
+                    LintRule::DuplicateColumnName,
+                    LintSeverity::Error,
+                    entity,
+                    Some(column),
+                    format!("{display_name}: 컬럼 물리명이 중복됩니다."),
+                ));
+            } else {
+                column_names.push(column_name.to_lowercase());
+            }
+
+            if dialect.is_reserved_word(column_name) {
+                findings.push(LintFinding::new(
+                    LintRule::ReservedWord,
+                    LintSeverity::Warning,
+                    entity,
+                    Some(column),
+                    format!("{display_name}: {dialect:?} 예약어를 컬럼명으로 사용했습니다."),
+                ));
+            }
+        }
+
+        if column.is_primary_key && column.nullable {
+            findings.push(LintFinding::new(
+                LintRule::NullablePrimaryKey,
+                LintSeverity::Error,
+                entity,
+                Some(column),
+                format!("{display_name}: 기본 키 컬럼이 NULL을 허용합니다."),
+            ));
+        }
+
+        let foreign_key = match column.foreign_key.as_ref() {
+            Some(foreign_key) => foreign_key,
+            None => continue,
+        };
+
+        let referenced_column = entities
+            .iter()
+            .find(|e| e.id == foreign_key.entity_id)
+            .and_then(|target| {
+                target
+                    .columns
+                    .iter()
+                    .find(|e| e.id == foreign_key.column_id)
+                    .map(|column| (target, column))
+            });
+
+        match referenced_column {
+            None => findings.push(LintFinding::new(
+                LintRule::BrokenRelationship,
+                LintSeverity::Error,
+                entity,
+                Some(column),
+                format!("{display_name}: 참조 대상 엔티티 또는 컬럼이 없습니다."),
+            )),
+            Some((target, referenced_column)) => {
+                if !is_same_type(column, referenced_column) {
+                    findings.push(LintFinding::new(
+                        LintRule::RelationshipTypeMismatch,
+                        LintSeverity::Warning,
+                        entity,
+                        Some(column),
+                        format!(
+                            "{display_name}({}) 타입이 참조 컬럼 {}.{}({})와 다릅니다.",
+                            column.full_type(),
+                            target.physical_name,
+                            referenced_column.physical_name,
+                            referenced_column.full_type()
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+// 같은 타입의 다른 표기(INTEGER/INT, SERIAL/INT 등)는 같은 것으로 봅니다.
+fn normalize_type(data_type: &str) -> String {
+    let upper = data_type.trim().to_uppercase();
+
+    let (base, rest) = match upper.find('(') {
+        Some(position) => (upper[..position].trim(), &upper[position..]),
+        None => (upper.as_str(), ""),
+    };
+
+    let base = match base {
+        "INTEGER" | "INT4" | "SERIAL" | "SERIAL4" => "INT",
+        "INT8" | "BIGSERIAL" | "SERIAL8" => "BIGINT",
+        "INT2" | "SMALLSERIAL" | "SERIAL2" => "SMALLINT",
+        "BOOL" => "BOOLEAN",
+        "CHARACTER VARYING" => "VARCHAR",
+        "CHARACTER" => "CHAR",
+        "NUMERIC" | "DEC" => "DECIMAL",
+        base => base,
+    };
+
+    format!("{base}{}", rest.replace(' ', ""))
+}
+
+fn is_same_type(column: &Column, referenced_column: &Column) -> bool {
+    normalize_type(&column.full_type()) == normalize_type(&referenced_column.full_type())
+        && column.is_unsigned == referenced_column.is_unsigned
+}
@@ -5,6 +5,7 @@ pub mod hash;
 pub use hash::*;
 
 pub mod jwt;
+#[allow(unused_imports)]
 pub use jwt::*;
 
 pub mod http;
@@ -14,3 +15,28 @@ pub use error::*;
 
 pub mod email;
 pub use email::*;
+
+pub mod dialect;
+pub use dialect::*;
+
+pub mod batch_write;
+pub mod bundle;
+pub mod codegen;
+pub mod column_catalog;
+pub mod csv;
+pub mod ddl;
+pub mod dictionary;
+pub mod diff;
+pub mod glossary;
+pub mod history;
+pub mod layout;
+pub mod lint;
+pub mod naming;
+pub mod relation;
+pub mod remap;
+pub mod schema_file;
+pub mod schema_import;
+pub mod sql;
+pub mod sqlite_import;
+pub mod svg;
+pub mod text_diagram;
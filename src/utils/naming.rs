@@ -0,0 +1,356 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    CaseStyle, Entity, IdentifierRule, NamingConfig, NamingEnforcement, Plurality,
+};
+
+use super::lint::{LintFinding, LintRule, LintSeverity};
+
+// 팀/프로젝트 명명 규칙 검사 및 자동 수정
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFix {
+    pub entity_id: String,
+    pub column_id: Option<String>,
+    pub before: String,
+    pub after: String,
+}
+
+// snake_case, camelCase, 공백 등 어떤 표기든 소문자 단어 목록으로 나눕니다.
+pub fn split_words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let chars = name.chars().collect::<Vec<_>>();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        // userId -> user, id / HTTPStatus -> http, status
+        let is_boundary = c.is_uppercase()
+            && i > 0
+            && (chars[i - 1].is_lowercase()
+                || chars[i - 1].is_ascii_digit()
+                || (chars[i - 1].is_uppercase()
+                    && chars.get(i + 1).map(|e| e.is_lowercase()).unwrap_or(false)));
+
+        if is_boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        word.extend(c.to_lowercase());
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+pub fn apply_case(words: &[String], case_style: CaseStyle) -> String {
+    match case_style {
+        CaseStyle::Snake => words.join("_"),
+        CaseStyle::UpperSnake => words.join("_").to_uppercase(),
+        CaseStyle::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, e)| if i == 0 { e.to_owned() } else { capitalize(e) })
+            .collect(),
+        CaseStyle::Pascal => words.iter().map(|e| capitalize(e)).collect(),
+    }
+}
+
+// 영어 단수/복수 변환 (테이블명 규칙 용도의 단순한 규칙)
+fn is_singular_exception(word: &str) -> bool {
+    word.ends_with("ss") || word.ends_with("us") || word.ends_with("is")
+}
+
+pub fn singularize(word: &str) -> String {
+    let lower = word.to_lowercase();
+
+    if is_singular_exception(&lower) {
+        word.to_owned()
+    } else if lower.ends_with("ies") && word.len() > 3 {
+        format!("{}{}", &word[..word.len() - 3], matching_case(word, "y"))
+    } else if ["ses", "xes", "zes", "ches", "shes"]
+        .iter()
+        .any(|e| lower.ends_with(e))
+    {
+        word[..word.len() - 2].to_owned()
+    } else if lower.ends_with('s') && word.len() > 1 {
+        word[..word.len() - 1].to_owned()
+    } else {
+        word.to_owned()
+    }
+}
+
+pub fn pluralize(word: &str) -> String {
+    let singular = singularize(word);
+    let lower = singular.to_lowercase();
+
+    let is_consonant_y = lower.ends_with('y')
+        && !lower
+            .chars()
+            .rev()
+            .nth(1)
+            .map(|e| "aeiou".contains(e))
+            .unwrap_or(true);
+
+    if is_consonant_y {
+        format!(
+            "{}{}",
+            &singular[..singular.len() - 1],
+            matching_case(word, "ies")
+        )
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|e| lower.ends_with(e))
+    {
+        format!("{singular}{}", matching_case(word, "es"))
+    } else {
+        format!("{singular}{}", matching_case(word, "s"))
+    }
+}
+
+fn matching_case(word: &str, tail: &str) -> String {
+    if word.chars().any(|e| e.is_lowercase()) {
+        tail.to_owned()
+    } else {
+        tail.to_uppercase()
+    }
+}
+
+fn strip_affixes<'a>(name: &'a str, rule: &IdentifierRule) -> &'a str {
+    let mut core = name;
+
+    if !rule.prefix.is_empty()
+        && core.len() > rule.prefix.len()
+        && core.is_char_boundary(rule.prefix.len())
+        && core[..rule.prefix.len()].eq_ignore_ascii_case(&rule.prefix)
+    {
+        core = &core[rule.prefix.len()..];
+    }
+
+    if !rule.suffix.is_empty() && core.len() > rule.suffix.len() {
+        let position = core.len() - rule.suffix.len();
+        if core.is_char_boundary(position) && core[position..].eq_ignore_ascii_case(&rule.suffix) {
+            core = &core[..position];
+        }
+    }
+
+    core
+}
+
+// 마지막 단어에만 단수/복수 규칙을 적용합니다. (user_orders -> user_order)
+fn apply_plurality(core: &str, plurality: Plurality) -> String {
+    let word_start = core
+        .char_indices()
+        .rev()
+        .find(|(i, c)| {
+            *c == '_'
+                || (c.is_uppercase()
+                    && *i > 0
+                    && core[..*i]
+                        .chars()
+                        .last()
+                        .map(|e| e.is_lowercase())
+                        .unwrap_or(false))
+        })
+        .map(|(i, c)| if c == '_' { i + 1 } else { i })
+        .unwrap_or(0);
+
+    let (head, last) = core.split_at(word_start);
+
+    let last = match plurality {
+        Plurality::Any => last.to_owned(),
+        Plurality::Singular => singularize(last),
+        Plurality::Plural => pluralize(last),
+    };
+
+    format!("{head}{last}")
+}
+
+fn max_length(config: &NamingConfig) -> Option<usize> {
+    config
+        .max_length
+        .or_else(|| config.dialect.max_identifier_length())
+}
+
+// 규칙 위반 사유 목록 (비어 있으면 통과)
+pub fn check_name(name: &str, config: &NamingConfig, is_table: bool) -> Vec<String> {
+    let mut messages = vec![];
+    let rule = if is_table {
+        &config.table
+    } else {
+        &config.column
+    };
+
+    if name.trim().is_empty() {
+        return messages;
+    }
+
+    if !rule.prefix.is_empty() && !name.starts_with(&rule.prefix) {
+        messages.push(format!("'{}' 접두사로 시작해야 합니다.", rule.prefix));
+    }
+
+    if !rule.suffix.is_empty() && !name.ends_with(&rule.suffix) {
+        messages.push(format!("'{}' 접미사로 끝나야 합니다.", rule.suffix));
+    }
+
+    let core = strip_affixes(name, rule);
+
+    if let Some(case_style) = rule.case_style {
+        if apply_case(&split_words(core), case_style) != core {
+            let example = match case_style {
+                CaseStyle::Snake => "snake_case",
+                CaseStyle::UpperSnake => "UPPER_SNAKE_CASE",
+                CaseStyle::Camel => "camelCase",
+                CaseStyle::Pascal => "PascalCase",
+            };
+            messages.push(format!("{example} 표기법을 따라야 합니다."));
+        }
+    }
+
+    if is_table && apply_plurality(core, config.table_plurality) != core {
+        messages.push(format!(
+            "테이블명은 {}형이어야 합니다.",
+            match config.table_plurality {
+                Plurality::Plural => "복수",
+                _ => "단수",
+            }
+        ));
+    }
+
+    if let Some(max_length) = max_length(config) {
+        if name.chars().count() > max_length {
+            messages.push(format!("최대 길이 {max_length}자를 넘습니다."));
+        }
+    }
+
+    let words = split_words(name);
+    for forbidden_word in config.forbidden_words.iter() {
+        let forbidden_word = forbidden_word.trim().to_lowercase();
+
+        if !forbidden_word.is_empty()
+            && (name.to_lowercase() == forbidden_word || words.contains(&forbidden_word))
+        {
+            messages.push(format!("금지어 '{forbidden_word}'를 포함합니다."));
+        }
+    }
+
+    messages
+}
+
+// 규칙에 맞게 이름을 고칩니다. 금지어는 자동으로 고칠 수 없으므로 그대로 둡니다.
+pub fn fix_name(name: &str, config: &NamingConfig, is_table: bool) -> String {
+    let rule = if is_table {
+        &config.table
+    } else {
+        &config.column
+    };
+
+    if name.trim().is_empty() {
+        return name.to_owned();
+    }
+
+    let mut core = strip_affixes(name.trim(), rule).to_owned();
+
+    if let Some(case_style) = rule.case_style {
+        core = apply_case(&split_words(&core), case_style);
+    }
+
+    if is_table {
+        core = apply_plurality(&core, config.table_plurality);
+    }
+
+    let mut fixed = format!("{}{core}{}", rule.prefix, rule.suffix);
+
+    if let Some(max_length) = max_length(config) {
+        if fixed.chars().count() > max_length {
+            fixed = fixed.chars().take(max_length).collect::<String>();
+            fixed = fixed.trim_end_matches('_').to_owned();
+        }
+    }
+
+    fixed
+}
+
+pub fn check_entity(entity: &Entity, config: &NamingConfig) -> Vec<LintFinding> {
+    let severity = match config.enforcement {
+        NamingEnforcement::Warning => LintSeverity::Warning,
+        NamingEnforcement::Error => LintSeverity::Error,
+    };
+
+    let mut findings = vec![];
+
+    for message in check_name(&entity.physical_name, config, true) {
+        findings.push(LintFinding::new(
+            LintRule::NamingConvention,
+            severity,
+            entity,
+            None,
+            format!("{}: {message}", entity.physical_name),
+        ));
+    }
+
+    for column in entity.columns.iter() {
+        for message in check_name(&column.physical_name, config, false) {
+            findings.push(LintFinding::new(
+                LintRule::NamingConvention,
+                severity,
+                entity,
+                Some(column),
+                format!(
+                    "{}.{}: {message}",
+                    entity.physical_name, column.physical_name
+                ),
+            ));
+        }
+    }
+
+    findings
+}
+
+pub fn fix_entities(entities: &mut [Entity], config: &NamingConfig) -> Vec<NameFix> {
+    let mut fixes = vec![];
+
+    for entity in entities.iter_mut() {
+        let entity_id = entity.id.clone();
+
+        let mut targets = vec![(None, &mut entity.physical_name, true)];
+        targets.extend(
+            entity
+                .columns
+                .iter_mut()
+                .map(|e| (Some(e.id.clone()), &mut e.physical_name, false)),
+        );
+
+        for (column_id, physical_name, is_table) in targets {
+            let fixed = fix_name(physical_name, config, is_table);
+
+            if fixed != *physical_name {
+                fixes.push(NameFix {
+                    entity_id: entity_id.clone(),
+                    column_id,
+                    before: std::mem::replace(physical_name, fixed.clone()),
+                    after: fixed,
+                });
+            }
+        }
+    }
+
+    fixes
+}
@@ -0,0 +1,65 @@
+use crate::models::{Column, Entity, ForeignKey};
+
+use super::sql;
+
+// 다이어그램 출력(텍스트 ERD, SVG)에서 쓰는 엔티티 간 관계
+// 대상 엔티티/컬럼을 찾을 수 없는 FK는 관계로 보지 않습니다.
+
+// 관계 한 건 (자식 엔티티의 FK 컬럼 -> 부모 엔티티의 컬럼)
+pub struct Relation<'a> {
+    pub parent: &'a Entity,
+    pub parent_column: &'a Column,
+    pub child: &'a Entity,
+    pub child_column: &'a Column,
+}
+
+impl Relation<'_> {
+    // 부모가 없어도 되는 관계 (FK 컬럼이 NULL 허용)
+    pub fn is_optional(&self) -> bool {
+        self.child_column.nullable
+    }
+
+    // 자식 쪽이 최대 1건인 관계 (FK 컬럼이 유니크이거나 단독 PK)
+    pub fn is_one_to_one(&self) -> bool {
+        self.child_column.is_unique
+            || (self.child_column.is_primary_key && sql::primary_key_columns(self.child).len() == 1)
+    }
+
+    // FK가 자식의 PK에 포함되면 식별 관계입니다.
+    pub fn is_identifying(&self) -> bool {
+        self.child_column.is_primary_key
+    }
+}
+
+pub fn relations<'a>(entities: &[&'a Entity]) -> Vec<Relation<'a>> {
+    let mut relations = vec![];
+
+    for child in entities.iter() {
+        for child_column in child.columns.iter() {
+            let Some(ForeignKey {
+                entity_id,
+                column_id,
+            }) = child_column.foreign_key.as_ref()
+            else {
+                continue;
+            };
+
+            let Some(parent) = entities.iter().find(|e| &e.id == entity_id) else {
+                continue;
+            };
+
+            let Some(parent_column) = parent.columns.iter().find(|e| &e.id == column_id) else {
+                continue;
+            };
+
+            relations.push(Relation {
+                parent,
+                parent_column,
+                child,
+                child_column,
+            });
+        }
+    }
+
+    relations
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::models::{Diagram, Domain, Entity, Note};
+
+use super::generate_uuid;
+
+// 엔티티/노트를 다른 프로젝트로 복사할 때 id를 새로 발급하고 관계(FK)가 새 id를 가리키도록 바꿉니다.
+// 컬럼/인덱스 id는 엔티티 안에서만 쓰이므로 그대로 둡니다.
+// 복사 대상에 없는 엔티티를 가리키는 FK는 끊습니다.
+pub fn copy_to_project(
+    entities: &[Entity],
+    notes: &[Note],
+    project_id: &str,
+) -> (Vec<Entity>, Vec<Note>) {
+    let entity_ids = entities
+        .iter()
+        .map(|e| (e.id.clone(), generate_uuid()))
+        .collect::<HashMap<_, _>>();
+
+    let entities = entities
+        .iter()
+        .map(|entity| {
+            let mut entity = entity.clone();
+            entity.id = entity_ids[&entity.id].clone();
+            entity.project_id = project_id.to_owned();
+
+            for column in entity.columns.iter_mut() {
+                column.foreign_key = column.foreign_key.take().and_then(|mut foreign_key| {
+                    foreign_key.entity_id = entity_ids.get(&foreign_key.entity_id)?.clone();
+                    Some(foreign_key)
+                });
+            }
+
+            entity
+        })
+        .collect();
+
+    let notes = notes
+        .iter()
+        .map(|note| Note {
+            id: generate_uuid(),
+            project_id: project_id.to_owned(),
+            ..note.clone()
+        })
+        .collect();
+
+    (entities, notes)
+}
+
+// 엔티티의 프로젝트에서 쓸 수 없는 도메인 연결은 끊습니다.
+// 팀 공용 도메인과 그 프로젝트 전용 도메인만 남기고, 삭제된 도메인이나 다른 프로젝트/팀/인스턴스의 도메인은 끊습니다.
+pub fn detach_foreign_domains(entities: &mut [Entity], team_domains: &[Domain]) {
+    for entity in entities.iter_mut() {
+        let project_id = entity.project_id.clone();
+
+        for column in entity.columns.iter_mut() {
+            let is_available = team_domains.iter().any(|e| {
+                Some(&e.id) == column.domain_id.as_ref()
+                    && (e.project_id.is_none() || e.project_id.as_ref() == Some(&project_id))
+            });
+
+            if !is_available {
+                column.domain_id = None;
+            }
+        }
+    }
+}
+
+// copy_to_project로 복사한 엔티티(같은 순서)에 맞춰 다이어그램 항목의 엔티티 id를 바꿉니다.
+// 기본 다이어그램은 새 프로젝트의 기본 다이어그램 id를 씁니다.
+pub fn copy_diagrams(
+    diagrams: &[Diagram],
+    old_entities: &[Entity],
+    new_entities: &[Entity],
+    project_id: &str,
+) -> Vec<Diagram> {
+    let entity_ids = old_entities
+        .iter()
+        .zip(new_entities.iter())
+        .map(|(old, new)| (old.id.clone(), new.id.clone()))
+        .collect::<HashMap<_, _>>();
+
+    diagrams
+        .iter()
+        .map(|diagram| {
+            let mut diagram = diagram.clone();
+            diagram.id = if diagram.is_default {
+                Diagram::default_id(project_id)
+            } else {
+                generate_uuid()
+            };
+            diagram.project_id = project_id.to_owned();
+            diagram.items = diagram
+                .items
+                .into_iter()
+                .filter_map(|mut item| {
+                    item.entity_id = entity_ids.get(&item.entity_id)?.clone();
+                    Some(item)
+                })
+                .collect();
+
+            diagram
+        })
+        .collect()
+}
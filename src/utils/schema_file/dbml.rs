@@ -0,0 +1,587 @@
+use crate::{
+    models::{IndexType, SortOrder},
+    utils::{
+        schema_import::{SchemaColumn, SchemaForeignKey, SchemaIndex, SchemaTable},
+        sql::string_literal,
+        AllError,
+    },
+};
+
+use super::{
+    lexer::{render, split_top_level, tokenize, trim_newlines, Cursor, Token},
+    set_enum_type, SchemaFile,
+};
+
+// dbdiagram.io DBML 파서
+// Table/Enum/Ref/Project 블록을 읽고 TableGroup, 스티키 Note, Records 같은 나머지 블록은 건너뜁니다.
+// 컬럼은 DBML 기본값대로 NULL을 허용하고, not null 설정이 있을 때만 막습니다.
+
+// 관계 한쪽 끝 (테이블, 컬럼들)
+type Endpoint = (String, Vec<String>);
+
+struct Reference {
+    left: Endpoint,
+    // '>' 다대일, '<' 일대다, '-' 일대일, '*' 다대다(<>)
+    kind: char,
+    right: Endpoint,
+}
+
+struct Parser {
+    file: SchemaFile,
+    // (별칭, 테이블 이름)
+    aliases: Vec<(String, String)>,
+    enums: Vec<(String, Vec<String>)>,
+    // Enum은 테이블 뒤에 정의할 수도 있으므로 컬럼 타입 이름을 모아 두었다가 마지막에 맞춰봅니다.
+    // (테이블 순번, 컬럼 순번, 타입 이름)
+    column_types: Vec<(usize, usize, String)>,
+    references: Vec<Reference>,
+}
+
+fn invalid(message: &str) -> AllError {
+    AllError::InvalidInput(format!("DBML을 해석할 수 없습니다: {message}"))
+}
+
+// schema.table처럼 스키마가 붙은 이름은 마지막 이름만 씁니다.
+fn qualified_name(cursor: &mut Cursor) -> Option<String> {
+    let mut name = cursor.next()?.identifier()?;
+
+    while cursor.peek().is_some_and(|e| e.is_symbol('.')) {
+        cursor.next();
+        name = cursor.next()?.identifier()?;
+    }
+
+    Some(name)
+}
+
+// [key: value, key2] 설정 목록 -> (소문자 키, 값 토큰)
+fn settings(tokens: &[Token]) -> Vec<(String, &[Token])> {
+    split_top_level(tokens, |e| e.is_symbol(','))
+        .into_iter()
+        .map(|part| {
+            let colon = part.iter().position(|e| e.is_symbol(':'));
+            let (key, value) = match colon {
+                Some(colon) => (&part[..colon], &part[colon + 1..]),
+                None => (part, &[][..]),
+            };
+
+            let key = key
+                .iter()
+                .filter_map(|e| e.identifier())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase();
+
+            (key, value)
+        })
+        .collect()
+}
+
+fn text_value(tokens: &[Token]) -> Option<String> {
+    match tokens {
+        [token] => token.text(),
+        _ => None,
+    }
+}
+
+fn default_value(tokens: &[Token]) -> Option<String> {
+    match tokens {
+        [Token::Str(text)] | [Token::Quoted(text)] => Some(string_literal(text)),
+        [Token::Expr(expression)] => Some(expression.clone()),
+        [Token::Word(word)] if word.eq_ignore_ascii_case("null") => None,
+        [Token::Word(word)]
+            if word.eq_ignore_ascii_case("true") || word.eq_ignore_ascii_case("false") =>
+        {
+            Some(word.to_uppercase())
+        }
+        [] => None,
+        _ => Some(render(tokens)),
+    }
+}
+
+// users.id, schema.users.id, users.(a, b)
+fn endpoint(cursor: &mut Cursor) -> Option<Endpoint> {
+    let mut names = vec![cursor.next()?.identifier()?];
+
+    while cursor.eat_symbol('.') {
+        if cursor.peek().is_some_and(|e| e.is_symbol('(')) {
+            let columns = split_top_level(cursor.enclosed('(', ')')?, |e| e.is_symbol(','))
+                .into_iter()
+                .filter_map(|e| e.first()?.identifier())
+                .collect();
+
+            return Some((names.pop()?, columns));
+        }
+
+        names.push(cursor.next()?.identifier()?);
+    }
+
+    let column = names.pop()?;
+    let table = names.pop()?;
+
+    Some((table, vec![column]))
+}
+
+fn relation_kind(cursor: &mut Cursor) -> Option<char> {
+    match cursor.next()? {
+        Token::Symbol('<') if cursor.eat_symbol('>') => Some('*'),
+        Token::Symbol(c) if ['<', '>', '-'].contains(c) => Some(*c),
+        _ => None,
+    }
+}
+
+// Note: '...' 또는 Note { '...' }
+fn note(cursor: &mut Cursor) -> Option<String> {
+    if cursor.eat_symbol(':') {
+        return cursor.next()?.text();
+    }
+
+    let body = trim_newlines(cursor.enclosed('{', '}')?);
+    text_value(body)
+}
+
+impl Parser {
+    fn table_name(&self, name: &str) -> String {
+        self.aliases
+            .iter()
+            .find(|e| e.0 == name)
+            .map(|e| e.1.clone())
+            .unwrap_or_else(|| name.to_owned())
+    }
+
+    fn parse_project(&mut self, cursor: &mut Cursor) -> Result<(), AllError> {
+        if !cursor.peek().is_some_and(|e| e.is_symbol('{')) {
+            self.file.name = qualified_name(cursor);
+        }
+
+        let body = cursor
+            .enclosed('{', '}')
+            .ok_or_else(|| invalid("Project 블록이 닫히지 않았습니다."))?;
+        let mut body = Cursor::new(body);
+
+        while let Some(token) = body.next() {
+            if token.is_word("note") {
+                if let Some(note) = note(&mut body) {
+                    self.file.description = note;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_table(&mut self, cursor: &mut Cursor) -> Result<(), AllError> {
+        let name = qualified_name(cursor).ok_or_else(|| invalid("테이블 이름이 없습니다."))?;
+
+        let mut table = SchemaTable {
+            name: name.clone(),
+            ..Default::default()
+        };
+
+        if cursor.peek().is_some_and(|e| e.is_word("as")) {
+            cursor.next();
+            if let Some(alias) = cursor.next().and_then(|e| e.identifier()) {
+                self.aliases.push((alias, name.clone()));
+            }
+        }
+
+        if cursor.peek().is_some_and(|e| e.is_symbol('[')) {
+            let header = cursor.enclosed('[', ']').unwrap_or_default();
+
+            for (key, value) in settings(header) {
+                if key == "note" {
+                    table.comment = text_value(value).unwrap_or_default();
+                }
+            }
+        }
+
+        let body = cursor
+            .enclosed('{', '}')
+            .ok_or_else(|| invalid(&format!("{name} 테이블 블록이 닫히지 않았습니다.")))?;
+        let mut body = Cursor::new(body);
+
+        loop {
+            body.skip_newlines();
+
+            let token = match body.peek() {
+                Some(token) => token,
+                None => break,
+            };
+
+            if token.is_word("note")
+                && body
+                    .peek_at(1)
+                    .is_some_and(|e| e.is_symbol(':') || e.is_symbol('{'))
+            {
+                body.next();
+                table.comment = note(&mut body).unwrap_or_default();
+                continue;
+            }
+
+            if token.is_word("indexes") && body.peek_at(1).is_some_and(|e| e.is_symbol('{')) {
+                body.next();
+                let indexes = body.enclosed('{', '}').unwrap_or_default();
+                self.parse_indexes(indexes, &mut table);
+                continue;
+            }
+
+            let line = body.rest_of_line();
+
+            // TablePartial 주입(~partial)
+            if line.first().is_some_and(|e| e.is_symbol('~')) {
+                self.file.warnings.push(format!(
+                    "{name}: TablePartial은 지원하지 않아 {}을(를) 건너뜁니다.",
+                    render(&line[1..])
+                ));
+                continue;
+            }
+
+            if let Some(column) = self.parse_column(line, &mut table) {
+                table.columns.push(column);
+            }
+        }
+
+        self.file.tables.push(table);
+
+        Ok(())
+    }
+
+    fn parse_column(&mut self, line: &[Token], table: &mut SchemaTable) -> Option<SchemaColumn> {
+        let mut cursor = Cursor::new(line);
+        let name = cursor.next()?.identifier()?;
+
+        // 타입은 설정 목록([...]) 앞까지입니다.
+        let type_end = line[cursor.position..]
+            .iter()
+            .position(|e| e.is_symbol('['))
+            .map_or(line.len(), |e| e + cursor.position);
+        let type_tokens = &line[cursor.position..type_end];
+        cursor.position = type_end;
+
+        let mut column = SchemaColumn {
+            name: name.clone(),
+            nullable: true,
+            ..Default::default()
+        };
+
+        // 타입 이름 (schema.enum 형태면 마지막 이름)
+        let type_name = match type_tokens {
+            [Token::Quoted(data_type)] => data_type.clone(),
+            [.., Token::Symbol('.'), Token::Word(data_type)] => data_type.clone(),
+            _ => render(type_tokens),
+        };
+
+        column.set_data_type(&type_name.to_uppercase());
+        self.column_types
+            .push((self.file.tables.len(), table.columns.len(), type_name));
+
+        let settings_tokens = cursor.enclosed('[', ']').unwrap_or_default();
+
+        for (key, value) in settings(settings_tokens) {
+            match key.as_str() {
+                "pk" | "primary key" => column.is_primary_key = true,
+                "increment" => column.is_auto_increment = true,
+                "not null" => column.nullable = false,
+                "null" => column.nullable = true,
+                "unique" => column.is_unique = true,
+                "default" => column.default_value = default_value(value),
+                "note" => column.comment = text_value(value).unwrap_or_default(),
+                "check" => {
+                    column.check = text_value(value).or_else(|| match value {
+                        [Token::Expr(expression)] => Some(expression.clone()),
+                        _ => None,
+                    })
+                }
+                "ref" => {
+                    let mut value = Cursor::new(value);
+
+                    if let (Some(kind), Some(right)) =
+                        (relation_kind(&mut value), endpoint(&mut value))
+                    {
+                        self.references.push(Reference {
+                            left: (table.name.clone(), vec![name.clone()]),
+                            kind,
+                            right,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Some(column)
+    }
+
+    fn parse_indexes(&mut self, body: &[Token], table: &mut SchemaTable) {
+        let mut cursor = Cursor::new(body);
+
+        while !cursor.is_done() {
+            let line = cursor.rest_of_line();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut line = Cursor::new(line);
+
+            let columns = match line.peek() {
+                Some(Token::Symbol('(')) => {
+                    split_top_level(line.enclosed('(', ')').unwrap_or_default(), |e| {
+                        e.is_symbol(',')
+                    })
+                    .into_iter()
+                    .map(|e| match e {
+                        [token] => token.identifier(),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+                }
+                Some(Token::Word(_) | Token::Quoted(_)) => {
+                    line.next().and_then(|e| e.identifier()).map(|e| vec![e])
+                }
+                _ => {
+                    line.next();
+                    None
+                }
+            };
+
+            let settings_tokens = line.enclosed('[', ']').unwrap_or_default();
+
+            let columns = match columns {
+                Some(columns) => columns,
+                None => {
+                    self.file.warnings.push(format!(
+                        "{}: 식 인덱스는 지원하지 않아 건너뜁니다.",
+                        table.name
+                    ));
+                    continue;
+                }
+            };
+
+            let mut index = SchemaIndex {
+                columns: columns
+                    .iter()
+                    .map(|e| (e.clone(), SortOrder::Asc))
+                    .collect(),
+                ..Default::default()
+            };
+
+            let mut is_primary_key = false;
+
+            for (key, value) in settings(settings_tokens) {
+                match key.as_str() {
+                    "pk" | "primary key" => is_primary_key = true,
+                    "unique" => index.is_unique = true,
+                    "name" => index.name = text_value(value).unwrap_or_default(),
+                    "type" if value.first().is_some_and(|e| e.is_word("hash")) => {
+                        index.index_type = IndexType::Hash
+                    }
+                    _ => {}
+                }
+            }
+
+            if is_primary_key {
+                table.primary_key = columns;
+            } else {
+                table.push_index(index);
+            }
+        }
+    }
+
+    fn parse_enum(&mut self, cursor: &mut Cursor) -> Result<(), AllError> {
+        let name = qualified_name(cursor).ok_or_else(|| invalid("열거형 이름이 없습니다."))?;
+
+        let body = cursor
+            .enclosed('{', '}')
+            .ok_or_else(|| invalid(&format!("{name} 열거형 블록이 닫히지 않았습니다.")))?;
+        let mut body = Cursor::new(body);
+
+        let mut values = vec![];
+
+        while !body.is_done() {
+            let line = body.rest_of_line();
+
+            if let Some(value) = line.first().and_then(|e| e.identifier()) {
+                values.push(value);
+            }
+        }
+
+        self.enums.push((name, values));
+
+        Ok(())
+    }
+
+    // Ref name: a.b > c.d [...] 또는 Ref name { a.b > c.d ... }
+    fn parse_reference(&mut self, cursor: &mut Cursor) -> Result<(), AllError> {
+        if cursor.peek().is_some_and(|e| e.identifier().is_some()) {
+            cursor.next();
+        }
+
+        let lines = if cursor.eat_symbol(':') {
+            vec![cursor.rest_of_line()]
+        } else {
+            let body = cursor
+                .enclosed('{', '}')
+                .ok_or_else(|| invalid("Ref 블록이 닫히지 않았습니다."))?;
+
+            split_top_level(body, |e| *e == Token::Newline)
+        };
+
+        for line in lines {
+            let mut cursor = Cursor::new(line);
+
+            let left = endpoint(&mut cursor);
+            let kind = relation_kind(&mut cursor);
+            let right = endpoint(&mut cursor);
+
+            match (left, kind, right) {
+                (Some(left), Some(kind), Some(right)) => {
+                    self.references.push(Reference { left, kind, right })
+                }
+                _ => return Err(invalid(&format!("관계 {}", render(line)))),
+            }
+        }
+
+        Ok(())
+    }
+
+    // 블록 이름 뒤의 { ... }까지 건너뜁니다.
+    fn skip_block(&mut self, cursor: &mut Cursor) -> Result<(), AllError> {
+        while cursor.peek().is_some_and(|e| !e.is_symbol('{')) {
+            cursor.next();
+        }
+
+        cursor
+            .enclosed('{', '}')
+            .map(|_| ())
+            .ok_or_else(|| invalid("블록이 닫히지 않았습니다."))
+    }
+
+    fn resolve_enums(&mut self) {
+        for (table_index, column_index, enum_name) in self.column_types.iter() {
+            let values = match self.enums.iter().find(|e| e.0 == *enum_name) {
+                Some(e) => &e.1,
+                None => continue,
+            };
+
+            if let Some(column) = self
+                .file
+                .tables
+                .get_mut(*table_index)
+                .and_then(|e| e.columns.get_mut(*column_index))
+            {
+                set_enum_type(column, values);
+            }
+        }
+    }
+
+    fn is_primary_key(&self, table_name: &str, columns: &[String]) -> bool {
+        let table = match self
+            .file
+            .tables
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(table_name))
+        {
+            Some(table) => table,
+            None => return false,
+        };
+
+        columns.iter().all(|name| {
+            table
+                .primary_key
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(name))
+                || table
+                    .columns
+                    .iter()
+                    .any(|e| e.name.eq_ignore_ascii_case(name) && e.is_primary_key)
+        })
+    }
+
+    fn resolve_references(&mut self) {
+        for reference in self.references.iter() {
+            let left = (self.table_name(&reference.left.0), &reference.left.1);
+            let right = (self.table_name(&reference.right.0), &reference.right.1);
+
+            // FK를 가진 쪽(자식)과 참조되는 쪽(부모)
+            // 일대일은 PK 컬럼끼리가 아니면 PK가 아닌 쪽을 자식으로 봅니다.
+            let (child, parent) = match reference.kind {
+                '-' if self.is_primary_key(&left.0, left.1)
+                    && !self.is_primary_key(&right.0, right.1) =>
+                {
+                    (right, left)
+                }
+                '>' | '-' => (left, right),
+                '<' => (right, left),
+                _ => {
+                    self.file.warnings.push(format!(
+                        "{}-{}: 다대다 관계는 연결 테이블을 만들어 표현해야 하므로 건너뜁니다.",
+                        left.0, right.0
+                    ));
+                    continue;
+                }
+            };
+
+            let table = match self
+                .file
+                .tables
+                .iter_mut()
+                .find(|e| e.name.eq_ignore_ascii_case(&child.0))
+            {
+                Some(table) => table,
+                None => {
+                    self.file
+                        .warnings
+                        .push(format!("{}: 관계를 둘 테이블을 찾을 수 없습니다.", child.0));
+                    continue;
+                }
+            };
+
+            // 일대일은 FK 컬럼을 UNIQUE로 표시합니다.
+            if reference.kind == '-' && child.1.len() == 1 {
+                if let Some(column) = table.find_column_mut(&child.1[0]) {
+                    column.is_unique = !column.is_primary_key;
+                }
+            }
+
+            table.foreign_keys.push(SchemaForeignKey {
+                columns: child.1.clone(),
+                referenced_table: parent.0,
+                referenced_columns: parent.1.clone(),
+            });
+        }
+    }
+}
+
+pub fn parse(source: &str) -> Result<SchemaFile, AllError> {
+    let tokens = tokenize(source)?;
+    let mut cursor = Cursor::new(&tokens);
+
+    let mut parser = Parser {
+        file: SchemaFile::default(),
+        aliases: vec![],
+        enums: vec![],
+        column_types: vec![],
+        references: vec![],
+    };
+
+    while let Some(token) = cursor.next() {
+        let keyword = match token {
+            Token::Newline => continue,
+            Token::Word(keyword) => keyword.to_lowercase(),
+            _ => return Err(invalid(&render(std::slice::from_ref(token)))),
+        };
+
+        match keyword.as_str() {
+            "project" => parser.parse_project(&mut cursor)?,
+            "table" => parser.parse_table(&mut cursor)?,
+            "enum" => parser.parse_enum(&mut cursor)?,
+            "ref" => parser.parse_reference(&mut cursor)?,
+            _ => parser.skip_block(&mut cursor)?,
+        }
+    }
+
+    parser.resolve_enums();
+    parser.resolve_references();
+
+    Ok(parser.file)
+}
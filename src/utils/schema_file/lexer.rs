@@ -0,0 +1,331 @@
+use crate::utils::AllError;
+
+// Prisma/DBML 공통 토큰
+// 두 형식 모두 줄 단위로 필드를 나누므로 줄바꿈도 토큰으로 남깁니다.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Word(String),
+    // "..." (Prisma 문자열, DBML 따옴표 식별자)
+    Quoted(String),
+    // '...', '''...''' (DBML 문자열)
+    Str(String),
+    // `...` (DBML 식)
+    Expr(String),
+    // /// 문서 주석 (Prisma)
+    Doc(String),
+    Symbol(char),
+    Newline,
+}
+
+impl Token {
+    pub fn is_word(&self, word: &str) -> bool {
+        matches!(self, Token::Word(e) if e.eq_ignore_ascii_case(word))
+    }
+
+    pub fn is_symbol(&self, symbol: char) -> bool {
+        matches!(self, Token::Symbol(c) if *c == symbol)
+    }
+
+    pub fn identifier(&self) -> Option<String> {
+        match self {
+            Token::Word(word) | Token::Quoted(word) => Some(word.to_owned()),
+            _ => None,
+        }
+    }
+
+    // 문자열 값 (따옴표 종류와 무관)
+    pub fn text(&self) -> Option<String> {
+        match self {
+            Token::Quoted(text) | Token::Str(text) => Some(text.to_owned()),
+            _ => None,
+        }
+    }
+}
+
+fn read_escaped(chars: &[char], position: &mut usize, quote: char) -> Option<String> {
+    let mut text = String::new();
+
+    while let Some(&c) = chars.get(*position) {
+        *position += 1;
+
+        match c {
+            '\\' => {
+                let escaped = *chars.get(*position)?;
+                *position += 1;
+
+                text.push(match escaped {
+                    'n' => '\n',
+                    't' => '\t',
+                    _ => escaped,
+                });
+            }
+            _ if c == quote => return Some(text),
+            _ => text.push(c),
+        }
+    }
+
+    None
+}
+
+pub fn tokenize(source: &str) -> Result<Vec<Token>, AllError> {
+    let chars = source.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut position = 0;
+    let mut line = 1;
+
+    let unterminated = |line: usize| {
+        AllError::InvalidInput(format!("{line}번째 줄: 닫히지 않은 문자열이 있습니다."))
+    };
+
+    while let Some(&c) = chars.get(position) {
+        let next = chars.get(position + 1).copied();
+
+        match c {
+            '\n' => {
+                tokens.push(Token::Newline);
+                line += 1;
+                position += 1;
+            }
+            _ if c.is_whitespace() => position += 1,
+            '/' if next == Some('/') => {
+                let start = position;
+                while position < chars.len() && chars[position] != '\n' {
+                    position += 1;
+                }
+
+                let comment = chars[start..position].iter().collect::<String>();
+
+                if let Some(doc) = comment.strip_prefix("///") {
+                    tokens.push(Token::Doc(doc.trim().to_owned()));
+                }
+            }
+            '/' if next == Some('*') => {
+                position += 2;
+                while position < chars.len()
+                    && !(chars[position] == '*' && chars.get(position + 1) == Some(&'/'))
+                {
+                    if chars[position] == '\n' {
+                        line += 1;
+                    }
+                    position += 1;
+                }
+                position += 2;
+            }
+            '\'' if chars[position..].starts_with(&['\'', '\'', '\'']) => {
+                // 여러 줄 문자열은 줄마다 들여쓰기를 걷어냅니다.
+                position += 3;
+                let start = position;
+
+                while position < chars.len() && !chars[position..].starts_with(&['\'', '\'', '\''])
+                {
+                    position += 1;
+                }
+
+                if position >= chars.len() {
+                    return Err(unterminated(line));
+                }
+
+                let text = chars[start..position].iter().collect::<String>();
+                line += text.matches('\n').count();
+                position += 3;
+
+                tokens.push(Token::Str(
+                    text.lines()
+                        .map(str::trim)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                        .trim()
+                        .to_owned(),
+                ));
+            }
+            '"' | '\'' | '`' => {
+                position += 1;
+
+                let text =
+                    read_escaped(&chars, &mut position, c).ok_or_else(|| unterminated(line))?;
+                line += text.matches('\n').count();
+
+                tokens.push(match c {
+                    '"' => Token::Quoted(text),
+                    '\'' => Token::Str(text),
+                    _ => Token::Expr(text),
+                });
+            }
+            _ if c.is_alphanumeric() || c == '_' => {
+                let start = position;
+                // 숫자는 소수점까지 한 단어로 읽습니다. (1.5)
+                let is_number = c.is_ascii_digit();
+
+                while let Some(&c) = chars.get(position) {
+                    if c.is_alphanumeric()
+                        || c == '_'
+                        || (is_number
+                            && c == '.'
+                            && chars.get(position + 1).is_some_and(char::is_ascii_digit))
+                    {
+                        position += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                tokens.push(Token::Word(chars[start..position].iter().collect()));
+            }
+            _ => {
+                tokens.push(Token::Symbol(c));
+                position += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+pub struct Cursor<'a> {
+    tokens: &'a [Token],
+    pub position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(tokens: &'a [Token]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    pub fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    pub fn peek_at(&self, offset: usize) -> Option<&'a Token> {
+        self.tokens.get(self.position + offset)
+    }
+
+    pub fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    pub fn eat_symbol(&mut self, symbol: char) -> bool {
+        if self.peek().is_some_and(|e| e.is_symbol(symbol)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn skip_newlines(&mut self) {
+        while self.peek() == Some(&Token::Newline) {
+            self.position += 1;
+        }
+    }
+
+    // 현재 줄의 나머지 토큰 (줄바꿈은 건너뜁니다)
+    pub fn rest_of_line(&mut self) -> &'a [Token] {
+        let start = self.position;
+
+        while self.peek().is_some_and(|e| *e != Token::Newline) {
+            self.position += 1;
+        }
+
+        let line = &self.tokens[start..self.position];
+        self.skip_newlines();
+        line
+    }
+
+    // 여는 괄호 위치에서 짝이 맞는 닫는 괄호까지 읽고 안쪽 토큰을 돌려줍니다.
+    // 괄호가 닫히지 않으면 None
+    pub fn enclosed(&mut self, open: char, close: char) -> Option<&'a [Token]> {
+        if !self.eat_symbol(open) {
+            return None;
+        }
+
+        let start = self.position;
+        let mut depth = 1;
+
+        while let Some(token) = self.next() {
+            if token.is_symbol(open) {
+                depth += 1;
+            } else if token.is_symbol(close) {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(&self.tokens[start..self.position - 1]);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// 괄호 안쪽이 아닌 구분자로 나눕니다. 빈 조각은 버립니다.
+pub fn split_top_level(tokens: &[Token], is_separator: impl Fn(&Token) -> bool) -> Vec<&[Token]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Symbol('(' | '[' | '{') => depth += 1,
+            Token::Symbol(')' | ']' | '}') => depth -= 1,
+            _ if depth == 0 && is_separator(token) => {
+                parts.push(&tokens[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&tokens[start..]);
+
+    parts
+        .into_iter()
+        .map(trim_newlines)
+        .filter(|e| !e.is_empty())
+        .collect()
+}
+
+pub fn trim_newlines(mut tokens: &[Token]) -> &[Token] {
+    while let [Token::Newline, rest @ ..] = tokens {
+        tokens = rest;
+    }
+
+    while let [rest @ .., Token::Newline] = tokens {
+        tokens = rest;
+    }
+
+    tokens
+}
+
+// 타입 인자나 기본값 같은 토큰 묶음을 원래 표기에 가깝게 되돌립니다.
+pub fn render(tokens: &[Token]) -> String {
+    let mut text = String::new();
+
+    for token in tokens.iter() {
+        match token {
+            Token::Word(word) => {
+                if text.ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                    text.push(' ');
+                }
+                text.push_str(word);
+            }
+            Token::Quoted(word) => text.push_str(&format!("\"{word}\"")),
+            Token::Str(word) => text.push_str(&format!("'{}'", word.replace('\'', "''"))),
+            Token::Expr(expression) => text.push_str(expression),
+            Token::Symbol(',') => text.push_str(", "),
+            Token::Symbol(c) => text.push(*c),
+            Token::Doc(_) | Token::Newline => {}
+        }
+    }
+
+    text
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use super::{schema_import::SchemaColumn, schema_import::SchemaTable, AllError};
+
+pub mod dbml;
+mod lexer;
+pub mod prisma;
+
+// 다른 도구의 스키마 파일 (Prisma schema, dbdiagram.io DBML)
+// 테이블 정의로 읽은 뒤에는 DDL 가져오기와 같은 schema_import 단계를 거칩니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaFileFormat {
+    Prisma,
+    Dbml,
+}
+
+impl SchemaFileFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SchemaFileFormat::Prisma => "Prisma",
+            SchemaFileFormat::Dbml => "DBML",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaFile {
+    // DBML Project 블록의 이름/노트 (새 프로젝트 이름과 설명으로 씁니다)
+    pub name: Option<String>,
+    pub description: String,
+    pub tables: Vec<SchemaTable>,
+    // 옮기지 못하고 건너뛴 항목
+    pub warnings: Vec<String>,
+}
+
+pub fn parse(format: SchemaFileFormat, source: &str) -> Result<SchemaFile, AllError> {
+    match format {
+        SchemaFileFormat::Prisma => prisma::parse(source),
+        SchemaFileFormat::Dbml => dbml::parse(source),
+    }
+}
+
+// 열거형 컬럼은 값 길이에 맞춘 VARCHAR와 IN 검사 조건으로 옮깁니다.
+fn set_enum_type(column: &mut SchemaColumn, values: &[String]) {
+    column.data_type = "VARCHAR".to_owned();
+    column.length = Some(
+        values
+            .iter()
+            .map(|e| e.chars().count() as u32)
+            .max()
+            .unwrap_or_default()
+            .max(1),
+    );
+
+    if !values.is_empty() {
+        let values = values
+            .iter()
+            .map(|e| super::sql::string_literal(e))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        column.check = Some(format!("{} IN ({values})", column.name));
+    }
+}
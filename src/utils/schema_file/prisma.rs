@@ -0,0 +1,542 @@
+use crate::{
+    models::{IndexType, SortOrder},
+    utils::{
+        schema_import::{SchemaColumn, SchemaForeignKey, SchemaIndex, SchemaTable},
+        sql::string_literal,
+        AllError, Dialect,
+    },
+};
+
+use super::{
+    lexer::{render, split_top_level, tokenize, Cursor, Token},
+    set_enum_type, SchemaFile,
+};
+
+// Prisma schema 파서
+// model은 테이블, 스칼라/열거형 필드는 컬럼, @relation(fields, references)이 있는 관계 필드는 FK가 됩니다.
+// @map/@@map이 있으면 DB 이름을 씁니다. 목록 관계 필드(Post[])처럼 반대쪽 관계 필드는 컬럼이 없으므로 건너뜁니다.
+
+struct Attribute<'a> {
+    name: String,
+    arguments: Vec<(Option<String>, &'a [Token])>,
+}
+
+impl<'a> Attribute<'a> {
+    // 이름 있는 인자 또는 position번째 위치 인자
+    fn argument(&self, name: &str, position: usize) -> Option<&'a [Token]> {
+        self.arguments
+            .iter()
+            .find(|(key, _)| key.as_deref() == Some(name))
+            .or_else(|| {
+                self.arguments
+                    .iter()
+                    .filter(|(key, _)| key.is_none())
+                    .nth(position)
+            })
+            .map(|e| e.1)
+    }
+
+    fn text_argument(&self, name: &str, position: usize) -> Option<String> {
+        match self.argument(name, position)? {
+            [token] => token.text(),
+            _ => None,
+        }
+    }
+}
+
+struct Field<'a> {
+    name: String,
+    field_type: String,
+    is_optional: bool,
+    is_list: bool,
+    attributes: Vec<Attribute<'a>>,
+    doc: String,
+}
+
+impl Field<'_> {
+    fn attribute(&self, name: &str) -> Option<&Attribute<'_>> {
+        self.attributes.iter().find(|e| e.name == name)
+    }
+
+    fn column_name(&self) -> String {
+        self.attribute("map")
+            .and_then(|e| e.text_argument("name", 0))
+            .unwrap_or_else(|| self.name.clone())
+    }
+}
+
+struct Model<'a> {
+    name: String,
+    fields: Vec<Field<'a>>,
+    attributes: Vec<Attribute<'a>>,
+    doc: String,
+}
+
+impl Model<'_> {
+    fn table_name(&self) -> String {
+        self.attributes
+            .iter()
+            .find(|e| e.name == "map")
+            .and_then(|e| e.text_argument("name", 0))
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    // 필드 이름을 컬럼 이름으로 바꿉니다.
+    fn column_name(&self, field_name: &str) -> String {
+        self.fields
+            .iter()
+            .find(|e| e.name == field_name)
+            .map(|e| e.column_name())
+            .unwrap_or_else(|| field_name.to_owned())
+    }
+}
+
+// 필드 타입은 Prisma 이름으로 적으므로 열거형도 원래 이름으로 찾고, 검사 조건에는 DB 값을 씁니다.
+struct EnumType {
+    name: String,
+    // (이름, DB 값)
+    values: Vec<(String, String)>,
+}
+
+fn parse_arguments(tokens: &[Token]) -> Vec<(Option<String>, &[Token])> {
+    split_top_level(tokens, |e| e.is_symbol(','))
+        .into_iter()
+        .map(|part| match part {
+            [Token::Word(key), Token::Symbol(':'), value @ ..] => (Some(key.clone()), value),
+            _ => (None, part),
+        })
+        .collect()
+}
+
+// @id, @db.VarChar(255), @@index([a, b]) 같은 속성을 읽습니다. (@ 다음 위치에서 호출)
+fn parse_attribute<'a>(cursor: &mut Cursor<'a>) -> Option<Attribute<'a>> {
+    let mut name = cursor.next()?.identifier()?;
+
+    while cursor.eat_symbol('.') {
+        name.push('.');
+        name.push_str(&cursor.next()?.identifier()?);
+    }
+
+    let arguments = if cursor.peek().is_some_and(|e| e.is_symbol('(')) {
+        parse_arguments(cursor.enclosed('(', ')')?)
+    } else {
+        vec![]
+    };
+
+    Some(Attribute { name, arguments })
+}
+
+fn parse_field<'a>(line: &'a [Token], doc: String) -> Option<Field<'a>> {
+    let mut cursor = Cursor::new(line);
+
+    let name = cursor.next()?.identifier()?;
+    let mut field_type = cursor.next()?.identifier()?;
+
+    // Unsupported("circle")
+    if cursor.peek().is_some_and(|e| e.is_symbol('(')) {
+        if let Some([Token::Quoted(native)]) = cursor.enclosed('(', ')') {
+            field_type = native.to_owned();
+        }
+    }
+
+    let is_optional = cursor.eat_symbol('?');
+    let is_list = cursor.eat_symbol('[') && cursor.eat_symbol(']');
+
+    let mut attributes = vec![];
+
+    while cursor.eat_symbol('@') {
+        attributes.extend(parse_attribute(&mut cursor));
+    }
+
+    Some(Field {
+        name,
+        field_type,
+        is_optional,
+        is_list,
+        attributes,
+        doc,
+    })
+}
+
+fn parse_model(name: String, body: &[Token], doc: String) -> Model<'_> {
+    let mut cursor = Cursor::new(body);
+    let mut model = Model {
+        name,
+        fields: vec![],
+        attributes: vec![],
+        doc,
+    };
+    let mut docs: Vec<String> = vec![];
+
+    while !cursor.is_done() {
+        if let Some(Token::Doc(doc)) = cursor.peek() {
+            docs.push(doc.clone());
+            cursor.next();
+            cursor.skip_newlines();
+            continue;
+        }
+
+        let line = cursor.rest_of_line();
+
+        match line {
+            [] => {}
+            [Token::Symbol('@'), Token::Symbol('@'), ..] => {
+                let mut attribute = Cursor::new(&line[2..]);
+                model.attributes.extend(parse_attribute(&mut attribute));
+            }
+            _ => model
+                .fields
+                .extend(parse_field(line, std::mem::take(&mut docs).join("\n"))),
+        }
+
+        docs.clear();
+    }
+
+    model
+}
+
+fn parse_enum(name: String, body: &[Token]) -> EnumType {
+    let mut cursor = Cursor::new(body);
+    let mut values = vec![];
+
+    while !cursor.is_done() {
+        let line = cursor.rest_of_line();
+        let mut line = Cursor::new(line);
+
+        // @@map 등 블록 속성은 값이 아닙니다.
+        if let Some(Token::Word(value)) = line.next() {
+            let mut db_value = value.clone();
+
+            while line.eat_symbol('@') {
+                if let Some(attribute) = parse_attribute(&mut line) {
+                    if attribute.name == "map" {
+                        db_value = attribute.text_argument("name", 0).unwrap_or(db_value);
+                    }
+                }
+            }
+
+            values.push((value.clone(), db_value));
+        }
+    }
+
+    EnumType { name, values }
+}
+
+fn provider(body: &[Token]) -> Option<Dialect> {
+    let provider = body.windows(3).find_map(|e| match e {
+        [Token::Word(key), Token::Symbol('='), Token::Quoted(value)] if key == "provider" => {
+            Some(value.as_str())
+        }
+        _ => None,
+    })?;
+
+    match provider {
+        "postgresql" | "postgres" | "cockroachdb" => Some(Dialect::PostgreSql),
+        "mysql" => Some(Dialect::MySql),
+        "sqlite" => Some(Dialect::Sqlite),
+        _ => None,
+    }
+}
+
+// 네이티브 타입 속성이 없을 때 Prisma가 쓰는 기본 DB 타입
+fn scalar_type(name: &str, dialect: Dialect) -> Option<&'static str> {
+    let data_type = match (name, dialect) {
+        ("String", Dialect::MySql) => "VARCHAR(191)",
+        ("String", _) => "TEXT",
+        ("Int", Dialect::Sqlite) => "INTEGER",
+        ("Int", _) => "INT",
+        ("BigInt", _) => "BIGINT",
+        ("Float", Dialect::MySql) => "DOUBLE",
+        ("Float", Dialect::PostgreSql) => "DOUBLE PRECISION",
+        ("Float", Dialect::Sqlite) => "REAL",
+        ("Decimal", Dialect::Sqlite) => "DECIMAL",
+        ("Decimal", _) => "DECIMAL(65,30)",
+        ("Boolean", _) => "BOOLEAN",
+        ("DateTime", Dialect::MySql) => "DATETIME(3)",
+        ("DateTime", Dialect::PostgreSql) => "TIMESTAMP(3)",
+        ("DateTime", Dialect::Sqlite) => "DATETIME",
+        ("Json", Dialect::MySql) => "JSON",
+        ("Json", Dialect::PostgreSql) => "JSONB",
+        ("Json", Dialect::Sqlite) => "TEXT",
+        ("Bytes", Dialect::MySql) => "LONGBLOB",
+        ("Bytes", Dialect::PostgreSql) => "BYTEA",
+        ("Bytes", Dialect::Sqlite) => "BLOB",
+        _ => return None,
+    };
+
+    Some(data_type)
+}
+
+// @db.VarChar(255) -> VARCHAR(255), @db.UnsignedInt -> INT UNSIGNED
+fn native_type(attribute: &Attribute) -> String {
+    let name = attribute.name.trim_start_matches("db.");
+
+    let (name, unsigned) = match name.strip_prefix("Unsigned") {
+        Some(name) => (name, " UNSIGNED"),
+        None => (name, ""),
+    };
+
+    let name = match name {
+        "DoublePrecision" => "DOUBLE PRECISION".to_owned(),
+        _ => name.to_uppercase(),
+    };
+
+    let arguments = attribute
+        .arguments
+        .iter()
+        .map(|e| render(e.1))
+        .collect::<Vec<_>>();
+
+    if arguments.is_empty() {
+        format!("{name}{unsigned}")
+    } else {
+        format!("{name}({}){unsigned}", arguments.join(","))
+    }
+}
+
+fn set_default(column: &mut SchemaColumn, tokens: &[Token], enum_type: Option<&EnumType>) {
+    column.default_value = match tokens {
+        [Token::Word(function), Token::Symbol('('), ..] => match function.as_str() {
+            "autoincrement" | "sequence" => {
+                column.is_auto_increment = true;
+                None
+            }
+            "now" => Some("CURRENT_TIMESTAMP".to_owned()),
+            // 애플리케이션(Prisma Client)에서 만드는 값은 DB 기본값이 아닙니다.
+            "uuid" | "cuid" | "nanoid" | "ulid" | "auto" => None,
+            "dbgenerated" => match &tokens[2..] {
+                [Token::Quoted(expression), Token::Symbol(')')] => Some(expression.clone()),
+                _ => None,
+            },
+            _ => Some(render(tokens)),
+        },
+        [Token::Quoted(text)] => Some(string_literal(text)),
+        [Token::Word(word)] if word == "true" || word == "false" => Some(word.to_uppercase()),
+        [Token::Word(word)] => match enum_type {
+            Some(enum_type) => enum_type
+                .values
+                .iter()
+                .find(|e| e.0 == *word)
+                .map(|e| string_literal(&e.1)),
+            None => Some(word.clone()),
+        },
+        _ => Some(render(tokens)),
+    };
+}
+
+// [a, b(sort: Desc)] 형태의 필드 목록
+fn field_list(tokens: &[Token]) -> Vec<(String, SortOrder)> {
+    let mut cursor = Cursor::new(tokens);
+
+    let inner = match cursor.enclosed('[', ']') {
+        Some(inner) => inner,
+        None => return vec![],
+    };
+
+    split_top_level(inner, |e| e.is_symbol(','))
+        .into_iter()
+        .filter_map(|part| {
+            let name = part.first()?.identifier()?;
+            let is_desc = part
+                .windows(3)
+                .any(|e| e[0].is_word("sort") && e[1].is_symbol(':') && e[2].is_word("Desc"));
+
+            Some((
+                name,
+                if is_desc {
+                    SortOrder::Desc
+                } else {
+                    SortOrder::Asc
+                },
+            ))
+        })
+        .collect()
+}
+
+fn build_table(
+    model: &Model,
+    models: &[Model],
+    enums: &[EnumType],
+    dialect: Dialect,
+) -> SchemaTable {
+    let mut table = SchemaTable {
+        name: model.table_name(),
+        comment: model.doc.clone(),
+        ..Default::default()
+    };
+
+    for field in model.fields.iter() {
+        if let Some(target) = models.iter().find(|e| e.name == field.field_type) {
+            // 관계 필드: FK 컬럼을 가진 쪽에만 @relation(fields, references)가 있습니다.
+            let relation = match field.attribute("relation") {
+                Some(relation) => relation,
+                None => continue,
+            };
+
+            let columns = relation
+                .argument("fields", usize::MAX)
+                .map(field_list)
+                .unwrap_or_default();
+            let referenced_columns = relation
+                .argument("references", usize::MAX)
+                .map(field_list)
+                .unwrap_or_default();
+
+            if columns.is_empty() {
+                continue;
+            }
+
+            table.foreign_keys.push(SchemaForeignKey {
+                columns: columns.iter().map(|e| model.column_name(&e.0)).collect(),
+                referenced_table: target.table_name(),
+                referenced_columns: referenced_columns
+                    .iter()
+                    .map(|e| target.column_name(&e.0))
+                    .collect(),
+            });
+
+            continue;
+        }
+
+        let mut column = SchemaColumn {
+            name: field.column_name(),
+            nullable: field.is_optional,
+            comment: field.doc.clone(),
+            ..Default::default()
+        };
+
+        let enum_type = enums.iter().find(|e| e.name == field.field_type);
+
+        let native = field.attributes.iter().find(|e| e.name.starts_with("db."));
+
+        match (native, enum_type) {
+            (Some(native), _) => column.set_data_type(&native_type(native)),
+            (None, Some(enum_type)) => {
+                let values = enum_type
+                    .values
+                    .iter()
+                    .map(|e| e.1.clone())
+                    .collect::<Vec<_>>();
+                set_enum_type(&mut column, &values);
+            }
+            (None, None) => column.set_data_type(
+                scalar_type(&field.field_type, dialect).unwrap_or(&field.field_type),
+            ),
+        }
+
+        // 스칼라 목록(String[])은 PostgreSQL 배열 타입으로 둡니다.
+        if field.is_list {
+            column.data_type.push_str("[]");
+        }
+
+        column.is_primary_key = field.attribute("id").is_some();
+        column.is_unique = field.attribute("unique").is_some();
+
+        if let Some(tokens) = field
+            .attribute("default")
+            .and_then(|e| e.argument("value", 0))
+        {
+            set_default(&mut column, tokens, enum_type);
+        }
+
+        table.columns.push(column);
+    }
+
+    for attribute in model.attributes.iter() {
+        let fields = || {
+            attribute
+                .argument("fields", 0)
+                .map(field_list)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, order)| (model.column_name(&name), order))
+                .collect::<Vec<_>>()
+        };
+
+        let index_type = match attribute.name.as_str() {
+            "id" => {
+                table.primary_key = fields().into_iter().map(|e| e.0).collect();
+                continue;
+            }
+            "unique" | "index" => match attribute.argument("type", usize::MAX) {
+                Some([token]) if token.is_word("Hash") => IndexType::Hash,
+                _ => IndexType::Btree,
+            },
+            "fulltext" => IndexType::Fulltext,
+            _ => continue,
+        };
+
+        table.push_index(SchemaIndex {
+            name: attribute
+                .text_argument("map", usize::MAX)
+                .unwrap_or_default(),
+            columns: fields(),
+            is_unique: attribute.name == "unique",
+            index_type,
+        });
+    }
+
+    table
+}
+
+pub fn parse(source: &str) -> Result<SchemaFile, AllError> {
+    let tokens = tokenize(source)?;
+    let mut cursor = Cursor::new(&tokens);
+
+    let mut models = vec![];
+    let mut enums = vec![];
+    let mut dialect = None;
+    let mut docs: Vec<String> = vec![];
+
+    while let Some(token) = cursor.next() {
+        let keyword = match token {
+            Token::Newline => continue,
+            Token::Doc(doc) => {
+                docs.push(doc.clone());
+                continue;
+            }
+            Token::Word(keyword) => keyword.as_str(),
+            _ => {
+                return Err(AllError::InvalidInput(
+                    "Prisma schema를 해석할 수 없습니다.".into(),
+                ))
+            }
+        };
+
+        let name = cursor
+            .next()
+            .and_then(|e| e.identifier())
+            .unwrap_or_default();
+
+        let body = cursor.enclosed('{', '}').ok_or_else(|| {
+            AllError::InvalidInput(format!("{keyword} {name}: 블록이 닫히지 않았습니다."))
+        })?;
+
+        let doc = std::mem::take(&mut docs).join("\n");
+
+        match keyword {
+            "model" => models.push(parse_model(name, body, doc)),
+            "enum" => enums.push((name, body)),
+            "datasource" => dialect = provider(body),
+            // generator, view, type 블록은 테이블이 아닙니다.
+            _ => {}
+        }
+    }
+
+    let enums = enums
+        .into_iter()
+        .map(|(name, body)| parse_enum(name, body))
+        .collect::<Vec<_>>();
+
+    let dialect = dialect.unwrap_or_default();
+
+    let tables = models
+        .iter()
+        .map(|model| build_table(model, &models, &enums, dialect))
+        .collect();
+
+    Ok(SchemaFile {
+        tables,
+        ..Default::default()
+    })
+}
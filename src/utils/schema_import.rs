@@ -0,0 +1,450 @@
+use std::collections::HashMap;
+
+use crate::models::{Column, Entity, ForeignKey, Index, IndexColumn, IndexType, SortOrder};
+
+use super::{generate_uuid, layout};
+
+// 외부 스키마(DDL 등)에서 읽어온 테이블 정의
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaTable {
+    pub name: String,
+    pub comment: String,
+    pub columns: Vec<SchemaColumn>,
+    pub primary_key: Vec<String>,
+    pub foreign_keys: Vec<SchemaForeignKey>,
+    pub indexes: Vec<SchemaIndex>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaColumn {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub is_primary_key: bool,
+    pub comment: String,
+    pub length: Option<u32>,
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+    pub default_value: Option<String>,
+    pub is_unique: bool,
+    pub is_auto_increment: bool,
+    pub is_unsigned: bool,
+    pub check: Option<String>,
+}
+
+impl SchemaColumn {
+    // "VARCHAR(255)", "DECIMAL(10,2)", "INT UNSIGNED" 같은 타입 표기를 나눠 담습니다.
+    // ENUM('a','b')처럼 숫자가 아닌 인자는 타입 표기에 그대로 남깁니다.
+    pub fn set_data_type(&mut self, data_type: &str) {
+        let mut words = vec![];
+
+        for word in data_type.split_whitespace() {
+            match word.to_uppercase().as_str() {
+                "UNSIGNED" => self.is_unsigned = true,
+                "SIGNED" | "ZEROFILL" => {}
+                _ => words.push(word),
+            }
+        }
+
+        let data_type = words.join(" ");
+
+        let (base, arguments) = match (data_type.find('('), data_type.rfind(')')) {
+            (Some(start), Some(end)) if start < end && data_type[end + 1..].trim().is_empty() => (
+                data_type[..start].trim().to_owned(),
+                data_type[start + 1..end]
+                    .split(',')
+                    .map(|e| e.trim().parse::<u32>().ok())
+                    .collect::<Option<Vec<_>>>(),
+            ),
+            _ => (data_type.clone(), Some(vec![])),
+        };
+
+        let arguments = match arguments {
+            Some(arguments) => arguments,
+            None => {
+                self.data_type = data_type;
+                return;
+            }
+        };
+
+        let upper = base.to_uppercase();
+        let is_numeric = [
+            "DECIMAL", "NUMERIC", "NUMBER", "FLOAT", "DOUBLE", "REAL", "DEC",
+        ]
+        .iter()
+        .any(|e| upper.starts_with(e));
+        let is_temporal = ["TIME", "DATETIME", "TIMESTAMP"]
+            .iter()
+            .any(|e| upper.starts_with(e));
+
+        match arguments.as_slice() {
+            [] => {}
+            [value] if is_numeric || is_temporal => self.precision = Some(*value),
+            [value] => self.length = Some(*value),
+            [precision, scale] => {
+                self.precision = Some(*precision);
+                self.scale = Some(*scale);
+            }
+            _ => {
+                self.data_type = data_type;
+                return;
+            }
+        }
+
+        if ["SERIAL", "BIGSERIAL", "SMALLSERIAL"].contains(&upper.as_str()) {
+            self.is_auto_increment = true;
+        }
+
+        self.data_type = base;
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaForeignKey {
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaIndex {
+    pub name: String,
+    pub columns: Vec<(String, SortOrder)>,
+    pub is_unique: bool,
+    pub index_type: IndexType,
+}
+
+impl SchemaTable {
+    pub fn find_column_mut(&mut self, name: &str) -> Option<&mut SchemaColumn> {
+        self.columns
+            .iter_mut()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+    }
+
+    // 이름 없는 인덱스는 idx_테이블_컬럼 형태로 이름을 붙입니다.
+    pub fn push_index(&mut self, mut index: SchemaIndex) {
+        if index.columns.is_empty() {
+            return;
+        }
+
+        if index.name.is_empty() {
+            let column_names = index
+                .columns
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join("_");
+            let prefix = if index.is_unique { "uk" } else { "idx" };
+            index.name = format!("{prefix}_{}_{column_names}", self.name);
+        }
+
+        self.indexes
+            .retain(|e| !e.name.eq_ignore_ascii_case(&index.name));
+        self.indexes.push(index);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedEntity {
+    pub entity: Entity,
+    pub added_columns: Vec<String>,
+    pub removed_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub created: Vec<ImportedEntity>,
+    pub updated: Vec<ImportedEntity>,
+    pub warnings: Vec<String>,
+}
+
+impl ImportPlan {
+    pub fn entities(&self) -> impl Iterator<Item = &Entity> {
+        self.created
+            .iter()
+            .chain(self.updated.iter())
+            .map(|e| &e.entity)
+    }
+}
+
+// 읽어온 테이블 목록을 프로젝트의 기존 엔티티와 물리명 기준으로 맞춰보고,
+// 생성/수정될 엔티티 목록을 만듭니다. 저장은 호출하는 쪽에서 합니다.
+pub fn build_import_plan(
+    project_id: &str,
+    existing: &[Entity],
+    tables: Vec<SchemaTable>,
+) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+
+    for table in tables.iter() {
+        let old_entity = existing
+            .iter()
+            .find(|e| e.physical_name.eq_ignore_ascii_case(&table.name));
+
+        let columns = table
+            .columns
+            .iter()
+            .map(|column| {
+                let old_column = old_entity.and_then(|entity| {
+                    entity
+                        .columns
+                        .iter()
+                        .find(|e| e.physical_name.eq_ignore_ascii_case(&column.name))
+                });
+
+                let is_primary_key = column.is_primary_key
+                    || table
+                        .primary_key
+                        .iter()
+                        .any(|e| e.eq_ignore_ascii_case(&column.name));
+
+                Column {
+                    id: old_column
+                        .map(|e| e.id.clone())
+                        .unwrap_or_else(generate_uuid),
+                    is_primary_key,
+                    logical_name: old_column
+                        .map(|e| e.logical_name.clone())
+                        .unwrap_or_else(|| column.name.clone()),
+                    physical_name: column.name.clone(),
+                    data_type: column.data_type.clone(),
+                    nullable: column.nullable && !is_primary_key,
+                    comment: if column.comment.is_empty() {
+                        old_column.map(|e| e.comment.clone()).unwrap_or_default()
+                    } else {
+                        column.comment.clone()
+                    },
+                    foreign_key: old_column.and_then(|e| e.foreign_key.clone()),
+                    length: column.length,
+                    precision: column.precision,
+                    scale: column.scale,
+                    default_value: column.default_value.clone(),
+                    is_unique: column.is_unique,
+                    is_auto_increment: column.is_auto_increment,
+                    is_unsigned: column.is_unsigned,
+                    check: column.check.clone(),
+                    // 타입이 그대로일 때만 기존 도메인 연결을 유지합니다.
+                    domain_id: old_column
+                        .filter(|e| {
+                            e.data_type.eq_ignore_ascii_case(&column.data_type)
+                                && e.length == column.length
+                                && e.precision == column.precision
+                                && e.scale == column.scale
+                        })
+                        .and_then(|e| e.domain_id.clone()),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let indexes = table
+            .indexes
+            .iter()
+            .filter_map(|index| {
+                let old_index = old_entity.and_then(|entity| {
+                    entity
+                        .indexes
+                        .iter()
+                        .find(|e| e.name.eq_ignore_ascii_case(&index.name))
+                });
+
+                let index_columns = index
+                    .columns
+                    .iter()
+                    .map(|(name, order)| {
+                        columns
+                            .iter()
+                            .find(|e| e.physical_name.eq_ignore_ascii_case(name))
+                            .map(|column| IndexColumn {
+                                column_id: column.id.clone(),
+                                order: *order,
+                            })
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                match index_columns {
+                    Some(index_columns) => Some(Index {
+                        id: old_index
+                            .map(|e| e.id.clone())
+                            .unwrap_or_else(generate_uuid),
+                        name: index.name.clone(),
+                        columns: index_columns,
+                        is_unique: index.is_unique,
+                        index_type: index.index_type,
+                    }),
+                    None => {
+                        plan.warnings.push(format!(
+                            "{}: 인덱스 {}의 컬럼을 찾을 수 없어 제외했습니다.",
+                            table.name, index.name
+                        ));
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match old_entity {
+            Some(old_entity) => {
+                let added_columns = columns
+                    .iter()
+                    .filter(|e| !old_entity.columns.iter().any(|old| old.id == e.id))
+                    .map(|e| e.physical_name.clone())
+                    .collect();
+                let removed_columns = old_entity
+                    .columns
+                    .iter()
+                    .filter(|old| !columns.iter().any(|e| e.id == old.id))
+                    .map(|e| e.physical_name.clone())
+                    .collect();
+
+                plan.updated.push(ImportedEntity {
+                    entity: Entity {
+                        id: old_entity.id.clone(),
+                        project_id: project_id.to_owned(),
+                        logical_name: old_entity.logical_name.clone(),
+                        physical_name: table.name.clone(),
+                        comment: if table.comment.is_empty() {
+                            old_entity.comment.clone()
+                        } else {
+                            table.comment.clone()
+                        },
+                        columns,
+                        indexes,
+                        x: old_entity.x,
+                        y: old_entity.y,
+                        width: old_entity.width,
+                        height: old_entity.height,
+                        color: old_entity.color.clone(),
+                        z_index: old_entity.z_index,
+                        collapsed: old_entity.collapsed,
+                    },
+                    added_columns,
+                    removed_columns,
+                });
+            }
+            None => {
+                plan.created.push(ImportedEntity {
+                    added_columns: columns.iter().map(|e| e.physical_name.clone()).collect(),
+                    removed_columns: vec![],
+                    entity: Entity {
+                        id: generate_uuid(),
+                        project_id: project_id.to_owned(),
+                        logical_name: table.name.clone(),
+                        physical_name: table.name.clone(),
+                        comment: table.comment.clone(),
+                        columns,
+                        indexes,
+                        x: 0.0,
+                        y: 0.0,
+                        width: None,
+                        height: None,
+                        color: None,
+                        z_index: 0,
+                        collapsed: false,
+                    },
+                });
+            }
+        }
+    }
+
+    resolve_foreign_keys(existing, &tables, &mut plan);
+
+    let mut created = plan
+        .created
+        .iter()
+        .map(|e| e.entity.clone())
+        .collect::<Vec<_>>();
+    layout::place_below(existing, &mut created);
+
+    for (item, placed) in plan.created.iter_mut().zip(created) {
+        item.entity.x = placed.x;
+        item.entity.y = placed.y;
+    }
+
+    plan
+}
+
+fn resolve_foreign_keys(existing: &[Entity], tables: &[SchemaTable], plan: &mut ImportPlan) {
+    // 참조 대상은 이번에 가져온 엔티티를 우선으로, 없으면 프로젝트의 기존 엔티티에서 찾습니다.
+    let mut targets: HashMap<String, Entity> = HashMap::new();
+
+    for entity in existing.iter().chain(plan.entities()) {
+        targets.insert(entity.physical_name.to_lowercase(), entity.clone());
+    }
+
+    let mut warnings = vec![];
+
+    let items = plan.created.iter_mut().chain(plan.updated.iter_mut());
+
+    for item in items {
+        let table = match tables
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(&item.entity.physical_name))
+        {
+            Some(table) => table,
+            None => continue,
+        };
+
+        for foreign_key in table.foreign_keys.iter() {
+            let target = match targets.get(&foreign_key.referenced_table.to_lowercase()) {
+                Some(target) => target,
+                None => {
+                    warnings.push(format!(
+                        "{}: 참조 테이블 {}을(를) 찾을 수 없습니다.",
+                        table.name, foreign_key.referenced_table
+                    ));
+                    continue;
+                }
+            };
+
+            let referenced_columns = if foreign_key.referenced_columns.is_empty() {
+                target
+                    .columns
+                    .iter()
+                    .filter(|e| e.is_primary_key)
+                    .map(|e| e.physical_name.clone())
+                    .collect::<Vec<_>>()
+            } else {
+                foreign_key.referenced_columns.clone()
+            };
+
+            if referenced_columns.len() != foreign_key.columns.len() {
+                warnings.push(format!(
+                    "{}: {} 참조 컬럼 수가 일치하지 않습니다.",
+                    table.name, foreign_key.referenced_table
+                ));
+                continue;
+            }
+
+            for (column_name, referenced_name) in
+                foreign_key.columns.iter().zip(referenced_columns.iter())
+            {
+                let referenced_column = target
+                    .columns
+                    .iter()
+                    .find(|e| e.physical_name.eq_ignore_ascii_case(referenced_name));
+
+                let column = item
+                    .entity
+                    .columns
+                    .iter_mut()
+                    .find(|e| e.physical_name.eq_ignore_ascii_case(column_name));
+
+                match (column, referenced_column) {
+                    (Some(column), Some(referenced_column)) => {
+                        column.foreign_key = Some(ForeignKey {
+                            entity_id: target.id.clone(),
+                            column_id: referenced_column.id.clone(),
+                        });
+                    }
+                    _ => warnings.push(format!(
+                        "{}.{}: 참조 컬럼 {}.{}을(를) 찾을 수 없습니다.",
+                        table.name, column_name, foreign_key.referenced_table, referenced_name
+                    )),
+                }
+            }
+        }
+    }
+
+    plan.warnings.append(&mut warnings);
+}
@@ -0,0 +1,299 @@
+use crate::models::{Column, Entity, Index, IndexType, SortOrder};
+
+use super::Dialect;
+
+// 모델을 방언별 DDL 문장으로 그립니다.
+// 제약 조건 이름은 마이그레이션에서 다시 찾을 수 있도록 항상 같은 규칙으로 짓습니다.
+
+pub fn quote(name: &str, dialect: Dialect) -> String {
+    match dialect {
+        Dialect::MySql => format!("`{}`", name.replace('`', "``")),
+        Dialect::PostgreSql | Dialect::Sqlite => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+pub fn string_literal(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+pub fn primary_key_name(entity: &Entity) -> String {
+    format!("{}_pkey", entity.physical_name)
+}
+
+pub fn unique_name(entity: &Entity, column: &Column) -> String {
+    format!("uk_{}_{}", entity.physical_name, column.physical_name)
+}
+
+pub fn foreign_key_name(entity: &Entity, column: &Column) -> String {
+    format!("fk_{}_{}", entity.physical_name, column.physical_name)
+}
+
+pub fn primary_key_columns(entity: &Entity) -> Vec<&Column> {
+    entity.columns.iter().filter(|e| e.is_primary_key).collect()
+}
+
+// SQLite의 AUTOINCREMENT는 INTEGER PRIMARY KEY 컬럼 정의 안에만 쓸 수 있습니다.
+fn is_sqlite_rowid(entity: &Entity, column: &Column, dialect: Dialect) -> bool {
+    dialect == Dialect::Sqlite
+        && column.is_primary_key
+        && column.is_auto_increment
+        && primary_key_columns(entity).len() == 1
+}
+
+pub fn column_type(column: &Column, dialect: Dialect) -> String {
+    match dialect {
+        Dialect::MySql if column.is_unsigned => format!("{} UNSIGNED", column.full_type()),
+        Dialect::Sqlite if column.is_auto_increment => "INTEGER".to_owned(),
+        _ => column.full_type(),
+    }
+}
+
+// 제약 조건(PK/UNIQUE/FK)을 뺀 컬럼 정의
+pub fn column_definition(entity: &Entity, column: &Column, dialect: Dialect) -> String {
+    let mut definition = format!(
+        "{} {}",
+        quote(&column.physical_name, dialect),
+        column_type(column, dialect)
+    );
+
+    if is_sqlite_rowid(entity, column, dialect) {
+        definition.push_str(" PRIMARY KEY AUTOINCREMENT");
+    }
+
+    if !column.nullable {
+        definition.push_str(" NOT NULL");
+    }
+
+    if let Some(default_value) = column.default_value.as_ref() {
+        definition.push_str(&format!(" DEFAULT {default_value}"));
+    }
+
+    if column.is_auto_increment {
+        match dialect {
+            Dialect::MySql => definition.push_str(" AUTO_INCREMENT"),
+            Dialect::PostgreSql => definition.push_str(" GENERATED BY DEFAULT AS IDENTITY"),
+            Dialect::Sqlite => {}
+        }
+    }
+
+    if let Some(check) = column.check.as_ref() {
+        definition.push_str(&format!(" CHECK ({check})"));
+    }
+
+    if dialect == Dialect::MySql && !column.comment.is_empty() {
+        definition.push_str(&format!(" COMMENT {}", string_literal(&column.comment)));
+    }
+
+    definition
+}
+
+fn column_list(names: &[&str], dialect: Dialect) -> String {
+    names
+        .iter()
+        .map(|e| quote(e, dialect))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn primary_key_clause(entity: &Entity, dialect: Dialect) -> Option<String> {
+    let columns = primary_key_columns(entity);
+
+    if columns.is_empty() || columns.iter().any(|e| is_sqlite_rowid(entity, e, dialect)) {
+        return None;
+    }
+
+    let names = columns
+        .iter()
+        .map(|e| e.physical_name.as_str())
+        .collect::<Vec<_>>();
+
+    Some(format!("PRIMARY KEY ({})", column_list(&names, dialect)))
+}
+
+pub fn unique_clause(entity: &Entity, column: &Column, dialect: Dialect) -> String {
+    format!(
+        "CONSTRAINT {} UNIQUE ({})",
+        quote(&unique_name(entity, column), dialect),
+        quote(&column.physical_name, dialect)
+    )
+}
+
+// 참조 대상을 찾을 수 없으면 None
+pub fn foreign_key_clause(
+    entity: &Entity,
+    column: &Column,
+    entities: &[Entity],
+    dialect: Dialect,
+) -> Option<String> {
+    let foreign_key = column.foreign_key.as_ref()?;
+    let target = entities.iter().find(|e| e.id == foreign_key.entity_id)?;
+    let target_column = target
+        .columns
+        .iter()
+        .find(|e| e.id == foreign_key.column_id)?;
+
+    Some(format!(
+        "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+        quote(&foreign_key_name(entity, column), dialect),
+        quote(&column.physical_name, dialect),
+        quote(&target.physical_name, dialect),
+        quote(&target_column.physical_name, dialect)
+    ))
+}
+
+// SQLite는 FK를 나중에 추가할 수 없으므로 테이블 생성 시에만 FK를 포함합니다.
+pub fn create_table(entity: &Entity, entities: &[Entity], dialect: Dialect) -> String {
+    let mut definitions = entity
+        .columns
+        .iter()
+        .map(|e| column_definition(entity, e, dialect))
+        .collect::<Vec<_>>();
+
+    definitions.extend(primary_key_clause(entity, dialect));
+
+    definitions.extend(
+        entity
+            .columns
+            .iter()
+            .filter(|e| e.is_unique)
+            .map(|e| unique_clause(entity, e, dialect)),
+    );
+
+    if dialect == Dialect::Sqlite {
+        definitions.extend(
+            entity
+                .columns
+                .iter()
+                .filter_map(|e| foreign_key_clause(entity, e, entities, dialect)),
+        );
+    }
+
+    let mut sql = format!(
+        "CREATE TABLE {} (\n  {}\n)",
+        quote(&entity.physical_name, dialect),
+        definitions.join(",\n  ")
+    );
+
+    if dialect == Dialect::MySql && !entity.comment.is_empty() {
+        sql.push_str(&format!(" COMMENT={}", string_literal(&entity.comment)));
+    }
+
+    sql.push(';');
+
+    if dialect == Dialect::PostgreSql {
+        for comment in comment_statements(entity, dialect) {
+            sql.push('\n');
+            sql.push_str(&comment);
+        }
+    }
+
+    sql
+}
+
+// PostgreSQL 전용 COMMENT ON 문장
+pub fn comment_statements(entity: &Entity, dialect: Dialect) -> Vec<String> {
+    let table = quote(&entity.physical_name, dialect);
+    let mut statements = vec![];
+
+    if !entity.comment.is_empty() {
+        statements.push(format!(
+            "COMMENT ON TABLE {table} IS {};",
+            string_literal(&entity.comment)
+        ));
+    }
+
+    for column in entity.columns.iter().filter(|e| !e.comment.is_empty()) {
+        statements.push(format!(
+            "COMMENT ON COLUMN {table}.{} IS {};",
+            quote(&column.physical_name, dialect),
+            string_literal(&column.comment)
+        ));
+    }
+
+    statements
+}
+
+// 인덱스가 존재하지 않는 컬럼을 참조하면 None
+pub fn create_index(entity: &Entity, index: &Index, dialect: Dialect) -> Option<String> {
+    let columns = index
+        .columns
+        .iter()
+        .map(|index_column| {
+            let column = entity
+                .columns
+                .iter()
+                .find(|e| e.id == index_column.column_id)?;
+
+            Some(match index_column.order {
+                SortOrder::Asc => quote(&column.physical_name, dialect),
+                SortOrder::Desc => format!("{} DESC", quote(&column.physical_name, dialect)),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?
+        .join(", ");
+
+    let kind = match (dialect, index.index_type) {
+        (Dialect::MySql, IndexType::Fulltext) => "FULLTEXT ",
+        (Dialect::MySql, IndexType::Spatial) => "SPATIAL ",
+        _ if index.is_unique => "UNIQUE ",
+        _ => "",
+    };
+
+    let using = match (dialect, index.index_type) {
+        (Dialect::MySql, IndexType::Hash) => " USING HASH",
+        (Dialect::PostgreSql, IndexType::Hash) => " USING hash",
+        (Dialect::PostgreSql, IndexType::Fulltext) => " USING gin",
+        (Dialect::PostgreSql, IndexType::Spatial) => " USING gist",
+        _ => "",
+    };
+
+    let table = quote(&entity.physical_name, dialect);
+    let name = quote(&index.name, dialect);
+
+    Some(match dialect {
+        Dialect::MySql => format!("CREATE {kind}INDEX {name} ON {table} ({columns}){using};"),
+        _ => format!("CREATE {kind}INDEX {name} ON {table}{using} ({columns});"),
+    })
+}
+
+pub fn drop_index(entity: &Entity, index: &Index, dialect: Dialect) -> String {
+    match dialect {
+        Dialect::MySql => format!(
+            "DROP INDEX {} ON {};",
+            quote(&index.name, dialect),
+            quote(&entity.physical_name, dialect)
+        ),
+        _ => format!("DROP INDEX {};", quote(&index.name, dialect)),
+    }
+}
+
+// ALTER TABLE ... DROP 제약 조건 (MySQL은 종류별로 구문이 다릅니다)
+pub fn drop_constraint(table: &str, kind: ConstraintKind, name: &str, dialect: Dialect) -> String {
+    let table = quote(table, dialect);
+
+    match (dialect, kind) {
+        (Dialect::MySql, ConstraintKind::PrimaryKey) => {
+            format!("ALTER TABLE {table} DROP PRIMARY KEY;")
+        }
+        (Dialect::MySql, ConstraintKind::ForeignKey) => {
+            format!(
+                "ALTER TABLE {table} DROP FOREIGN KEY {};",
+                quote(name, dialect)
+            )
+        }
+        (Dialect::MySql, ConstraintKind::Unique) => {
+            format!("ALTER TABLE {table} DROP INDEX {};", quote(name, dialect))
+        }
+        _ => format!(
+            "ALTER TABLE {table} DROP CONSTRAINT {};",
+            quote(name, dialect)
+        ),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    PrimaryKey,
+    Unique,
+    ForeignKey,
+}
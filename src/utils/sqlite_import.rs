@@ -0,0 +1,228 @@
+use std::path::PathBuf;
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::models::SortOrder;
+
+use super::{
+    generate_uuid,
+    schema_import::{SchemaColumn, SchemaForeignKey, SchemaIndex, SchemaTable},
+    AllError,
+};
+
+// 업로드된 SQLite 데이터베이스 파일에서 스키마를 읽습니다.
+// sqlite_master와 pragma_table_info/foreign_key_list/index_list 카탈로그를 조회하므로 CREATE 문을 직접 해석하지 않습니다.
+
+const HEADER: &[u8] = b"SQLite format 3\0";
+
+// 읽는 동안만 쓰는 임시 파일 (drop 시 삭제)
+struct TempFile(PathBuf);
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn to_error(error: rusqlite::Error) -> AllError {
+    AllError::InvalidInput(format!("SQLite 파일을 읽을 수 없습니다: {error}"))
+}
+
+pub fn read_tables(data: &[u8]) -> Result<Vec<SchemaTable>, AllError> {
+    if !data.starts_with(HEADER) {
+        return Err(AllError::InvalidInput(
+            "SQLite 데이터베이스 파일이 아닙니다.".into(),
+        ));
+    }
+
+    let file = TempFile(std::env::temp_dir().join(format!("{}.db", generate_uuid())));
+
+    std::fs::write(&file.0, data).map_err(|error| AllError::IoError(error.to_string()))?;
+
+    let connection =
+        Connection::open_with_flags(&file.0, OpenFlags::SQLITE_OPEN_READ_ONLY).map_err(to_error)?;
+
+    let mut statement = connection
+        .prepare(
+            "SELECT name FROM sqlite_master \
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        )
+        .map_err(to_error)?;
+
+    let table_names = statement
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(to_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(to_error)?;
+
+    table_names
+        .iter()
+        .map(|name| read_table(&connection, name).map_err(to_error))
+        .collect()
+}
+
+fn read_table(connection: &Connection, name: &str) -> rusqlite::Result<SchemaTable> {
+    let mut table = SchemaTable {
+        name: name.to_owned(),
+        ..Default::default()
+    };
+
+    // (이름, 타입, NOT NULL, 기본값, PK 순번)
+    let mut statement = connection.prepare(
+        "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?1) ORDER BY cid",
+    )?;
+
+    let columns = statement
+        .query_map([name], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, bool>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, u32>(4)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut primary_key = columns
+        .iter()
+        .filter(|e| e.4 > 0)
+        .map(|e| (e.4, e.0.clone()))
+        .collect::<Vec<_>>();
+    primary_key.sort();
+
+    for (column_name, data_type, not_null, default_value, _) in columns.iter() {
+        let mut column = SchemaColumn {
+            name: column_name.clone(),
+            nullable: !not_null,
+            default_value: default_value.clone(),
+            ..Default::default()
+        };
+
+        // 타입을 선언하지 않은 컬럼도 있습니다.
+        column.set_data_type(if data_type.trim().is_empty() {
+            "TEXT"
+        } else {
+            data_type
+        });
+
+        // INTEGER PRIMARY KEY 단일 키는 rowid 별칭이라 값이 자동으로 매겨집니다.
+        column.is_auto_increment = primary_key.len() == 1
+            && primary_key[0].1 == *column_name
+            && column.data_type.eq_ignore_ascii_case("INTEGER");
+
+        table.columns.push(column);
+    }
+
+    table.primary_key = primary_key.into_iter().map(|e| e.1).collect();
+
+    read_foreign_keys(connection, &mut table)?;
+    read_indexes(connection, &mut table)?;
+
+    Ok(table)
+}
+
+fn read_foreign_keys(connection: &Connection, table: &mut SchemaTable) -> rusqlite::Result<()> {
+    // (FK 번호, 참조 테이블, 컬럼, 참조 컬럼) - 복합 FK는 같은 번호로 여러 행이 나옵니다.
+    let mut statement = connection.prepare(
+        "SELECT id, \"table\", \"from\", \"to\" FROM pragma_foreign_key_list(?1) ORDER BY id, seq",
+    )?;
+
+    let rows = statement
+        .query_map([&table.name], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut current: Option<(i64, SchemaForeignKey)> = None;
+
+    for (id, referenced_table, from, to) in rows {
+        match current.as_mut() {
+            Some((current_id, foreign_key)) if *current_id == id => {
+                foreign_key.columns.push(from);
+                foreign_key.referenced_columns.extend(to);
+            }
+            _ => {
+                table.foreign_keys.extend(current.take().map(|e| e.1));
+                current = Some((
+                    id,
+                    SchemaForeignKey {
+                        columns: vec![from],
+                        referenced_table,
+                        // 참조 컬럼을 생략하면 참조 테이블의 PK를 가리킵니다.
+                        referenced_columns: to.into_iter().collect(),
+                    },
+                ));
+            }
+        }
+    }
+
+    table.foreign_keys.extend(current.map(|e| e.1));
+
+    Ok(())
+}
+
+fn read_indexes(connection: &Connection, table: &mut SchemaTable) -> rusqlite::Result<()> {
+    // origin: c = CREATE INDEX, u = UNIQUE 제약, pk = PRIMARY KEY 제약
+    let mut statement = connection
+        .prepare("SELECT name, \"unique\", origin FROM pragma_index_list(?1) ORDER BY seq")?;
+
+    let indexes = statement
+        .query_map([&table.name], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, bool>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut statement = connection.prepare(
+        "SELECT name, \"desc\" FROM pragma_index_xinfo(?1) WHERE key = 1 AND name IS NOT NULL ORDER BY seqno",
+    )?;
+
+    for (index_name, is_unique, origin) in indexes.iter() {
+        if origin == "pk" {
+            continue;
+        }
+
+        let columns = statement
+            .query_map([index_name], |row| {
+                let order = if row.get::<_, bool>(1)? {
+                    SortOrder::Desc
+                } else {
+                    SortOrder::Asc
+                };
+
+                Ok((row.get::<_, String>(0)?, order))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        // 단일 컬럼 UNIQUE 제약은 컬럼 속성으로 옮깁니다.
+        if origin == "u" && columns.len() == 1 {
+            if let Some(column) = table.find_column_mut(&columns[0].0) {
+                column.is_unique = true;
+                continue;
+            }
+        }
+
+        table.push_index(SchemaIndex {
+            // 자동 생성된 인덱스(sqlite_autoindex_*)는 규칙대로 이름을 다시 붙입니다.
+            name: if index_name.starts_with("sqlite_autoindex_") {
+                String::new()
+            } else {
+                index_name.clone()
+            },
+            columns,
+            is_unique: *is_unique,
+            ..Default::default()
+        });
+    }
+
+    Ok(())
+}
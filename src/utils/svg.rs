@@ -0,0 +1,341 @@
+use crate::models::{Column, Entity, Note};
+
+use super::{
+    layout::{self, COLUMN_HEIGHT, ENTITY_HEADER_HEIGHT},
+    relation::{relations, Relation},
+};
+
+// 저장된 x/y 좌표 그대로 다이어그램을 SVG로 그립니다. (썸네일, 메일, 문서 삽입용)
+// 같은 데이터면 항상 같은 결과가 나오도록 그리는 순서를 id 기준으로 고정하고, 글자 폭은 글자 수로 어림합니다.
+
+const PADDING: f64 = 40.0;
+const NOTE_WIDTH: f64 = 200.0;
+const NOTE_LINE_HEIGHT: f64 = 18.0;
+const NOTE_LINE_CHARS: usize = 26;
+const NAME_CHARS: usize = 20;
+const TYPE_CHARS: usize = 14;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// 상자 폭을 넘지 않도록 글자 수로 자릅니다.
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_owned()
+    } else {
+        text.chars().take(max_chars - 1).collect::<String>() + "…"
+    }
+}
+
+fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = vec![];
+
+    for line in text.lines() {
+        let chars = line.chars().collect::<Vec<_>>();
+
+        if chars.is_empty() {
+            lines.push(String::new());
+            continue;
+        }
+
+        lines.extend(chars.chunks(max_chars).map(|e| e.iter().collect()));
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+fn entity_rect(entity: &Entity) -> Rect {
+    Rect {
+        x: entity.x,
+        y: entity.y,
+        width: layout::entity_width(entity),
+        height: layout::entity_height(entity),
+    }
+}
+
+// 상자 안에 그릴 수 있는 컬럼 수 (접혔으면 0)
+fn visible_columns(entity: &Entity) -> usize {
+    let rows = (layout::entity_height(entity) - ENTITY_HEADER_HEIGHT) / COLUMN_HEIGHT;
+
+    entity.columns.len().min(rows.max(0.0).floor() as usize)
+}
+
+// 폭을 바꾼 노트는 한 줄 글자 수도 폭에 맞춥니다. 접힌 노트는 첫 줄만 봅니다.
+fn note_lines(note: &Note) -> Vec<String> {
+    let width = note.width.unwrap_or(NOTE_WIDTH);
+    let max_chars = ((NOTE_LINE_CHARS as f64 * width / NOTE_WIDTH) as usize).max(1);
+    let mut lines = wrap(&note.content, max_chars);
+
+    if note.collapsed {
+        lines.truncate(1);
+    }
+
+    lines
+}
+
+pub fn note_height(note: &Note) -> f64 {
+    match note.height {
+        Some(height) if !note.collapsed => height,
+        _ => 16.0 + NOTE_LINE_HEIGHT * note_lines(note).len() as f64,
+    }
+}
+
+fn note_rect(note: &Note) -> Rect {
+    Rect {
+        x: note.x,
+        y: note.y,
+        width: note.width.unwrap_or(NOTE_WIDTH),
+        height: note_height(note),
+    }
+}
+
+// 컬럼 행의 세로 중앙 (컬럼이 보이지 않으면 헤더 중앙)
+fn column_center_y(entity: &Entity, column: &Column) -> f64 {
+    let y = entity.y;
+
+    match entity.columns.iter().position(|e| e.id == column.id) {
+        Some(index) if index < visible_columns(entity) => {
+            y + ENTITY_HEADER_HEIGHT + COLUMN_HEIGHT * (index as f64 + 0.5)
+        }
+        _ => y + ENTITY_HEADER_HEIGHT / 2.0,
+    }
+}
+
+fn render_entity(entity: &Entity) -> Vec<String> {
+    let rect = entity_rect(entity);
+    let (x, y) = (rect.x, rect.y);
+    let mut lines = vec![];
+
+    lines.push(format!(
+        r##"<g class="entity" data-id="{}">"##,
+        escape(&entity.id)
+    ));
+    lines.push(format!(
+        r##"<rect x="{x}" y="{y}" width="{}" height="{}" rx="4" fill="{}" stroke="#4a5568"/>"##,
+        rect.width,
+        rect.height,
+        escape(entity.color.as_deref().unwrap_or("#ffffff"))
+    ));
+
+    if !entity.collapsed {
+        lines.push(format!(
+            r##"<path d="M{x} {} H{}" stroke="#4a5568"/>"##,
+            y + ENTITY_HEADER_HEIGHT,
+            x + rect.width
+        ));
+    }
+
+    let has_logical_name =
+        !entity.logical_name.is_empty() && entity.logical_name != entity.physical_name;
+
+    lines.push(format!(
+        r##"<text x="{}" y="{}" font-weight="bold">{}</text>"##,
+        x + 8.0,
+        y + if has_logical_name { 17.0 } else { 25.0 },
+        escape(&truncate(&entity.physical_name, NAME_CHARS + 6))
+    ));
+
+    if has_logical_name {
+        lines.push(format!(
+            r##"<text x="{}" y="{}" font-size="11" fill="#718096">{}</text>"##,
+            x + 8.0,
+            y + 33.0,
+            escape(&truncate(&entity.logical_name, NAME_CHARS + 8))
+        ));
+    }
+
+    for (index, column) in entity
+        .columns
+        .iter()
+        .take(visible_columns(entity))
+        .enumerate()
+    {
+        let baseline = y + ENTITY_HEADER_HEIGHT + COLUMN_HEIGHT * index as f64 + 16.0;
+
+        let marker = match (column.is_primary_key, column.foreign_key.is_some()) {
+            (true, _) => Some(("PK", "#b7791f")),
+            (false, true) => Some(("FK", "#2b6cb0")),
+            (false, false) => None,
+        };
+
+        if let Some((marker, color)) = marker {
+            lines.push(format!(
+                r##"<text x="{}" y="{baseline}" font-size="9" font-weight="bold" fill="{color}">{marker}</text>"##,
+                x + 6.0
+            ));
+        }
+
+        let name = if column.nullable {
+            escape(&truncate(&column.physical_name, NAME_CHARS))
+        } else {
+            format!(
+                r##"<tspan font-weight="bold">{}</tspan>"##,
+                escape(&truncate(&column.physical_name, NAME_CHARS))
+            )
+        };
+
+        lines.push(format!(
+            r##"<text x="{}" y="{baseline}">{name}</text>"##,
+            x + 28.0
+        ));
+        lines.push(format!(
+            r##"<text x="{}" y="{baseline}" font-size="11" fill="#718096" text-anchor="end">{}</text>"##,
+            x + rect.width - 8.0,
+            escape(&truncate(&column.full_type(), TYPE_CHARS))
+        ));
+    }
+
+    lines.push("</g>".to_owned());
+
+    lines
+}
+
+// 자식(FK) 쪽 컬럼에서 부모 쪽 컬럼으로 꺾은선을 긋습니다. 비식별 관계는 점선입니다.
+fn render_relation(relation: &Relation) -> String {
+    let child = entity_rect(relation.child);
+    let parent = entity_rect(relation.parent);
+
+    let y1 = column_center_y(relation.child, relation.child_column);
+    let y2 = column_center_y(relation.parent, relation.parent_column);
+
+    let (x1, x2, mid) = if child.x + child.width < parent.x {
+        let x1 = child.x + child.width;
+        (x1, parent.x, (x1 + parent.x) / 2.0)
+    } else if parent.x + parent.width < child.x {
+        let x2 = parent.x + parent.width;
+        (child.x, x2, (child.x + x2) / 2.0)
+    } else {
+        // 세로로 겹치면 양쪽 다 오른쪽으로 돌아서 연결합니다.
+        let x1 = child.x + child.width;
+        let x2 = parent.x + parent.width;
+        (x1, x2, x1.max(x2) + 30.0)
+    };
+
+    let child_marker = if relation.is_one_to_one() {
+        "one"
+    } else {
+        "many"
+    };
+
+    let dash = if relation.is_identifying() {
+        ""
+    } else {
+        r##" stroke-dasharray="6 4""##
+    };
+
+    format!(
+        r##"<path class="relation" d="M{x1} {y1} H{mid} V{y2} H{x2}" fill="none" stroke="#4a5568"{dash} marker-start="url(#{child_marker})" marker-end="url(#one)"/>"##
+    )
+}
+
+fn render_note(note: &Note) -> Vec<String> {
+    let rect = note_rect(note);
+    let mut lines = vec![];
+
+    lines.push(format!(
+        r##"<g class="note" data-id="{}">"##,
+        escape(&note.id)
+    ));
+    lines.push(format!(
+        r##"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="#d69e2e"/>"##,
+        rect.x,
+        rect.y,
+        rect.width,
+        rect.height,
+        escape(note.color.as_deref().unwrap_or("#fefcbf"))
+    ));
+
+    for (index, line) in note_lines(note).iter().enumerate() {
+        lines.push(format!(
+            r##"<text x="{}" y="{}">{}</text>"##,
+            rect.x + 8.0,
+            rect.y + 8.0 + NOTE_LINE_HEIGHT * (index as f64 + 0.75),
+            escape(line)
+        ));
+    }
+
+    lines.push("</g>".to_owned());
+
+    lines
+}
+
+pub fn render(entities: &[Entity], notes: &[Note]) -> String {
+    let mut entities = entities.iter().collect::<Vec<_>>();
+    entities.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut notes = notes.iter().collect::<Vec<_>>();
+    notes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let rects = entities
+        .iter()
+        .map(|e| entity_rect(e))
+        .chain(notes.iter().map(|e| note_rect(e)))
+        .collect::<Vec<_>>();
+
+    let (left, top, right, bottom) = rects
+        .iter()
+        .fold(None, |acc, rect| {
+            let (left, top, right, bottom) =
+                acc.unwrap_or((rect.x, rect.y, rect.x + rect.width, rect.y + rect.height));
+
+            Some((
+                f64::min(left, rect.x),
+                f64::min(top, rect.y),
+                f64::max(right, rect.x + rect.width),
+                f64::max(bottom, rect.y + rect.height),
+            ))
+        })
+        .unwrap_or((0.0, 0.0, 0.0, 0.0));
+
+    // 관계선이 상자 오른쪽으로 돌아나갈 수 있으므로 여유를 둡니다.
+    let (left, top) = (left - PADDING, top - PADDING);
+    let width = right - left + PADDING + 30.0;
+    let height = bottom - top + PADDING;
+
+    let mut lines = vec![
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="{left} {top} {width} {height}" font-family="sans-serif" font-size="12">"##
+        ),
+        "<defs>".to_owned(),
+        r##"<marker id="one" viewBox="0 0 12 12" refX="12" refY="6" markerWidth="12" markerHeight="12" orient="auto-start-reverse"><path d="M6 0 V12" stroke="#4a5568"/></marker>"##.to_owned(),
+        r##"<marker id="many" viewBox="0 0 12 12" refX="12" refY="6" markerWidth="12" markerHeight="12" orient="auto-start-reverse"><path d="M12 0 L0 6 L12 12" fill="none" stroke="#4a5568"/></marker>"##.to_owned(),
+        "</defs>".to_owned(),
+        format!(
+            r##"<rect x="{left}" y="{top}" width="{width}" height="{height}" fill="#f7fafc"/>"##
+        ),
+    ];
+
+    lines.extend(relations(&entities).iter().map(render_relation));
+
+    // 겹치면 z_index가 큰 쪽이 위에 오도록 그립니다. (같으면 엔티티, 노트 순)
+    let mut shapes = entities
+        .iter()
+        .map(|e| (e.z_index, 0, &e.id, render_entity(e)))
+        .chain(notes.iter().map(|e| (e.z_index, 1, &e.id, render_note(e))))
+        .collect::<Vec<_>>();
+    shapes.sort_by(|a, b| (a.0, a.1, a.2).cmp(&(b.0, b.1, b.2)));
+
+    for (_, _, _, shape) in shapes {
+        lines.extend(shape);
+    }
+
+    lines.push("</svg>".to_owned());
+
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,472 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Column, Entity, Project};
+
+use super::{relation::relations, sql};
+
+// 문서에 붙여 넣을 수 있는 텍스트 ERD (dbdiagram.io DBML, Mermaid erDiagram, PlantUML IE 표기법)
+// 논리명/주석은 각 형식이 허용하는 위치(노트, 별칭, 설명)에 넣습니다.
+// DB 조회 순서와 무관하게 같은 결과가 나오도록 엔티티는 물리명 순으로 그립니다.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextDiagramFormat {
+    Dbml,
+    Mermaid,
+    PlantUml,
+}
+
+impl TextDiagramFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TextDiagramFormat::Dbml => "dbml",
+            TextDiagramFormat::Mermaid => "mmd",
+            TextDiagramFormat::PlantUml => "puml",
+        }
+    }
+}
+
+pub fn render(format: TextDiagramFormat, project: &Project, entities: &[Entity]) -> String {
+    let mut entities = entities.iter().collect::<Vec<_>>();
+    entities.sort_by(|a, b| a.physical_name.cmp(&b.physical_name));
+
+    match format {
+        TextDiagramFormat::Dbml => to_dbml(project, &entities),
+        TextDiagramFormat::Mermaid => to_mermaid(&entities),
+        TextDiagramFormat::PlantUml => to_plantuml(project, &entities),
+    }
+}
+
+// 논리명이 물리명과 다를 때만 논리명을 붙입니다.
+fn description(logical_name: &str, physical_name: &str, comment: &str) -> String {
+    let logical_name = logical_name.trim();
+    let comment = comment.trim();
+
+    let logical_name = if logical_name == physical_name {
+        ""
+    } else {
+        logical_name
+    };
+
+    match (logical_name.is_empty(), comment.is_empty()) {
+        (true, true) => String::new(),
+        (false, true) => logical_name.to_owned(),
+        (true, false) => comment.to_owned(),
+        (false, false) => format!("{logical_name} - {comment}"),
+    }
+}
+
+fn is_plain_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Mermaid/PlantUML 식별자로 쓸 수 없는 문자는 _로 바꿉니다.
+fn identifier(name: &str) -> String {
+    let identifier = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{identifier}")
+    } else {
+        identifier
+    }
+}
+
+fn dbml_name(name: &str) -> String {
+    if is_plain_identifier(name) {
+        name.to_owned()
+    } else {
+        format!("\"{}\"", name.replace('"', "\\\""))
+    }
+}
+
+fn dbml_string(text: &str) -> String {
+    format!(
+        "'{}'",
+        text.replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('\n', "\\n")
+    )
+}
+
+// 숫자/문자열/불리언 외의 기본값은 식으로 봅니다.
+fn dbml_default(value: &str) -> String {
+    let lower = value.to_ascii_lowercase();
+
+    if value.starts_with('\'')
+        || value.parse::<f64>().is_ok()
+        || matches!(lower.as_str(), "true" | "false" | "null")
+    {
+        value.to_owned()
+    } else {
+        format!("`{value}`")
+    }
+}
+
+fn to_dbml(project: &Project, entities: &[&Entity]) -> String {
+    let mut lines = vec![format!("Project {} {{", dbml_name(&project.name))];
+
+    if !project.description.trim().is_empty() {
+        lines.push(format!(
+            "  Note: {}",
+            dbml_string(project.description.trim())
+        ));
+    }
+
+    lines.push("}".to_owned());
+
+    for entity in entities.iter() {
+        let note = description(&entity.logical_name, &entity.physical_name, &entity.comment);
+
+        lines.push(String::new());
+
+        if note.is_empty() {
+            lines.push(format!("Table {} {{", dbml_name(&entity.physical_name)));
+        } else {
+            lines.push(format!(
+                "Table {} [note: {}] {{",
+                dbml_name(&entity.physical_name),
+                dbml_string(&note)
+            ));
+        }
+
+        let primary_keys = sql::primary_key_columns(entity);
+
+        for column in entity.columns.iter() {
+            let mut settings = vec![];
+
+            if column.is_primary_key && primary_keys.len() == 1 {
+                settings.push("pk".to_owned());
+            }
+
+            if column.is_auto_increment {
+                settings.push("increment".to_owned());
+            }
+
+            if !column.nullable && !column.is_primary_key {
+                settings.push("not null".to_owned());
+            }
+
+            if column.is_unique {
+                settings.push("unique".to_owned());
+            }
+
+            if let Some(default_value) = column.default_value.as_ref() {
+                settings.push(format!("default: {}", dbml_default(default_value)));
+            }
+
+            let note = description(&column.logical_name, &column.physical_name, &column.comment);
+
+            if !note.is_empty() {
+                settings.push(format!("note: {}", dbml_string(&note)));
+            }
+
+            let data_type = column.full_type();
+            let data_type = if data_type.contains(' ') {
+                format!("\"{data_type}\"")
+            } else {
+                data_type
+            };
+
+            if settings.is_empty() {
+                lines.push(format!(
+                    "  {} {data_type}",
+                    dbml_name(&column.physical_name)
+                ));
+            } else {
+                lines.push(format!(
+                    "  {} {data_type} [{}]",
+                    dbml_name(&column.physical_name),
+                    settings.join(", ")
+                ));
+            }
+        }
+
+        let mut indexes = vec![];
+
+        if primary_keys.len() > 1 {
+            let names = primary_keys
+                .iter()
+                .map(|e| dbml_name(&e.physical_name))
+                .collect::<Vec<_>>();
+
+            indexes.push(format!("    ({}) [pk]", names.join(", ")));
+        }
+
+        for index in entity.indexes.iter() {
+            let names = index
+                .columns
+                .iter()
+                .filter_map(|index_column| {
+                    entity
+                        .columns
+                        .iter()
+                        .find(|e| e.id == index_column.column_id)
+                })
+                .map(|e| dbml_name(&e.physical_name))
+                .collect::<Vec<_>>();
+
+            if names.is_empty() {
+                continue;
+            }
+
+            let mut settings = vec![format!("name: {}", dbml_string(&index.name))];
+
+            if index.is_unique {
+                settings.insert(0, "unique".to_owned());
+            }
+
+            indexes.push(format!(
+                "    ({}) [{}]",
+                names.join(", "),
+                settings.join(", ")
+            ));
+        }
+
+        if !indexes.is_empty() {
+            lines.push(String::new());
+            lines.push("  indexes {".to_owned());
+            lines.extend(indexes);
+            lines.push("  }".to_owned());
+        }
+
+        lines.push("}".to_owned());
+    }
+
+    let relations = relations(entities);
+
+    if !relations.is_empty() {
+        lines.push(String::new());
+    }
+
+    for relation in relations.iter() {
+        let operator = if relation.is_one_to_one() { "-" } else { ">" };
+
+        lines.push(format!(
+            "Ref {}: {}.{} {operator} {}.{}",
+            dbml_name(&sql::foreign_key_name(
+                relation.child,
+                relation.child_column
+            )),
+            dbml_name(&relation.child.physical_name),
+            dbml_name(&relation.child_column.physical_name),
+            dbml_name(&relation.parent.physical_name),
+            dbml_name(&relation.parent_column.physical_name),
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+// Mermaid 문자열 안에는 큰따옴표를 쓸 수 없습니다.
+fn mermaid_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "'").replace('\n', " "))
+}
+
+// Mermaid 속성 타입에는 공백과 쉼표를 쓸 수 없습니다. (DECIMAL(10,2) -> DECIMAL(10-2))
+fn mermaid_type(column: &Column) -> String {
+    column
+        .full_type()
+        .chars()
+        .map(|c| match c {
+            ',' => '-',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+fn to_mermaid(entities: &[&Entity]) -> String {
+    let mut lines = vec!["erDiagram".to_owned()];
+
+    for entity in entities.iter() {
+        let name = identifier(&entity.physical_name);
+        let label = description(&entity.logical_name, &entity.physical_name, "");
+
+        if label.is_empty() {
+            lines.push(format!("    {name} {{"));
+        } else {
+            lines.push(format!("    {name}[{}] {{", mermaid_string(&label)));
+        }
+
+        for column in entity.columns.iter() {
+            let mut keys = vec![];
+
+            if column.is_primary_key {
+                keys.push("PK");
+            }
+
+            if column.foreign_key.is_some() {
+                keys.push("FK");
+            }
+
+            if column.is_unique {
+                keys.push("UK");
+            }
+
+            let mut line = format!(
+                "        {} {}",
+                mermaid_type(column),
+                identifier(&column.physical_name)
+            );
+
+            if !keys.is_empty() {
+                line.push(' ');
+                line.push_str(&keys.join(", "));
+            }
+
+            let comment = description(&column.logical_name, &column.physical_name, &column.comment);
+
+            if !comment.is_empty() {
+                line.push(' ');
+                line.push_str(&mermaid_string(&comment));
+            }
+
+            lines.push(line);
+        }
+
+        lines.push("    }".to_owned());
+    }
+
+    for relation in relations(entities).iter() {
+        let parent = if relation.is_optional() { "|o" } else { "||" };
+        let child = if relation.is_one_to_one() { "o|" } else { "o{" };
+        let line = if relation.is_identifying() {
+            "--"
+        } else {
+            ".."
+        };
+
+        lines.push(format!(
+            "    {} {parent}{line}{child} {} : {}",
+            identifier(&relation.parent.physical_name),
+            identifier(&relation.child.physical_name),
+            mermaid_string(&relation.child_column.physical_name)
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn plantuml_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "'").replace('\n', "\\n"))
+}
+
+fn plantuml_column(column: &Column) -> String {
+    let mut line = format!(
+        "  {}{} : {}",
+        if column.nullable { "  " } else { "* " },
+        column.physical_name,
+        column.full_type()
+    );
+
+    if column.is_primary_key {
+        line.push_str(" <<PK>>");
+    }
+
+    if column.foreign_key.is_some() {
+        line.push_str(" <<FK>>");
+    }
+
+    if column.is_unique {
+        line.push_str(" <<UK>>");
+    }
+
+    let comment = description(&column.logical_name, &column.physical_name, &column.comment);
+
+    if !comment.is_empty() {
+        line.push_str(&format!(" // {}", comment.replace('\n', " ")));
+    }
+
+    line
+}
+
+// PK 컬럼은 구분선 위에, 나머지는 아래에 그립니다.
+fn to_plantuml(project: &Project, entities: &[&Entity]) -> String {
+    let mut lines = vec![
+        "@startuml".to_owned(),
+        format!("title {}", project.name.replace('\n', " ")),
+        "hide circle".to_owned(),
+        "skinparam linetype ortho".to_owned(),
+    ];
+
+    for entity in entities.iter() {
+        let name = identifier(&entity.physical_name);
+        let label = description(&entity.logical_name, &entity.physical_name, "");
+
+        let label = if label.is_empty() {
+            entity.physical_name.clone()
+        } else {
+            format!("{}\\n{label}", entity.physical_name)
+        };
+
+        lines.push(String::new());
+        lines.push(format!("entity {} as {name} {{", plantuml_string(&label)));
+
+        lines.extend(
+            entity
+                .columns
+                .iter()
+                .filter(|e| e.is_primary_key)
+                .map(plantuml_column),
+        );
+
+        lines.push("  --".to_owned());
+
+        lines.extend(
+            entity
+                .columns
+                .iter()
+                .filter(|e| !e.is_primary_key)
+                .map(plantuml_column),
+        );
+
+        lines.push("}".to_owned());
+
+        if !entity.comment.trim().is_empty() {
+            lines.push(format!(
+                "note bottom of {name} : {}",
+                entity.comment.trim().replace('\n', "\\n")
+            ));
+        }
+    }
+
+    let relations = relations(entities);
+
+    if !relations.is_empty() {
+        lines.push(String::new());
+    }
+
+    for relation in relations.iter() {
+        let parent = if relation.is_optional() { "|o" } else { "||" };
+        let child = if relation.is_one_to_one() { "o|" } else { "o{" };
+        let line = if relation.is_identifying() {
+            "--"
+        } else {
+            ".."
+        };
+
+        lines.push(format!(
+            "{} {parent}{line}{child} {} : {}",
+            identifier(&relation.parent.physical_name),
+            identifier(&relation.child.physical_name),
+            relation.child_column.physical_name
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("@enduml".to_owned());
+
+    lines.join("\n") + "\n"
+}